@@ -1,21 +1,37 @@
+mod async_validate;
+mod context;
 mod enum_derive;
 mod named_struct_derive;
 mod unnamed_struct_derive;
 
 use enum_derive::expand_enum_validate_derive;
+pub use enum_derive::Variants;
 use named_struct_derive::expand_named_struct_derive;
 use proc_macro2::TokenStream;
 use unnamed_struct_derive::expand_unnamed_struct_derive;
 
 pub fn expand_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    expand_validate_derive(input, false)
+}
+
+pub fn expand_async_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    expand_validate_derive(input, true)
+}
+
+fn expand_validate_derive(
+    input: &syn::DeriveInput,
+    asynchronous: bool,
+) -> Result<TokenStream, crate::Errors> {
     match &input.data {
         syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
-            syn::Fields::Named(fields) => expand_named_struct_derive(input, fields),
-            syn::Fields::Unnamed(fields) => expand_unnamed_struct_derive(input, fields),
+            syn::Fields::Named(fields) => expand_named_struct_derive(input, fields, asynchronous),
+            syn::Fields::Unnamed(fields) => {
+                expand_unnamed_struct_derive(input, fields, asynchronous)
+            }
             syn::Fields::Unit => Err(vec![crate::Error::unit_struct_not_support(input)]),
         },
         syn::Data::Enum(syn::DataEnum { variants, .. }) => {
-            expand_enum_validate_derive(input, variants)
+            expand_enum_validate_derive(input, variants, asynchronous)
         }
         syn::Data::Union(_) => Err(vec![crate::Error::union_not_support(input)]),
     }
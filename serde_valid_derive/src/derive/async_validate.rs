@@ -0,0 +1,17 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Implement the async checks of `AsyncValidate`,
+/// whose errors are merged into the errors of `Validate` by `AsyncValidate::validate_async`.
+pub fn expand_async_validate_impl(input: &syn::DeriveInput, body: TokenStream) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::serde_valid::AsyncValidate for #ident #type_generics #where_clause {
+            async fn validate_async_checks(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                #body
+            }
+        }
+    )
+}
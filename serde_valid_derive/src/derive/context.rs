@@ -0,0 +1,142 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_quote;
+
+/// Find the context type of `#[validate(context = "...")]` on the struct or the enum.
+pub fn find_validate_context(
+    input: &syn::DeriveInput,
+    asynchronous: bool,
+) -> Result<Option<syn::Type>, crate::Errors> {
+    let context = collect_validate_context(&input.attrs)?;
+    if asynchronous && context.is_some() {
+        Err(vec![crate::Error::async_validate_context_not_support(
+            input,
+        )])
+    } else {
+        Ok(context)
+    }
+}
+
+fn collect_validate_context(
+    attributes: &[syn::Attribute],
+) -> Result<Option<syn::Type>, crate::Errors> {
+    let mut errors = vec![];
+    let mut context = None;
+
+    for attribute in attributes {
+        if attribute.path != parse_quote!(validate) {
+            continue;
+        }
+        let nested = match attribute.parse_meta() {
+            Ok(syn::Meta::List(list)) => list.nested,
+            Ok(meta) => {
+                errors.push(crate::Error::validate_container_attribute_need_context(
+                    &meta,
+                ));
+                continue;
+            }
+            Err(error) => {
+                errors.push(crate::Error::validate_attribute_parse_error(
+                    attribute, &error,
+                ));
+                continue;
+            }
+        };
+        for nested_meta in nested.iter() {
+            match nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(lit_str),
+                    ..
+                })) if path.is_ident("context") => match lit_str.parse::<syn::Type>() {
+                    Ok(context_type) => context = Some(context_type),
+                    Err(error) => errors.push(crate::Error::validate_context_type_parse_error(
+                        lit_str, &error,
+                    )),
+                },
+                syn::NestedMeta::Meta(meta) => errors.push(
+                    crate::Error::validate_container_attribute_need_context(meta),
+                ),
+                syn::NestedMeta::Lit(lit) => errors.push(crate::Error::literal_not_support(lit)),
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(context)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Implement `Validate`, or `ValidateWithContext` when the context type is declared.
+///
+/// Without the context, `ValidateWithContext` of any context forwards to `Validate`,
+/// so that the type can be nested in the types with the context.
+/// `Validate::validate_group_id` is also implemented when the validators use the active group.
+pub fn expand_validate_impl(
+    input: &syn::DeriveInput,
+    context: Option<&syn::Type>,
+    uses_group: bool,
+    body: TokenStream,
+) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let validate_impl = match context {
+        Some(context) => quote!(
+            impl #impl_generics ::serde_valid::ValidateWithContext<#context> for #ident #type_generics #where_clause {
+                fn validate_with_context(
+                    &self,
+                    __context: &#context,
+                ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    #body
+                }
+            }
+        ),
+        None if uses_group => quote!(
+            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
+                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    let __group: Option<::std::any::TypeId> = None;
+                    #body
+                }
+
+                fn validate_group_id(
+                    &self,
+                    __group: ::std::any::TypeId,
+                ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    let __group = Some(__group);
+                    #body
+                }
+            }
+        ),
+        None => quote!(
+            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
+                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    #body
+                }
+            }
+        ),
+    };
+
+    if context.is_some() {
+        return validate_impl;
+    }
+
+    let mut context_generics = input.generics.clone();
+    context_generics.params.push(parse_quote!(__C: ?Sized));
+    let (context_impl_generics, _, _) = context_generics.split_for_impl();
+
+    quote!(
+        #validate_impl
+
+        impl #context_impl_generics ::serde_valid::ValidateWithContext<__C> for #ident #type_generics #where_clause {
+            fn validate_with_context(
+                &self,
+                _: &__C,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                ::serde_valid::Validate::validate(self)
+            }
+        }
+    )
+}
@@ -1,9 +1,17 @@
+use super::async_validate::expand_async_validate_impl;
+use super::context::{expand_validate_impl, find_validate_context};
 use super::named_struct_derive::collect_named_fields_validators_list;
 use super::unnamed_struct_derive::collect_unnamed_fields_validators_list;
 use crate::error::{array_errors_tokens, new_type_errors_tokens, object_errors_tokens};
-use crate::rule::{collect_rules_from_named_struct, collect_rules_from_unnamed_struct};
-use crate::serde::rename::collect_serde_rename_map;
+use crate::rule::{
+    collect_rules_from_enum, collect_rules_from_named_struct, collect_rules_from_unnamed_struct,
+};
+use crate::serde::case::RenameRule;
+use crate::serde::rename::{
+    collect_serde_rename_map, find_serde_rename_all, find_serde_rename_all_fields,
+};
 use crate::types::CommaSeparatedTokenStreams;
+use crate::validate::FieldValidators;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::collections::HashSet;
@@ -14,80 +22,153 @@ pub type Variants = syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>
 pub fn expand_enum_validate_derive(
     input: &syn::DeriveInput,
     variants: &Variants,
+    asynchronous: bool,
 ) -> Result<TokenStream, crate::Errors> {
     let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let rename_all_fields = find_serde_rename_all_fields(&input.attrs);
 
     let mut errors = vec![];
 
+    let context = find_validate_context(input, asynchronous).unwrap_or_else(|context_errors| {
+        errors.extend(context_errors);
+        None
+    });
+    let context = context.as_ref();
+
+    let enum_rules = collect_rules_from_enum(
+        &input.attrs,
+        quote!(__enum_rule_vec_errors),
+        context,
+        asynchronous,
+    )
+    .unwrap_or_else(|rule_errors| {
+        errors.extend(rule_errors);
+        quote!()
+    });
+
+    let mut uses_group = false;
     let validations_and_rules =
-        TokenStream::from_iter(variants.iter().enumerate().map(|(index, variant)| {
-            match &variant.fields {
-                syn::Fields::Named(named_fields) => {
-                    match expand_enum_variant_named_fields(index, ident, variant, named_fields) {
-                        Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
-                        Err(variant_errors) => {
-                            errors.extend(variant_errors);
-                            quote!()
-                        }
+        TokenStream::from_iter(variants.iter().map(|variant| match &variant.fields {
+            syn::Fields::Named(named_fields) => {
+                match expand_enum_variant_named_fields(
+                    ident,
+                    variant,
+                    named_fields,
+                    rename_all_fields,
+                    context,
+                    asynchronous,
+                ) {
+                    Ok((variant_varidates_and_rules, variant_uses_group)) => {
+                        uses_group |= variant_uses_group;
+                        variant_varidates_and_rules
+                    }
+                    Err(variant_errors) => {
+                        errors.extend(variant_errors);
+                        quote!()
                     }
                 }
-                syn::Fields::Unnamed(unnamed_fields) => {
-                    match expand_enum_variant_unnamed_fields_varidation(
-                        index,
-                        ident,
-                        variant,
-                        unnamed_fields,
-                    ) {
-                        Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
-                        Err(variant_errors) => {
-                            errors.extend(variant_errors);
-                            quote!()
-                        }
+            }
+            syn::Fields::Unnamed(unnamed_fields) => {
+                match expand_enum_variant_unnamed_fields_varidation(
+                    ident,
+                    variant,
+                    unnamed_fields,
+                    context,
+                    asynchronous,
+                ) {
+                    Ok((variant_varidates_and_rules, variant_uses_group)) => {
+                        uses_group |= variant_uses_group;
+                        variant_varidates_and_rules
+                    }
+                    Err(variant_errors) => {
+                        errors.extend(variant_errors);
+                        quote!()
+                    }
+                }
+            }
+            syn::Fields::Unit => {
+                match expand_enum_variant_unit(ident, variant, context, asynchronous) {
+                    Ok(variant_rules) => variant_rules,
+                    Err(variant_errors) => {
+                        errors.extend(variant_errors);
+                        quote!()
                     }
                 }
-                syn::Fields::Unit => quote!(),
             }
         }));
 
     if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    #validations_and_rules
+        let body = quote!(
+            let mut __enum_rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+            #enum_rules
+
+            let mut __variant_result: Result<(), ::serde_valid::validation::Errors> = Ok(());
+            #validations_and_rules
 
-                    Ok(())
+            match __variant_result {
+                Ok(()) if __enum_rule_vec_errors.is_empty() => Ok(()),
+                Ok(()) => Err(::serde_valid::validation::Errors::NewType(__enum_rule_vec_errors)),
+                Err(mut __errors) => {
+                    if !__enum_rule_vec_errors.is_empty() {
+                        __errors.merge(::serde_valid::validation::Errors::NewType(__enum_rule_vec_errors));
+                    }
+                    Err(__errors)
                 }
             }
-        ))
+        );
+        if asynchronous {
+            Ok(expand_async_validate_impl(input, body))
+        } else {
+            Ok(expand_validate_impl(input, context, uses_group, body))
+        }
     } else {
         Err(errors)
     }
 }
 
 fn expand_enum_variant_named_fields(
-    index: usize,
     ident: &syn::Ident,
     variant: &syn::Variant,
     named_fields: &syn::FieldsNamed,
-) -> Result<TokenStream, crate::Errors> {
+    rename_all_fields: Option<RenameRule>,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+) -> Result<(TokenStream, bool), crate::Errors> {
     let mut errors = vec![];
 
     let variant_ident = &variant.ident;
     let mut fields_idents = CommaSeparatedTokenStreams::new();
-    let else_token = make_else_token(index);
-    let rename_map = collect_serde_rename_map(named_fields);
+    let rename_map = collect_serde_rename_map(
+        named_fields,
+        find_serde_rename_all(&variant.attrs).or(rename_all_fields),
+    );
 
-    let (rule_fields, rules) = match collect_rules_from_named_struct(&variant.attrs) {
+    let (rule_fields, rules) = match collect_rules_from_named_struct(
+        &variant.attrs,
+        named_fields,
+        &rename_map,
+        context,
+        asynchronous,
+    ) {
         Ok(field_rules) => field_rules,
         Err(variant_errors) => {
-            errors.extend(variant_errors.into_iter());
+            errors.extend(variant_errors);
             (HashSet::new(), quote!())
         }
     };
 
-    let validates = match collect_named_fields_validators_list(named_fields, &rename_map) {
+    let mut uses_group = false;
+    let validates = match collect_named_fields_validators_list(
+        named_fields,
+        &rename_map,
+        context,
+        asynchronous,
+    ) {
         Ok(field_validators_list) => {
+            uses_group = field_validators_list
+                .iter()
+                .any(FieldValidators::uses_group);
             TokenStream::from_iter(field_validators_list.iter().map(|validators| {
                 let field_ident = validators.ident();
 
@@ -105,7 +186,7 @@ fn expand_enum_variant_named_fields(
             }))
         }
         Err(fields_errors) => {
-            errors.extend(fields_errors.into_iter());
+            errors.extend(fields_errors);
             quote!()
         }
     };
@@ -113,18 +194,21 @@ fn expand_enum_variant_named_fields(
     let variant_errors = object_errors_tokens();
 
     if errors.is_empty() {
-        Ok(quote!(
-            #else_token if let #ident::#variant_ident{#fields_idents} = &self {
-                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+        Ok((
+            quote!(
+                if let #ident::#variant_ident{#fields_idents} = &self {
+                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                    let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
 
-                #validates
-                #rules
+                    #validates
+                    #rules
 
-                if !(__rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty()) {
-                    Err(#variant_errors)?
+                    if !(__rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty()) {
+                        __variant_result = Err(#variant_errors);
+                    }
                 }
-            }
+            ),
+            uses_group,
         ))
     } else {
         Err(errors)
@@ -132,48 +216,58 @@ fn expand_enum_variant_named_fields(
 }
 
 fn expand_enum_variant_unnamed_fields_varidation(
-    index: usize,
     ident: &syn::Ident,
     variant: &syn::Variant,
     unnamed_fields: &syn::FieldsUnnamed,
-) -> Result<TokenStream, crate::Errors> {
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+) -> Result<(TokenStream, bool), crate::Errors> {
     let mut errors = vec![];
 
     let variant_ident = &variant.ident;
     let mut fields_idents = CommaSeparatedTokenStreams::new();
-    let else_token = make_else_token(index);
 
-    let (rule_fields, rules) = match collect_rules_from_unnamed_struct(&variant.attrs) {
+    let (rule_fields, rules) = match collect_rules_from_unnamed_struct(
+        &variant.attrs,
+        unnamed_fields,
+        context,
+        asynchronous,
+    ) {
         Ok(field_rules) => field_rules,
         Err(variant_errors) => {
-            errors.extend(variant_errors.into_iter());
+            errors.extend(variant_errors);
             (HashSet::new(), quote!())
         }
     };
 
-    let validates = match collect_unnamed_fields_validators_list(unnamed_fields) {
-        Ok(field_validators_list) => {
-            TokenStream::from_iter(field_validators_list.iter().map(|validators| {
-                let field_ident = validators.ident();
+    let mut uses_group = false;
+    let validates =
+        match collect_unnamed_fields_validators_list(unnamed_fields, context, asynchronous) {
+            Ok(field_validators_list) => {
+                uses_group = field_validators_list
+                    .iter()
+                    .any(FieldValidators::uses_group);
+                TokenStream::from_iter(field_validators_list.iter().map(|validators| {
+                    let field_ident = validators.ident();
 
-                if let Some(token) = validators.get_tokens() {
-                    fields_idents.push(quote!(#field_ident));
-                    quote!(#token)
-                } else {
-                    if rule_fields.contains(field_ident) {
+                    if let Some(token) = validators.get_tokens() {
                         fields_idents.push(quote!(#field_ident));
+                        quote!(#token)
                     } else {
-                        fields_idents.push(quote!(_));
+                        if rule_fields.contains(field_ident) {
+                            fields_idents.push(quote!(#field_ident));
+                        } else {
+                            fields_idents.push(quote!(_));
+                        }
+                        quote!()
                     }
-                    quote!()
-                }
-            }))
-        }
-        Err(fields_errors) => {
-            errors.extend(fields_errors.into_iter());
-            quote!()
-        }
-    };
+                }))
+            }
+            Err(fields_errors) => {
+                errors.extend(fields_errors);
+                quote!()
+            }
+        };
 
     let variant_errors = if unnamed_fields.unnamed.len() != 1 {
         array_errors_tokens()
@@ -182,28 +276,54 @@ fn expand_enum_variant_unnamed_fields_varidation(
     };
 
     if errors.is_empty() {
-        Ok(quote!(
-            #else_token if let #ident::#variant_ident(#fields_idents) = &self {
-                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+        Ok((
+            quote!(
+                if let #ident::#variant_ident(#fields_idents) = &self {
+                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                    let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
 
-                #validates
-                #rules
+                    #validates
+                    #rules
 
-                if !(__rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty()) {
-                    Err(#variant_errors)?
+                    if !(__rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty()) {
+                        __variant_result = Err(#variant_errors);
+                    }
                 }
-            }
+            ),
+            uses_group,
         ))
     } else {
         Err(errors)
     }
 }
 
-fn make_else_token(index: usize) -> TokenStream {
-    if index == 0 {
-        quote!()
-    } else {
-        quote!(else)
+fn expand_enum_variant_unit(
+    ident: &syn::Ident,
+    variant: &syn::Variant,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+) -> Result<TokenStream, crate::Errors> {
+    let variant_ident = &variant.ident;
+    let rules = collect_rules_from_enum(
+        &variant.attrs,
+        quote!(__rule_vec_errors),
+        context,
+        asynchronous,
+    )?;
+
+    if rules.is_empty() {
+        return Ok(quote!());
     }
+
+    Ok(quote!(
+        if let #ident::#variant_ident = &self {
+            let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+
+            #rules
+
+            if !__rule_vec_errors.is_empty() {
+                __variant_result = Err(::serde_valid::validation::Errors::NewType(__rule_vec_errors));
+            }
+        }
+    ))
 }
@@ -1,8 +1,12 @@
+use super::async_validate::expand_async_validate_impl;
+use super::context::{expand_validate_impl, find_validate_context};
 use crate::error::object_errors_tokens;
 use crate::rule::collect_rules_from_named_struct;
-use crate::serde::rename::{collect_serde_rename_map, RenameMap};
+use crate::serde::rename::{collect_serde_rename_map, find_serde_rename_all, RenameMap};
 use crate::types::{Field, NamedField};
-use crate::validate::{extract_meta_validator, FieldValidators};
+use crate::validate::{
+    extract_meta_validator, is_validate_attribute_of, uses_validation_group, FieldValidators,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::borrow::Cow;
@@ -13,14 +17,25 @@ use syn::parse_quote;
 pub fn expand_named_struct_derive(
     input: &syn::DeriveInput,
     fields: &syn::FieldsNamed,
+    asynchronous: bool,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-    let rename_map = collect_serde_rename_map(fields);
+    let rename_map = collect_serde_rename_map(fields, find_serde_rename_all(&input.attrs));
 
     let mut errors = vec![];
 
-    let (rule_fields, rules) = match collect_rules_from_named_struct(&input.attrs) {
+    let context = find_validate_context(input, asynchronous).unwrap_or_else(|context_errors| {
+        errors.extend(context_errors);
+        None
+    });
+    let context = context.as_ref();
+
+    let (rule_fields, rules) = match collect_rules_from_named_struct(
+        &input.attrs,
+        fields,
+        &rename_map,
+        context,
+        asynchronous,
+    ) {
         Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
         Err(rule_errors) => {
             errors.extend(rule_errors);
@@ -28,40 +43,46 @@ pub fn expand_named_struct_derive(
         }
     };
 
-    let validates = match collect_named_fields_validators_list(fields, &rename_map) {
-        Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
-            if validator.is_empty() && rule_fields.contains(validator.ident()) {
-                validator.get_field_variable_token()
-            } else {
-                validator.generate_tokens()
+    let mut uses_group = false;
+    let validates =
+        match collect_named_fields_validators_list(fields, &rename_map, context, asynchronous) {
+            Ok(field_validators) => {
+                uses_group = field_validators.iter().any(FieldValidators::uses_group);
+                TokenStream::from_iter(field_validators.iter().map(|validator| {
+                    if validator.is_empty() && rule_fields.contains(validator.ident()) {
+                        validator.get_field_variable_token()
+                    } else {
+                        validator.generate_tokens()
+                    }
+                }))
             }
-        })),
-        Err(validation_errors) => {
-            errors.extend(validation_errors.into_iter());
-            quote!()
-        }
-    };
+            Err(validation_errors) => {
+                errors.extend(validation_errors);
+                quote!()
+            }
+        };
 
     let fields_errors = object_errors_tokens();
 
     if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+        let body = quote!(
+                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
 
-                    #validates
-                    #rules
+                #validates
+                #rules
 
-                    if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
+                if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
+                    Ok(())
+                } else {
+                    Err(#fields_errors)
                 }
-            }
-        ))
+        );
+        if asynchronous {
+            Ok(expand_async_validate_impl(input, body))
+        } else {
+            Ok(expand_validate_impl(input, context, uses_group, body))
+        }
     } else {
         Err(errors)
     }
@@ -70,21 +91,23 @@ pub fn expand_named_struct_derive(
 pub fn collect_named_fields_validators_list<'a>(
     fields: &'a syn::FieldsNamed,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<Vec<FieldValidators<'a, NamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
     let validators = fields
         .named
         .iter()
-        .filter_map(
-            |field| match collect_named_field_validators(field, rename_map) {
+        .filter_map(|field| {
+            match collect_named_field_validators(field, rename_map, context, asynchronous) {
                 Ok(validators) => Some(validators),
                 Err(ref mut error) => {
                     errors.append(error);
                     None
                 }
-            },
-        )
+            }
+        })
         .collect();
 
     if errors.is_empty() {
@@ -97,6 +120,8 @@ pub fn collect_named_fields_validators_list<'a>(
 fn collect_named_field_validators<'a>(
     field: &'a syn::Field,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<FieldValidators<'a, NamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
@@ -105,10 +130,13 @@ fn collect_named_field_validators<'a>(
         .attrs()
         .iter()
         .filter_map(|attribute| {
-            if attribute.path != parse_quote!(validate) {
+            if attribute.path != parse_quote!(validate)
+                || !is_validate_attribute_of(attribute, asynchronous)
+            {
                 return None;
             }
-            match extract_meta_validator(&named_field, attribute, rename_map) {
+            match extract_meta_validator(&named_field, attribute, rename_map, context, asynchronous)
+            {
                 Ok(validator) => Some(validator),
                 Err(validator_error) => {
                     errors.extend(validator_error);
@@ -122,8 +150,14 @@ fn collect_named_field_validators<'a>(
         return Err(errors);
     }
 
+    let uses_group = !asynchronous
+        && named_field.attrs().iter().any(|attribute| {
+            attribute.path == parse_quote!(validate) && uses_validation_group(attribute, context)
+        });
+
     Ok(FieldValidators::new(
         Cow::Owned(named_field.clone()),
         validators,
+        uses_group,
     ))
 }
@@ -1,7 +1,11 @@
+use super::async_validate::expand_async_validate_impl;
+use super::context::{expand_validate_impl, find_validate_context};
 use crate::error::{array_errors_tokens, new_type_errors_tokens};
 use crate::rule::collect_rules_from_unnamed_struct;
 use crate::types::{Field, UnnamedField};
-use crate::validate::{extract_meta_validator, FieldValidators};
+use crate::validate::{
+    extract_meta_validator, is_validate_attribute_of, uses_validation_group, FieldValidators,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::borrow::Cow;
@@ -12,30 +16,39 @@ use syn::parse_quote;
 pub fn expand_unnamed_struct_derive(
     input: &syn::DeriveInput,
     fields: &syn::FieldsUnnamed,
+    asynchronous: bool,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-
     let mut errors = vec![];
 
-    let (rule_fields, rules) = match collect_rules_from_unnamed_struct(&input.attrs) {
-        Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
-        Err(rule_errors) => {
-            errors.extend(rule_errors);
-            (HashSet::new(), quote!())
-        }
-    };
-
-    let validates = match collect_unnamed_fields_validators_list(fields) {
-        Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
-            if validator.is_empty() && rule_fields.contains(validator.ident()) {
-                validator.get_field_variable_token()
-            } else {
-                validator.generate_tokens()
+    let context = find_validate_context(input, asynchronous).unwrap_or_else(|context_errors| {
+        errors.extend(context_errors);
+        None
+    });
+    let context = context.as_ref();
+
+    let (rule_fields, rules) =
+        match collect_rules_from_unnamed_struct(&input.attrs, fields, context, asynchronous) {
+            Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
+            Err(rule_errors) => {
+                errors.extend(rule_errors);
+                (HashSet::new(), quote!())
             }
-        })),
+        };
+
+    let mut uses_group = false;
+    let validates = match collect_unnamed_fields_validators_list(fields, context, asynchronous) {
+        Ok(field_validators) => {
+            uses_group = field_validators.iter().any(FieldValidators::uses_group);
+            TokenStream::from_iter(field_validators.iter().map(|validator| {
+                if validator.is_empty() && rule_fields.contains(validator.ident()) {
+                    validator.get_field_variable_token()
+                } else {
+                    validator.generate_tokens()
+                }
+            }))
+        }
         Err(validation_errors) => {
-            errors.extend(validation_errors.into_iter());
+            errors.extend(validation_errors);
             quote!()
         }
     };
@@ -47,44 +60,49 @@ pub fn expand_unnamed_struct_derive(
     };
 
     if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
-
-                    #validates
-                    #rules
-
-                    if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
+        let body = quote!(
+                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+
+                #validates
+                #rules
+
+                if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
+                    Ok(())
+                } else {
+                    Err(#fields_errors)
                 }
-            }
-        ))
+        );
+        if asynchronous {
+            Ok(expand_async_validate_impl(input, body))
+        } else {
+            Ok(expand_validate_impl(input, context, uses_group, body))
+        }
     } else {
         Err(errors)
     }
 }
 
-pub fn collect_unnamed_fields_validators_list(
-    fields: &syn::FieldsUnnamed,
-) -> Result<Vec<FieldValidators<UnnamedField>>, crate::Errors> {
+pub fn collect_unnamed_fields_validators_list<'a>(
+    fields: &'a syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+) -> Result<Vec<FieldValidators<'a, UnnamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
     let validators = fields
         .unnamed
         .iter()
         .enumerate()
-        .filter_map(|field| match collect_unnamed_field_validators(field) {
-            Ok(validators) => Some(validators),
-            Err(ref mut error) => {
-                errors.append(error);
-                None
-            }
-        })
+        .filter_map(
+            |field| match collect_unnamed_field_validators(field, context, asynchronous) {
+                Ok(validators) => Some(validators),
+                Err(ref mut error) => {
+                    errors.append(error);
+                    None
+                }
+            },
+        )
         .collect();
 
     if !errors.is_empty() {
@@ -94,9 +112,11 @@ pub fn collect_unnamed_fields_validators_list(
     Ok(validators)
 }
 
-fn collect_unnamed_field_validators(
-    (index, field): (usize, &syn::Field),
-) -> Result<FieldValidators<UnnamedField>, crate::Errors> {
+fn collect_unnamed_field_validators<'a>(
+    (index, field): (usize, &'a syn::Field),
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+) -> Result<FieldValidators<'a, UnnamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
     let unnamed_field = UnnamedField::new(index, field);
@@ -105,10 +125,18 @@ fn collect_unnamed_field_validators(
         .attrs()
         .iter()
         .filter_map(|attribute| {
-            if attribute.path != parse_quote!(validate) {
+            if attribute.path != parse_quote!(validate)
+                || !is_validate_attribute_of(attribute, asynchronous)
+            {
                 return None;
             }
-            match extract_meta_validator(&unnamed_field, attribute, &HashMap::new()) {
+            match extract_meta_validator(
+                &unnamed_field,
+                attribute,
+                &HashMap::new(),
+                context,
+                asynchronous,
+            ) {
                 Ok(validator) => Some(validator),
                 Err(validator_errors) => {
                     errors.extend(validator_errors);
@@ -122,5 +150,14 @@ fn collect_unnamed_field_validators(
         return Err(errors);
     }
 
-    Ok(FieldValidators::new(Cow::Owned(unnamed_field), validators))
+    let uses_group = !asynchronous
+        && unnamed_field.attrs().iter().any(|attribute| {
+            attribute.path == parse_quote!(validate) && uses_validation_group(attribute, context)
+        });
+
+    Ok(FieldValidators::new(
+        Cow::Owned(unnamed_field),
+        validators,
+        uses_group,
+    ))
 }
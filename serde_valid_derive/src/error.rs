@@ -12,7 +12,7 @@ pub fn object_errors_tokens() -> TokenStream {
                 .into_iter()
                 .map(|(field, errors)| {
                     let mut __field_items_errors = vec![];
-                    let mut __field_properties_errors = None;
+                    let mut __field_properties_errors = vec![];
                     let mut __field_errors: ::serde_valid::validation::VecErrors = errors
                         .into_iter()
                         .filter_map(|error| match error {
@@ -21,14 +21,17 @@ pub fn object_errors_tokens() -> TokenStream {
                                 None
                             }
                             ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
+                                __field_properties_errors.push(__object_errors);
                                 None
                             }
                             _ => Some(error),
                         })
                         .collect();
 
-                    if let Some(__object_errors) = __field_properties_errors {
+                    if let Some(__object_errors) = __field_properties_errors
+                        .into_iter()
+                        .reduce(|a, b| a.merge(b))
+                    {
                         __field_errors.extend(__object_errors.errors);
 
                         (
@@ -76,7 +79,7 @@ pub fn array_errors_tokens() -> TokenStream {
                 .into_iter()
                 .map(|(index, errors)| {
                     let mut __field_items_errors = vec![];
-                    let mut __field_properties_errors = None;
+                    let mut __field_properties_errors = vec![];
                     let mut __field_errors: ::serde_valid::validation::VecErrors = errors
                         .into_iter()
                         .filter_map(|error| match error {
@@ -85,14 +88,17 @@ pub fn array_errors_tokens() -> TokenStream {
                                 None
                             }
                             ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
+                                __field_properties_errors.push(__object_errors);
                                 None
                             }
                             _ => Some(error),
                         })
                         .collect();
 
-                    if let Some(__object_errors) = __field_properties_errors {
+                    if let Some(__object_errors) = __field_properties_errors
+                        .into_iter()
+                        .reduce(|a, b| a.merge(b))
+                    {
                         __field_errors.extend(__object_errors.errors);
 
                         (
@@ -160,6 +166,13 @@ impl Error {
         Self::new(input.span(), "#[derive(Validate)] does not support Union.")
     }
 
+    pub fn json_schema_union_not_support(input: &syn::DeriveInput) -> Self {
+        Self::new(
+            input.span(),
+            "#[derive(JsonSchema)] does not support Union.",
+        )
+    }
+
     pub fn rule_need_function(path: &syn::Path) -> Self {
         Self::new(path.span(), "#[rule(???)] needs rule_fn.")
     }
@@ -194,6 +207,52 @@ impl Error {
         )
     }
 
+    pub fn rule_target_unknown_field(field_ident: &syn::Ident) -> Self {
+        Self::new(
+            field_ident.span(),
+            format!("#[rule(..., target = ???)] unknown field `{field_ident}`."),
+        )
+    }
+
+    pub fn rule_target_need_field(index: &syn::LitInt) -> Self {
+        Self::new(
+            index.span(),
+            "#[rule(..., target = ???)] of the named fields struct allow field name only.",
+        )
+    }
+
+    pub fn rule_target_unknown_index(index: &syn::LitInt) -> Self {
+        Self::new(
+            index.span(),
+            format!("#[rule(..., target = ???)] unknown field index `{index}`."),
+        )
+    }
+
+    pub fn rule_target_need_index(field_ident: &syn::Ident) -> Self {
+        Self::new(
+            field_ident.span(),
+            "#[rule(..., target = ???)] of the unnamed fields struct allow index integer only.",
+        )
+    }
+
+    pub fn rule_target_not_support(span: proc_macro2::Span) -> Self {
+        Self::new(
+            span,
+            "#[rule(..., target = ???)] of the enum or the unit variant does not support.",
+        )
+    }
+
+    pub fn rule_allow_self_arguments(
+        rule_fn_name_path: &syn::Path,
+        meta: &syn::NestedMeta,
+    ) -> Self {
+        let rule_fn_name = quote!(#rule_fn_name_path).to_string();
+        Self::new(
+            meta.span(),
+            format!("#[rule({rule_fn_name}(???, ...))] of the enum or the unit variant allow `self` or `ctx` only."),
+        )
+    }
+
     pub fn rule_validate_attribute_parse_error(
         attribute: &syn::Attribute,
         error: &syn::Error,
@@ -201,6 +260,117 @@ impl Error {
         Self::new(attribute.span(), format!("#[rule] parse error: {error}"))
     }
 
+    pub fn validate_container_attribute_need_context(meta: &syn::Meta) -> Self {
+        Self::new(
+            meta.span(),
+            "#[validate(...)] of the struct or the enum allow only `context = \"...\"`.",
+        )
+    }
+
+    pub fn validate_context_type_parse_error(lit_str: &syn::LitStr, error: &syn::Error) -> Self {
+        Self::new(lit_str.span(), format!("Context type parse error: {error}"))
+    }
+
+    pub fn validate_context_not_declared(span: proc_macro2::Span) -> Self {
+        Self::new(
+            span,
+            "The context type is not declared. Use #[validate(context = \"...\")] on the struct or the enum.",
+        )
+    }
+
+    pub fn async_validate_context_not_support(input: &syn::DeriveInput) -> Self {
+        Self::new(
+            input.span(),
+            "#[derive(AsyncValidate)] does not support #[validate(context = \"...\")].",
+        )
+    }
+
+    pub fn validate_context_allow_custom_only(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "`context` is allowed only with `custom`.",
+        )
+    }
+
+    pub fn validate_map_entries_message_not_support(list: &syn::MetaList) -> Self {
+        let path = &list.path;
+        let name = quote!(#path).to_string();
+        Self::new(
+            list.span(),
+            format!("`message` of `{name}` must be inside like `{name}(..., message = \"...\")`."),
+        )
+    }
+
+    pub fn validate_map_entries_need_validation(list: &syn::MetaList) -> Self {
+        let path = &list.path;
+        let name = quote!(#path).to_string();
+        Self::new(
+            list.span(),
+            format!("`{name}` need the validation like `{name}(max_length = 5)`."),
+        )
+    }
+
+    pub fn validate_contains_need_validation(list: &syn::MetaList) -> Self {
+        Self::new(
+            list.span(),
+            "`contains` need the validation like `contains(enumerate(\"admin\"))`.",
+        )
+    }
+
+    pub fn validate_contains_count_duplicated(path: &syn::Path) -> Self {
+        let name = quote!(#path).to_string();
+        Self::new(path.span(), format!("`{name}` is duplicated."))
+    }
+
+    pub fn validate_contains_min_greater_than_max(
+        list: &syn::MetaList,
+        min_contains: usize,
+        max_contains: usize,
+    ) -> Self {
+        Self::new(
+            list.span(),
+            format!(
+                "`min_contains = {min_contains}` must be `<=` `max_contains = {max_contains}`."
+            ),
+        )
+    }
+
+    pub fn validate_contains_async_not_support(list: &syn::MetaList) -> Self {
+        Self::new(
+            list.span(),
+            "`contains` does not support with `custom_async`.",
+        )
+    }
+
+    pub fn validate_groups_need_item(path: &syn::Path) -> Self {
+        Self::new(path.span(), "`groups` need items.")
+    }
+
+    pub fn validate_groups_allow_type_path(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "`groups(???)` allow the types of `ValidationGroup` only, such as `groups(Create)`.",
+        )
+    }
+
+    pub fn validate_groups_duplicated(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(nested_meta.span(), "`groups` is duplicated.")
+    }
+
+    pub fn validate_groups_context_not_support(list: &syn::MetaList) -> Self {
+        Self::new(
+            list.span(),
+            "`groups` does not support with #[validate(context = \"...\")].",
+        )
+    }
+
+    pub fn validate_groups_async_not_support(list: &syn::MetaList) -> Self {
+        Self::new(
+            list.span(),
+            "`groups` does not support with `custom_async`.",
+        )
+    }
+
     pub fn validate_meta_literal_not_support(lit: &syn::Lit) -> Self {
         Self::new(lit.span(), "#[validate(???)] does not support literal.")
     }
@@ -266,6 +436,31 @@ impl Error {
         Self::new(nested.span(), "`custom` support only 1 item.")
     }
 
+    pub fn validate_expr_parse_error(lit_str: &syn::LitStr, error: &syn::Error) -> Self {
+        Self::new(lit_str.span(), format!("`expr` parse error: {error}"))
+    }
+
+    pub fn validate_when_parse_error(value: &TokenStream, error: &syn::Error) -> Self {
+        Self::new(
+            value.span(),
+            format!("`when = path_to_fn` or `when = \"expr\"` parse error: {error}"),
+        )
+    }
+
+    pub fn validate_when_duplicated(value: &TokenStream) -> Self {
+        Self::new(value.span(), "`when` is duplicated.")
+    }
+
+    pub fn validate_custom_closure_parse_error(
+        attribute: &syn::Attribute,
+        error: &syn::Error,
+    ) -> Self {
+        Self::new(
+            attribute.span(),
+            format!("`custom = |...| ...` parse error: {error}"),
+        )
+    }
+
     pub fn message_fn_need_item(path: &syn::Path) -> Self {
         Self::new(path.span(), "`message_fn` need items.")
     }
@@ -281,6 +476,34 @@ impl Error {
         Self::new(nested_meta.span(), "`message_fn` support only 1 item.")
     }
 
+    pub fn message_template_unknown_placeholder(
+        lit_str: &syn::LitStr,
+        unknown: &str,
+        candidates: &[&str],
+    ) -> Self {
+        let filterd_candidates =
+            did_you_mean(unknown, candidates).unwrap_or_else(|| candidates.to_vec());
+
+        Self::new(
+            lit_str.span(),
+            format!("Unknown placeholder: `{{{unknown}}}`. Is it one of the following?\n{filterd_candidates:#?}"),
+        )
+    }
+
+    pub fn message_template_unclosed_placeholder(lit_str: &syn::LitStr) -> Self {
+        Self::new(
+            lit_str.span(),
+            "Unclosed placeholder. Use `{{` to write the `{` in the message.",
+        )
+    }
+
+    pub fn message_template_unmatched_brace(lit_str: &syn::LitStr) -> Self {
+        Self::new(
+            lit_str.span(),
+            "Unmatched `}`. Use `}}` to write the `}` in the message.",
+        )
+    }
+
     pub fn literal_only(meta: &syn::Meta) -> Self {
         Self::new(meta.span(), "Allow literal only.")
     }
@@ -289,6 +512,10 @@ impl Error {
         Self::new(lit.span(), "Allow numeric literal only.")
     }
 
+    pub fn usize_literal_only(lit: &syn::Lit) -> Self {
+        Self::new(lit.span(), "Allow usize literal only.")
+    }
+
     pub fn str_literal_only(lit: &syn::Lit) -> Self {
         Self::new(lit.span(), "Allow str literal only.")
     }
@@ -2,18 +2,20 @@
 mod derive;
 mod error;
 mod rule;
+mod schema;
 mod serde;
 mod types;
 mod validate;
 
-use derive::expand_derive;
+use derive::{expand_async_derive, expand_derive};
 use error::to_compile_errors;
 use error::{Error, Errors};
 use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
+use schema::expand_json_schema_derive;
 use syn::{parse_macro_input, DeriveInput};
 
-#[proc_macro_derive(Validate, attributes(rule, validate))]
+#[proc_macro_derive(Validate, attributes(rule, rule_async, validate))]
 #[proc_macro_error]
 pub fn derive_validate(tokens: TokenStream) -> TokenStream {
     let input = parse_macro_input!(tokens as DeriveInput);
@@ -22,3 +24,23 @@ pub fn derive_validate(tokens: TokenStream) -> TokenStream {
         .unwrap_or_else(to_compile_errors)
         .into()
 }
+
+#[proc_macro_derive(AsyncValidate, attributes(rule, rule_async, validate))]
+#[proc_macro_error]
+pub fn derive_async_validate(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    expand_async_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
+
+#[proc_macro_derive(JsonSchema, attributes(rule, validate))]
+#[proc_macro_error]
+pub fn derive_json_schema(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    expand_json_schema_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
@@ -1,5 +1,73 @@
+mod enum_rule;
 mod named_struct_rule;
 mod unnamed_struct_rule;
 
+pub use enum_rule::collect_rules_from_enum;
 pub use named_struct_rule::collect_rules_from_named_struct;
 pub use unnamed_struct_rule::collect_rules_from_unnamed_struct;
+
+use crate::types::CommaSeparatedNestedMetas;
+
+/// The field which the rule errors attach to, by `#[rule(..., target = ...)]`.
+pub enum RuleTarget {
+    Field(syn::Ident),
+    Index(syn::LitInt),
+}
+
+impl RuleTarget {
+    pub fn span(&self) -> proc_macro2::Span {
+        match self {
+            Self::Field(field_ident) => field_ident.span(),
+            Self::Index(index) => index.span(),
+        }
+    }
+}
+
+/// Parse `#[rule(...)]`, separating `target = ...` from the rule function.
+pub fn parse_rule_attribute(
+    attribute: &syn::Attribute,
+) -> Result<(syn::MetaList, Option<RuleTarget>), crate::Errors> {
+    if attribute.tokens.is_empty() {
+        return Err(vec![crate::Error::rule_need_function(&attribute.path)]);
+    }
+
+    attribute
+        .parse_args_with(|input: syn::parse::ParseStream| {
+            let mut nested = CommaSeparatedNestedMetas::new();
+            let mut target = None;
+            while !input.is_empty() {
+                if input.peek(syn::Ident) && input.peek2(syn::Token![=]) {
+                    let key: syn::Ident = input.parse()?;
+                    if key != "target" {
+                        return Err(syn::Error::new(key.span(), "expected `target = ...`"));
+                    }
+                    input.parse::<syn::Token![=]>()?;
+                    target = Some(if input.peek(syn::LitInt) {
+                        RuleTarget::Index(input.parse()?)
+                    } else if input.peek(syn::LitStr) {
+                        RuleTarget::Field(input.parse::<syn::LitStr>()?.parse()?)
+                    } else {
+                        RuleTarget::Field(input.parse()?)
+                    });
+                } else {
+                    nested.push(input.parse()?);
+                }
+                if !input.is_empty() {
+                    input.parse::<syn::Token![,]>()?;
+                }
+            }
+            Ok((
+                syn::MetaList {
+                    path: attribute.path.clone(),
+                    paren_token: Default::default(),
+                    nested,
+                },
+                target,
+            ))
+        })
+        .map_err(|error| {
+            vec![crate::Error::rule_validate_attribute_parse_error(
+                attribute, &error,
+            )]
+        })
+}
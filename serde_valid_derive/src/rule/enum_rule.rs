@@ -0,0 +1,118 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+
+use super::parse_rule_attribute;
+use crate::types::CommaSeparatedTokenStreams;
+
+/// Collect `#[rule(...)]` of the enum or the unit variant,
+/// which allows only `self` and `ctx` arguments.
+pub fn collect_rules_from_enum(
+    attributes: &[syn::Attribute],
+    errors_variable: TokenStream,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+) -> Result<TokenStream, crate::Errors> {
+    let mut errors = vec![];
+    let rule_path: syn::Path = if asynchronous {
+        syn::parse_quote!(rule_async)
+    } else {
+        syn::parse_quote!(rule)
+    };
+
+    let rules = attributes
+        .iter()
+        .filter(|attribute| attribute.path == rule_path)
+        .filter_map(|attribute| {
+            let rule = parse_rule_attribute(attribute).and_then(|(list, target)| {
+                if let Some(target) = target {
+                    return Err(vec![crate::Error::rule_target_not_support(target.span())]);
+                }
+                collect_rule(&list, &errors_variable, context, asynchronous)
+            });
+            match rule {
+                Ok(stream) => Some(stream),
+                Err(rule_errors) => {
+                    errors.extend(rule_errors);
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(TokenStream::from_iter(rules))
+    } else {
+        Err(errors)
+    }
+}
+
+fn collect_rule(
+    syn::MetaList {
+        path, ref nested, ..
+    }: &syn::MetaList,
+    errors_variable: &TokenStream,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+) -> Result<TokenStream, crate::Errors> {
+    let mut errors = vec![];
+
+    match nested.len() {
+        0 => Err(vec![crate::Error::rule_need_function(path)])?,
+        2.. => nested.iter().skip(1).for_each(|nested_meta| {
+            errors.push(crate::Error::rule_allow_single_function(nested_meta))
+        }),
+        _ => {}
+    }
+
+    let rule_fn = match &nested[0] {
+        syn::NestedMeta::Meta(syn::Meta::List(list)) => list,
+        syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+            Err(vec![crate::Error::meta_path_not_support(path)])?
+        }
+        syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) => {
+            Err(vec![crate::Error::meta_name_value_not_support(name_value)])?
+        }
+        syn::NestedMeta::Lit(lit) => Err(vec![crate::Error::literal_not_support(lit)])?,
+    };
+    let rule_fn_name = &rule_fn.path;
+
+    let rule_fn_args = rule_fn
+        .nested
+        .iter()
+        .filter_map(|nested_meta| match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("self") => {
+                Some(quote!(self))
+            }
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("ctx") => {
+                if context.is_none() {
+                    errors.push(crate::Error::validate_context_not_declared(path.span()));
+                }
+                Some(quote!(__context))
+            }
+            _ => {
+                errors.push(crate::Error::rule_allow_self_arguments(
+                    rule_fn_name,
+                    nested_meta,
+                ));
+                None
+            }
+        })
+        .collect::<CommaSeparatedTokenStreams>();
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let await_token = if asynchronous {
+        quote!(.await)
+    } else {
+        quote!()
+    };
+
+    Ok(quote!(
+        if let Err(__errors) = #rule_fn_name(#rule_fn_args)#await_token {
+            #errors_variable.extend(::serde_valid::validation::IntoVecErrors::into_vec_errors(__errors));
+        };
+    ))
+}
@@ -5,19 +5,34 @@ use quote::{quote, ToTokens};
 use syn::parse_quote;
 use syn::spanned::Spanned;
 
+use super::{parse_rule_attribute, RuleTarget};
+use crate::serde::rename::RenameMap;
 use crate::types::CommaSeparatedTokenStreams;
 
 pub fn collect_rules_from_named_struct(
     attributes: &[syn::Attribute],
+    fields: &syn::FieldsNamed,
+    rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
+    let rule_path: syn::Path = if asynchronous {
+        parse_quote!(rule_async)
+    } else {
+        parse_quote!(rule)
+    };
 
     let mut rule_fields = HashSet::new();
     let rules = attributes
         .iter()
-        .filter(|attribute| attribute.path == parse_quote!(rule))
-        .filter_map(|attribute| match attribute.parse_meta() {
-            Ok(syn::Meta::List(list)) => match collect_rule(&list) {
+        .filter(|attribute| attribute.path == rule_path)
+        .filter_map(|attribute| {
+            let rule = parse_rule_attribute(attribute).and_then(|(list, target)| {
+                let push_error = push_error_tokens(target.as_ref(), fields, rename_map)?;
+                collect_rule(&list, push_error, context, asynchronous)
+            });
+            match rule {
                 Ok((field_ident, stream)) => {
                     rule_fields.extend(field_ident);
                     Some(stream)
@@ -26,16 +41,6 @@ pub fn collect_rules_from_named_struct(
                     errors.extend(rule_errors);
                     None
                 }
-            },
-            Ok(_) => {
-                errors.push(crate::Error::rule_need_function(&attribute.path));
-                None
-            }
-            Err(error) => {
-                errors.push(crate::Error::rule_validate_attribute_parse_error(
-                    attribute, &error,
-                ));
-                None
             }
         })
         .collect::<Vec<_>>();
@@ -51,6 +56,9 @@ fn collect_rule(
     syn::MetaList {
         path, ref nested, ..
     }: &syn::MetaList,
+    push_error: TokenStream,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -64,7 +72,9 @@ fn collect_rule(
 
     let rule = match &nested[0] {
         syn::NestedMeta::Meta(meta) => match meta {
-            syn::Meta::List(list) => extract_rule_from_meta_list(list),
+            syn::Meta::List(list) => {
+                extract_rule_from_meta_list(list, push_error, context, asynchronous)
+            }
             syn::Meta::NameValue(name_value) => {
                 Err(vec![crate::Error::meta_name_value_not_support(name_value)])
             }
@@ -81,7 +91,7 @@ fn collect_rule(
                 Err(errors)
             }
         }
-        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors.into_iter()).collect()),
+        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors).collect()),
     }
 }
 
@@ -91,6 +101,9 @@ fn extract_rule_from_meta_list(
         ref nested,
         ..
     }: &syn::MetaList,
+    push_error: TokenStream,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -103,6 +116,12 @@ fn extract_rule_from_meta_list(
         .iter()
         .filter_map(|nested_meta| {
             let arg = match nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("ctx") => {
+                    if context.is_none() {
+                        errors.push(crate::Error::validate_context_not_declared(path.span()));
+                    }
+                    Some(quote!(__context))
+                }
                 syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
                     arg_idents.insert(syn::Ident::new(
                         &path.to_token_stream().to_string(),
@@ -126,12 +145,64 @@ fn extract_rule_from_meta_list(
         return Err(errors);
     }
 
+    let await_token = if asynchronous {
+        quote!(.await)
+    } else {
+        quote!()
+    };
+
     Ok((
         arg_idents,
         quote!(
-            if let Err(__error) = #rule_fn_name(#rule_fn_args) {
-                __rule_vec_errors.push(__error);
+            if let Err(__errors) = #rule_fn_name(#rule_fn_args)#await_token {
+                for __error in ::serde_valid::validation::IntoVecErrors::into_vec_errors(__errors) {
+                    #push_error
+                }
             };
         ),
     ))
 }
+
+fn push_error_tokens(
+    target: Option<&RuleTarget>,
+    fields: &syn::FieldsNamed,
+    rename_map: &RenameMap,
+) -> Result<TokenStream, crate::Errors> {
+    match target {
+        None => Ok(quote!(match __error {
+            ::serde_valid::validation::Error::Properties(__object_errors) => {
+                __rule_vec_errors.extend(__object_errors.errors);
+                for (__property, __errors) in __object_errors.properties {
+                    __property_vec_errors_map
+                        .entry(__property)
+                        .or_default()
+                        .extend(::serde_valid::validation::IntoVecErrors::into_vec_errors(
+                            __errors,
+                        ));
+                }
+            }
+            __error => __rule_vec_errors.push(__error),
+        })),
+        Some(RuleTarget::Field(field_ident)) => {
+            if !fields
+                .named
+                .iter()
+                .any(|field| field.ident.as_ref() == Some(field_ident))
+            {
+                return Err(vec![crate::Error::rule_target_unknown_field(field_ident)]);
+            }
+            let field_name = field_ident.to_string();
+            let rename = rename_map
+                .get(&field_name)
+                .cloned()
+                .unwrap_or_else(|| quote!(#field_name));
+            Ok(quote!(
+                __property_vec_errors_map
+                    .entry(#rename.into())
+                    .or_default()
+                    .push(__error);
+            ))
+        }
+        Some(RuleTarget::Index(index)) => Err(vec![crate::Error::rule_target_need_field(index)]),
+    }
+}
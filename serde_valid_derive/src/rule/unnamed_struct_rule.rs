@@ -3,20 +3,34 @@ use std::collections::HashSet;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::parse_quote;
+use syn::spanned::Spanned;
 
+use super::{parse_rule_attribute, RuleTarget};
 use crate::types::CommaSeparatedTokenStreams;
 
 pub fn collect_rules_from_unnamed_struct(
     attributes: &[syn::Attribute],
+    fields: &syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
+    let rule_path: syn::Path = if asynchronous {
+        parse_quote!(rule_async)
+    } else {
+        parse_quote!(rule)
+    };
 
     let mut rule_fields = HashSet::new();
     let rules = attributes
         .iter()
-        .filter(|attribute| attribute.path == parse_quote!(rule))
-        .filter_map(|attribute| match attribute.parse_meta() {
-            Ok(syn::Meta::List(list)) => match collect_rule(&list) {
+        .filter(|attribute| attribute.path == rule_path)
+        .filter_map(|attribute| {
+            let rule = parse_rule_attribute(attribute).and_then(|(list, target)| {
+                let push_error = push_error_tokens(target.as_ref(), fields)?;
+                collect_rule(&list, push_error, context, asynchronous)
+            });
+            match rule {
                 Ok((field_ident, stream)) => {
                     rule_fields.extend(field_ident);
                     Some(stream)
@@ -25,16 +39,6 @@ pub fn collect_rules_from_unnamed_struct(
                     errors.extend(rule_errors);
                     None
                 }
-            },
-            Ok(_) => {
-                errors.push(crate::Error::rule_need_function(&attribute.path));
-                None
-            }
-            Err(error) => {
-                errors.push(crate::Error::rule_validate_attribute_parse_error(
-                    attribute, &error,
-                ));
-                None
             }
         })
         .collect::<Vec<_>>();
@@ -50,6 +54,9 @@ fn collect_rule(
     syn::MetaList {
         path, ref nested, ..
     }: &syn::MetaList,
+    push_error: TokenStream,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -63,7 +70,9 @@ fn collect_rule(
 
     let rule = match &nested[0] {
         syn::NestedMeta::Meta(meta) => match meta {
-            syn::Meta::List(list) => extract_rule_from_meta_list(list),
+            syn::Meta::List(list) => {
+                extract_rule_from_meta_list(list, push_error, context, asynchronous)
+            }
             syn::Meta::NameValue(name_value) => {
                 Err(vec![crate::Error::meta_name_value_not_support(name_value)])
             }
@@ -80,7 +89,7 @@ fn collect_rule(
                 Err(errors)
             }
         }
-        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors.into_iter()).collect()),
+        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors).collect()),
     }
 }
 
@@ -90,6 +99,9 @@ fn extract_rule_from_meta_list(
         ref nested,
         ..
     }: &syn::MetaList,
+    push_error: TokenStream,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -110,6 +122,12 @@ fn extract_rule_from_meta_list(
                     }
                     _ => None,
                 },
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("ctx") => {
+                    if context.is_none() {
+                        errors.push(crate::Error::validate_context_not_declared(path.span()));
+                    }
+                    Some(quote!(__context))
+                }
                 syn::NestedMeta::Meta(_) => None,
             };
             if arg.is_none() {
@@ -126,13 +144,51 @@ fn extract_rule_from_meta_list(
         return Err(errors);
     }
 
+    let await_token = if asynchronous {
+        quote!(.await)
+    } else {
+        quote!()
+    };
+
     Ok((
         arg_idents,
         quote!(
-            if let Err(__error) = #rule_fn_name(#rule_fn_args) {
-                __rule_vec_errors
-                    .push(__error);
+            if let Err(__errors) = #rule_fn_name(#rule_fn_args)#await_token {
+                for __error in ::serde_valid::validation::IntoVecErrors::into_vec_errors(__errors) {
+                    #push_error
+                }
             };
         ),
     ))
 }
+
+fn push_error_tokens(
+    target: Option<&RuleTarget>,
+    fields: &syn::FieldsUnnamed,
+) -> Result<TokenStream, crate::Errors> {
+    match target {
+        None => Ok(quote!(match __error {
+            ::serde_valid::validation::Error::Items(__array_errors) => {
+                __rule_vec_errors.extend(__array_errors.errors);
+                for (__index, __errors) in __array_errors.items {
+                    __item_vec_errors_map.entry(__index).or_default().extend(
+                        ::serde_valid::validation::IntoVecErrors::into_vec_errors(__errors),
+                    );
+                }
+            }
+            __error => __rule_vec_errors.push(__error),
+        })),
+        Some(RuleTarget::Index(index)) => match index.base10_parse::<usize>() {
+            Ok(index) if index < fields.unnamed.len() => Ok(quote!(
+                __item_vec_errors_map
+                    .entry(#index)
+                    .or_default()
+                    .push(__error);
+            )),
+            _ => Err(vec![crate::Error::rule_target_unknown_index(index)]),
+        },
+        Some(RuleTarget::Field(field_ident)) => {
+            Err(vec![crate::Error::rule_target_need_index(field_ident)])
+        }
+    }
+}
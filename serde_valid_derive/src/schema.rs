@@ -0,0 +1,39 @@
+mod enum_schema;
+mod keyword;
+mod named_struct_schema;
+mod unnamed_struct_schema;
+
+use crate::serde::default::has_serde_default;
+use crate::serde::rename::{collect_serde_rename_map, find_serde_rename_all};
+use enum_schema::expand_enum_schema;
+use named_struct_schema::expand_named_fields_schema;
+use proc_macro2::TokenStream;
+use quote::quote;
+use unnamed_struct_schema::expand_unnamed_fields_schema;
+
+pub fn expand_json_schema_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let schema = match &input.data {
+        syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
+            syn::Fields::Named(fields) => expand_named_fields_schema(
+                fields,
+                &collect_serde_rename_map(fields, find_serde_rename_all(&input.attrs)),
+                has_serde_default(&input.attrs),
+            )?,
+            syn::Fields::Unnamed(fields) => expand_unnamed_fields_schema(fields)?,
+            syn::Fields::Unit => quote!(::serde_valid::json::json!({ "type": "null" })),
+        },
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => expand_enum_schema(input, variants)?,
+        syn::Data::Union(_) => Err(vec![crate::Error::json_schema_union_not_support(input)])?,
+    };
+
+    Ok(quote!(
+        impl #impl_generics ::serde_valid::schema::JsonSchema for #ident #type_generics #where_clause {
+            fn json_schema() -> ::serde_valid::json::Value {
+                #schema
+            }
+        }
+    ))
+}
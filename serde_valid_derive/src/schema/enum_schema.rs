@@ -0,0 +1,129 @@
+use super::named_struct_schema::expand_named_fields_schema;
+use super::unnamed_struct_schema::expand_unnamed_fields_schema;
+use crate::derive::Variants;
+use crate::serde::rename::{
+    collect_serde_rename_map, find_serde_rename, find_serde_rename_all,
+    find_serde_rename_all_fields,
+};
+use crate::serde::skip::has_serde_skip_deserializing;
+use crate::serde::tag::{find_serde_enum_tag, EnumTag};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The schema of the enum in the serde representation,
+/// which is externally tagged by default.
+///
+/// The variants of the untagged enum are listed in `anyOf`,
+/// because serde takes the first variant matching the input.
+pub fn expand_enum_schema(
+    input: &syn::DeriveInput,
+    variants: &Variants,
+) -> Result<TokenStream, crate::Errors> {
+    let rename_all = find_serde_rename_all(&input.attrs);
+    let rename_all_fields = find_serde_rename_all_fields(&input.attrs);
+    let enum_tag = find_serde_enum_tag(&input.attrs);
+    let mut errors = vec![];
+
+    let variant_schemas = variants
+        .iter()
+        .filter(|variant| !has_serde_skip_deserializing(&variant.attrs))
+        .filter_map(|variant| {
+            let variant_ident = &variant.ident;
+            let variant_name = match rename_all {
+                Some(rename_all) => rename_all.apply_to_variant(&variant_ident.to_string()),
+                None => variant_ident.to_string(),
+            };
+            let variant_key = find_serde_rename(&variant.attrs).unwrap_or(quote!(#variant_name));
+
+            let fields_schema = match &variant.fields {
+                syn::Fields::Named(named_fields) => expand_named_fields_schema(
+                    named_fields,
+                    &collect_serde_rename_map(
+                        named_fields,
+                        find_serde_rename_all(&variant.attrs).or(rename_all_fields),
+                    ),
+                    false,
+                ),
+                syn::Fields::Unnamed(unnamed_fields) => {
+                    expand_unnamed_fields_schema(unnamed_fields)
+                }
+                syn::Fields::Unit => {
+                    return Some(unit_variant_schema(&enum_tag, &variant_key));
+                }
+            };
+
+            match fields_schema {
+                Ok(fields_schema) => Some(variant_schema(&enum_tag, &variant_key, fields_schema)),
+                Err(variant_errors) => {
+                    errors.extend(variant_errors);
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    if variant_schemas.is_empty() {
+        Ok(quote!(::serde_valid::json::json!({ "not": {} })))
+    } else if let EnumTag::Untagged = enum_tag {
+        Ok(quote!(
+            ::serde_valid::json::json!({ "anyOf": [#((#variant_schemas)),*] })
+        ))
+    } else {
+        Ok(quote!(
+            ::serde_valid::json::json!({ "oneOf": [#((#variant_schemas)),*] })
+        ))
+    }
+}
+
+fn unit_variant_schema(enum_tag: &EnumTag, variant_key: &TokenStream) -> TokenStream {
+    match enum_tag {
+        EnumTag::External => quote!(::serde_valid::json::json!({ "const": #variant_key })),
+        EnumTag::Internal { tag } | EnumTag::Adjacent { tag, .. } => {
+            quote!(::serde_valid::json::json!({
+                "type": "object",
+                "properties": { #tag: { "const": #variant_key } },
+                "required": [#tag],
+            }))
+        }
+        EnumTag::Untagged => quote!(::serde_valid::json::json!({ "type": "null" })),
+    }
+}
+
+fn variant_schema(
+    enum_tag: &EnumTag,
+    variant_key: &TokenStream,
+    fields_schema: TokenStream,
+) -> TokenStream {
+    match enum_tag {
+        EnumTag::External => quote!(::serde_valid::json::json!({
+            "type": "object",
+            "properties": { #variant_key: (#fields_schema) },
+            "required": [#variant_key],
+            "additionalProperties": false,
+        })),
+        EnumTag::Internal { tag } => quote!({
+            let mut __schema = #fields_schema;
+            ::serde_valid::schema::merge_flatten_schema(
+                &mut __schema,
+                ::serde_valid::json::json!({
+                    "properties": { #tag: { "const": #variant_key } },
+                    "required": [#tag],
+                }),
+            );
+            __schema
+        }),
+        EnumTag::Adjacent { tag, content } => quote!(::serde_valid::json::json!({
+            "type": "object",
+            "properties": {
+                #tag: { "const": #variant_key },
+                #content: (#fields_schema),
+            },
+            "required": [#tag, #content],
+        })),
+        EnumTag::Untagged => fields_schema,
+    }
+}
@@ -0,0 +1,248 @@
+use crate::types::{CommaSeparatedNestedMetas, Field, SingleIdentPath};
+use crate::validate::common::{
+    get_literal, get_numeric, get_str, MetaListValidation, MetaNameValueValidation,
+    MetaPathValidation,
+};
+use crate::validate::{has_validation_groups, split_when_predicate, WhenPredicate};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::str::FromStr;
+use syn::parse_quote;
+
+pub type Keyword = TokenStream;
+
+/// The keyword which applies only when the predicate holds.
+pub type ConditionalKeyword = (WhenPredicate, Keyword);
+
+/// Collect the JSON Schema keywords from the `#[validate(...)]` attributes of the field.
+///
+/// The validations which can not be expressed in JSON Schema (like `custom`),
+/// the validations of the groups, and the validations with `when` are skipped.
+pub fn collect_field_keywords(field: &impl Field) -> Result<Vec<Keyword>, crate::Errors> {
+    collect_field_keywords_and_conditionals(field).map(|(keywords, _)| keywords)
+}
+
+/// Collect the JSON Schema keywords, and the keywords of the validations with `when`
+/// which apply only when the predicate holds.
+pub fn collect_field_keywords_and_conditionals(
+    field: &impl Field,
+) -> Result<(Vec<Keyword>, Vec<ConditionalKeyword>), crate::Errors> {
+    let mut keywords = vec![];
+    let mut conditionals = vec![];
+    for (predicate, keyword) in collect_keywords(field)? {
+        match predicate {
+            Some(predicate) => conditionals.push((predicate, keyword)),
+            None => keywords.push(keyword),
+        }
+    }
+    Ok((keywords, conditionals))
+}
+
+fn collect_keywords(
+    field: &impl Field,
+) -> Result<Vec<(Option<WhenPredicate>, Keyword)>, crate::Errors> {
+    let mut errors = vec![];
+
+    let keywords = field
+        .attrs()
+        .iter()
+        .filter(|attribute| attribute.path == parse_quote!(validate))
+        .filter_map(|attribute| split_when_predicate(attribute).ok())
+        .filter_map(|(attribute, predicate)| match attribute.parse_meta() {
+            Ok(syn::Meta::List(list)) if has_validation_groups(&list) => None,
+            Ok(syn::Meta::List(syn::MetaList { nested, .. })) => match nested.first() {
+                Some(syn::NestedMeta::Meta(meta)) => match extract_keyword(meta) {
+                    Ok(keyword) => keyword.map(|keyword| (predicate, keyword)),
+                    Err(keyword_errors) => {
+                        errors.extend(keyword_errors);
+                        None
+                    }
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(keywords)
+    } else {
+        Err(errors)
+    }
+}
+
+fn extract_keyword(meta: &syn::Meta) -> Result<Option<Keyword>, crate::Errors> {
+    match meta {
+        syn::Meta::Path(path) => {
+            let validation_name = SingleIdentPath::new(path).ident().to_string();
+            match MetaPathValidation::from_str(&validation_name) {
+                Ok(MetaPathValidation::UniqueItems) => {
+                    Ok(Some(keyword_tokens("uniqueItems", quote!(true))))
+                }
+                Err(_) => Ok(None),
+            }
+        }
+        syn::Meta::List(list) => {
+            let syn::MetaList { path, nested, .. } = list;
+            let validation_name = SingleIdentPath::new(path).ident().to_string();
+            match MetaListValidation::from_str(&validation_name) {
+                Ok(MetaListValidation::Enumerate) => {
+                    let mut errors = vec![];
+                    let enumerate = nested
+                        .iter()
+                        .filter_map(|item| match item {
+                            syn::NestedMeta::Lit(lit) => Some(lit),
+                            syn::NestedMeta::Meta(meta) => {
+                                errors.push(crate::Error::literal_only(meta));
+                                None
+                            }
+                        })
+                        .collect::<Vec<_>>();
+
+                    if errors.is_empty() {
+                        Ok(Some(keyword_tokens("enum", quote!([#(#enumerate),*]))))
+                    } else {
+                        Err(errors)
+                    }
+                }
+                Ok(MetaListValidation::Keys) => map_entries_keyword("propertyNames", nested),
+                Ok(MetaListValidation::Values) => {
+                    map_entries_keyword("additionalProperties", nested)
+                }
+                Ok(MetaListValidation::Contains) => contains_keyword(nested),
+                Ok(MetaListValidation::Custom | MetaListValidation::CustomAsync) | Err(_) => {
+                    Ok(None)
+                }
+            }
+        }
+        syn::Meta::NameValue(syn::MetaNameValue { path, lit, .. }) => {
+            let validation_name = SingleIdentPath::new(path).ident().to_string();
+            let keyword = match MetaNameValueValidation::from_str(&validation_name) {
+                Ok(MetaNameValueValidation::Minimum) => ("minimum", numeric_tokens(lit)?),
+                Ok(MetaNameValueValidation::Maximum) => ("maximum", numeric_tokens(lit)?),
+                Ok(MetaNameValueValidation::ExclusiveMinimum) => {
+                    ("exclusiveMinimum", numeric_tokens(lit)?)
+                }
+                Ok(MetaNameValueValidation::ExclusiveMaximum) => {
+                    ("exclusiveMaximum", numeric_tokens(lit)?)
+                }
+                Ok(MetaNameValueValidation::MinLength) => ("minLength", numeric_tokens(lit)?),
+                Ok(MetaNameValueValidation::MaxLength) => ("maxLength", numeric_tokens(lit)?),
+                Ok(MetaNameValueValidation::MinItems) => ("minItems", numeric_tokens(lit)?),
+                Ok(MetaNameValueValidation::MaxItems) => ("maxItems", numeric_tokens(lit)?),
+                Ok(MetaNameValueValidation::MinProperties) => {
+                    ("minProperties", numeric_tokens(lit)?)
+                }
+                Ok(MetaNameValueValidation::MaxProperties) => {
+                    ("maxProperties", numeric_tokens(lit)?)
+                }
+                Ok(MetaNameValueValidation::MultipleOf) => ("multipleOf", numeric_tokens(lit)?),
+                Ok(MetaNameValueValidation::Pattern) => {
+                    let pattern = get_str(lit)?;
+                    ("pattern", quote!(#pattern))
+                }
+                Ok(MetaNameValueValidation::ConstValue) => {
+                    let const_value = get_literal(lit)?;
+                    ("const", quote!(#const_value))
+                }
+                Ok(MetaNameValueValidation::Format) => {
+                    let format = get_str(lit)?;
+                    ("format", quote!(#format))
+                }
+                Ok(MetaNameValueValidation::Expr) | Err(_) => return Ok(None),
+            };
+            Ok(Some(keyword_tokens(keyword.0, keyword.1)))
+        }
+    }
+}
+
+/// The keyword of `keys(...)` or `values(...)`, which is applied to the subschema of the entries.
+fn map_entries_keyword(
+    entries_keyword: &str,
+    nested: &CommaSeparatedNestedMetas,
+) -> Result<Option<Keyword>, crate::Errors> {
+    let keyword = match nested.first() {
+        Some(syn::NestedMeta::Meta(meta)) => extract_keyword(meta)?,
+        _ => None,
+    };
+
+    Ok(keyword.map(|keyword| {
+        quote!(
+            let __entries_schema = {
+                let mut __schema = match ::std::mem::take(&mut __schema[#entries_keyword]) {
+                    ::serde_valid::json::Value::Null => ::serde_valid::json::json!({}),
+                    __entries_schema => __entries_schema,
+                };
+                #keyword
+                __schema
+            };
+            __schema[#entries_keyword] = __entries_schema;
+        )
+    }))
+}
+
+/// The keyword of `contains(...)`, whose item validation is the subschema of `contains`.
+fn contains_keyword(nested: &CommaSeparatedNestedMetas) -> Result<Option<Keyword>, crate::Errors> {
+    let mut item_keyword = None;
+    let mut count_keywords = vec![];
+    for nested_meta in nested.iter() {
+        match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path, lit, ..
+            })) if path.is_ident("min_contains") => {
+                count_keywords.push(keyword_tokens("minContains", numeric_tokens(lit)?));
+            }
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path, lit, ..
+            })) if path.is_ident("max_contains") => {
+                count_keywords.push(keyword_tokens("maxContains", numeric_tokens(lit)?));
+            }
+            syn::NestedMeta::Meta(meta) if item_keyword.is_none() => {
+                item_keyword = Some(extract_keyword(meta)?);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(item_keyword.flatten().map(|keyword| {
+        quote!(
+            let __contains_schema = {
+                let mut __schema = ::serde_valid::json::json!({});
+                #keyword
+                __schema
+            };
+            ::serde_valid::schema::insert_keyword(&mut __schema, "contains", __contains_schema);
+            #(#count_keywords)*
+        )
+    }))
+}
+
+fn numeric_tokens(lit: &syn::Lit) -> Result<TokenStream, crate::Errors> {
+    let numeric = get_numeric(lit)?;
+    Ok(quote!(#numeric))
+}
+
+fn keyword_tokens(keyword: &str, value: TokenStream) -> Keyword {
+    quote!(
+        ::serde_valid::schema::insert_keyword(
+            &mut __schema,
+            #keyword,
+            ::serde_valid::json::json!(#value),
+        );
+    )
+}
+
+/// The schema expression of the field, with the keywords applied.
+pub fn field_schema_tokens(ty: &syn::Type, keywords: &[Keyword]) -> TokenStream {
+    let schema = quote!(<#ty as ::serde_valid::schema::JsonSchema>::json_schema());
+
+    if keywords.is_empty() {
+        schema
+    } else {
+        quote!({
+            let mut __schema = #schema;
+            #(#keywords)*
+            __schema
+        })
+    }
+}
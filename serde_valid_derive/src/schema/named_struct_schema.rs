@@ -0,0 +1,137 @@
+use super::keyword::{collect_field_keywords_and_conditionals, field_schema_tokens};
+use crate::serde::default::has_serde_default;
+use crate::serde::flatten::has_serde_flatten;
+use crate::serde::rename::RenameMap;
+use crate::serde::skip::has_serde_skip_deserializing;
+use crate::types::{Field, NamedField};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The object schema of the fields.
+///
+/// `container_default` is `#[serde(default)]` of the struct, which makes all the fields optional.
+pub fn expand_named_fields_schema(
+    fields: &syn::FieldsNamed,
+    rename_map: &RenameMap,
+    container_default: bool,
+) -> Result<TokenStream, crate::Errors> {
+    let mut errors = vec![];
+    let mut properties = vec![];
+    let mut required = vec![];
+    let mut flattens = vec![];
+    let mut conditionals = vec![];
+
+    for field in fields.named.iter() {
+        let named_field = NamedField::new(field);
+        if has_serde_skip_deserializing(named_field.attrs()) {
+            continue;
+        }
+        if has_serde_flatten(named_field.attrs()) {
+            let ty = named_field.ty();
+            flattens.push(quote!(<#ty as ::serde_valid::schema::JsonSchema>::json_schema()));
+            continue;
+        }
+
+        let field_key = named_field.key();
+        let rename = rename_map.get(named_field.name()).unwrap_or(&field_key);
+
+        match collect_field_keywords_and_conditionals(&named_field) {
+            Ok((keywords, conditional_keywords)) => {
+                let schema = field_schema_tokens(named_field.ty(), &keywords);
+                properties.push(quote!((::std::string::String::from(#rename), #schema)));
+
+                for (predicate, keyword) in conditional_keywords {
+                    // The other predicates are Rust code, which JSON Schema can't express,
+                    // so the keyword is left out as documented in `Conditional Validation`.
+                    let Some((condition_field, condition_value)) = predicate.field_equals_literal()
+                    else {
+                        continue;
+                    };
+                    let condition_name = condition_field.to_string();
+                    let condition_key = rename_map
+                        .get(&condition_name)
+                        .cloned()
+                        .unwrap_or_else(|| quote!(#condition_name));
+                    let schema = field_schema_tokens(named_field.ty(), &[keyword]);
+                    conditionals.push(quote!({
+                        let __then_schema = #schema;
+                        ::serde_valid::json::json!({
+                            "if": {
+                                "properties": { #condition_key: { "const": #condition_value } },
+                                "required": [#condition_key]
+                            },
+                            "then": {
+                                "properties": { #rename: __then_schema }
+                            }
+                        })
+                    }));
+                }
+            }
+            Err(keyword_errors) => errors.extend(keyword_errors),
+        }
+
+        if !container_default
+            && !is_option_type(named_field.ty())
+            && !has_serde_default(named_field.attrs())
+        {
+            required.push(rename.clone());
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let required = if required.is_empty() {
+        quote!()
+    } else {
+        quote!("required": [#(#required),*],)
+    };
+
+    let conditionals = if conditionals.is_empty() {
+        quote!()
+    } else {
+        quote!(
+            ::serde_valid::schema::insert_keyword(
+                &mut __schema,
+                "allOf",
+                ::serde_valid::json::Value::Array(vec![#(#conditionals),*]),
+            );
+        )
+    };
+
+    Ok(quote!({
+        let __properties: ::serde_valid::json::Map<
+            ::std::string::String,
+            ::serde_valid::json::Value,
+        > = vec![#(#properties),*].into_iter().collect();
+
+        let mut __schema = ::serde_valid::json::json!({
+            "type": "object",
+            "properties": __properties,
+            #required
+        });
+        #conditionals
+        #(::serde_valid::schema::merge_flatten_schema(&mut __schema, #flattens);)*
+        __schema
+    }))
+}
+
+/// Whether the type is `Option`, `std::option::Option` or `core::option::Option`.
+fn is_option_type(ty: &syn::Type) -> bool {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return false;
+    };
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    match segments.as_slice() {
+        [option] => path.leading_colon.is_none() && option == "Option",
+        [krate, module, option] => {
+            (krate == "std" || krate == "core") && module == "option" && option == "Option"
+        }
+        _ => false,
+    }
+}
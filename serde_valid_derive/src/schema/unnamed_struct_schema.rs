@@ -0,0 +1,43 @@
+use super::keyword::{collect_field_keywords, field_schema_tokens};
+use crate::types::{Field, UnnamedField};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn expand_unnamed_fields_schema(
+    fields: &syn::FieldsUnnamed,
+) -> Result<TokenStream, crate::Errors> {
+    let mut errors = vec![];
+
+    let items = fields
+        .unnamed
+        .iter()
+        .enumerate()
+        .filter_map(|(index, field)| {
+            let unnamed_field = UnnamedField::new(index, field);
+            match collect_field_keywords(&unnamed_field) {
+                Ok(keywords) => Some(field_schema_tokens(unnamed_field.ty(), &keywords)),
+                Err(keyword_errors) => {
+                    errors.extend(keyword_errors);
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    if items.len() == 1 {
+        Ok(items[0].clone())
+    } else {
+        let size = items.len();
+        Ok(quote!(::serde_valid::json::json!({
+            "type": "array",
+            "prefixItems": [#((#items)),*],
+            "items": false,
+            "minItems": #size,
+            "maxItems": #size,
+        })))
+    }
+}
@@ -1 +1,84 @@
+pub mod case;
+pub mod default;
+pub mod flatten;
 pub mod rename;
+pub mod skip;
+pub mod tag;
+
+use syn::parse_quote;
+
+/// Whether the attributes have the serde attribute like `#[serde(default)]` or `#[serde(default = "...")]`.
+fn has_serde_path_attribute(attributes: &[syn::Attribute], name: &str) -> bool {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path == parse_quote!(serde))
+        .any(|attribute| match attribute.parse_meta() {
+            Ok(syn::Meta::List(serde_list)) => serde_list.nested.iter().any(|nested_meta| {
+                matches!(
+                    nested_meta,
+                    syn::NestedMeta::Meta(serde_meta) if serde_meta.path().is_ident(name)
+                )
+            }),
+            _ => false,
+        })
+}
+
+/// Find `#[serde(name = "...")]` or `#[serde(name(deserialize = "..."))]`.
+fn find_serde_name_value(attributes: &[syn::Attribute], name: &str) -> Option<syn::LitStr> {
+    let mut value = None;
+    for attribute in attributes {
+        if attribute.path == parse_quote!(serde) {
+            if let Some(serde_value) = find_name_value_from_serde_attributes(attribute, name) {
+                value = Some(serde_value);
+            }
+        }
+    }
+    value
+}
+
+fn find_name_value_from_serde_attributes(
+    attribute: &syn::Attribute,
+    name: &str,
+) -> Option<syn::LitStr> {
+    if let Ok(syn::Meta::List(serde_list)) = attribute.parse_meta() {
+        for serde_nested_meta in serde_list.nested {
+            if let syn::NestedMeta::Meta(serde_meta) = &serde_nested_meta {
+                if !serde_meta.path().is_ident(name) {
+                    continue;
+                }
+                if let Some(value) = find_deserialize_name_value(serde_meta) {
+                    return Some(value);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn find_deserialize_name_value(serde_meta: &syn::Meta) -> Option<syn::LitStr> {
+    match serde_meta {
+        syn::Meta::NameValue(name_value) => {
+            if let syn::Lit::Str(lit_str) = &name_value.lit {
+                Some(lit_str.clone())
+            } else {
+                None
+            }
+        }
+        syn::Meta::List(list) => {
+            for nested_meta in &list.nested {
+                if let syn::NestedMeta::Meta(meta) = nested_meta {
+                    if *meta.path() != parse_quote!(deserialize) {
+                        continue;
+                    }
+                    if let syn::Meta::NameValue(deserialize_name_value) = meta {
+                        if let syn::Lit::Str(lit_str) = &deserialize_name_value.lit {
+                            return Some(lit_str.clone());
+                        }
+                    }
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,5 @@
+use super::has_serde_path_attribute;
+
+pub fn has_serde_flatten(attributes: &[syn::Attribute]) -> bool {
+    has_serde_path_attribute(attributes, "flatten")
+}
@@ -1,64 +1,45 @@
 use std::collections::HashMap;
+use std::str::FromStr;
 
+use super::case::RenameRule;
+use super::find_serde_name_value;
 use crate::types::{Field, NamedField};
 use proc_macro2::TokenStream;
-use quote::ToTokens;
-use syn::parse_quote;
+use quote::{quote, ToTokens};
 
 pub type RenameMap = HashMap<String, TokenStream>;
 
-pub fn collect_serde_rename_map(fields: &syn::FieldsNamed) -> RenameMap {
+pub fn collect_serde_rename_map(
+    fields: &syn::FieldsNamed,
+    rename_all: Option<RenameRule>,
+) -> RenameMap {
     let mut renames = RenameMap::new();
     for field in fields.named.iter() {
         let named_field = NamedField::new(field);
-        for attribute in named_field.attrs() {
-            if attribute.path == parse_quote!(serde) {
-                if let Some(rename) = find_rename_from_serde_attributes(attribute) {
-                    renames.insert(field.ident.to_token_stream().to_string(), rename);
-                }
-            }
+        if let Some(rename) = find_serde_rename(named_field.attrs()) {
+            renames.insert(named_field.name().to_owned(), rename);
+        } else if let Some(rename_all) = rename_all {
+            let rename = rename_all.apply_to_field(named_field.name());
+            renames.insert(named_field.name().to_owned(), quote!(#rename));
         }
     }
     renames
 }
 
-fn find_rename_from_serde_attributes(attribute: &syn::Attribute) -> Option<TokenStream> {
-    if let Ok(syn::Meta::List(serde_list)) = attribute.parse_meta() {
-        for serde_nested_meta in serde_list.nested {
-            if let syn::NestedMeta::Meta(serde_meta) = &serde_nested_meta {
-                if let Some(rename) = find_rename_from_serde_rename_attributes(serde_meta) {
-                    return Some(rename);
-                }
-            }
-        }
-    }
-    None
+/// Find `#[serde(rename = "...")]` or `#[serde(rename(deserialize = "..."))]`.
+pub fn find_serde_rename(attributes: &[syn::Attribute]) -> Option<TokenStream> {
+    find_serde_name_value(attributes, "rename").map(|lit_str| lit_str.to_token_stream())
 }
 
-fn find_rename_from_serde_rename_attributes(serde_meta: &syn::Meta) -> Option<TokenStream> {
-    match serde_meta {
-        syn::Meta::NameValue(rename_name_value) => {
-            if let syn::Lit::Str(lit_str) = &rename_name_value.lit {
-                Some(lit_str.to_token_stream())
-            } else {
-                None
-            }
-        }
-        syn::Meta::List(rename_list) => {
-            for rename_nested_meta in &rename_list.nested {
-                if let syn::NestedMeta::Meta(rename_meta) = rename_nested_meta {
-                    if *rename_meta.path() != parse_quote!(deserialize) {
-                        continue;
-                    }
-                    if let syn::Meta::NameValue(deserialize_name_value) = rename_meta {
-                        if let syn::Lit::Str(lit_str) = &deserialize_name_value.lit {
-                            return Some(lit_str.to_token_stream());
-                        }
-                    }
-                }
-            }
-            None
-        }
-        _ => None,
-    }
+/// Find `#[serde(rename_all = "...")]` or `#[serde(rename_all(deserialize = "..."))]`.
+pub fn find_serde_rename_all(attributes: &[syn::Attribute]) -> Option<RenameRule> {
+    find_serde_name_value(attributes, "rename_all")
+        .and_then(|lit_str| RenameRule::from_str(&lit_str.value()).ok())
+}
+
+/// Find `#[serde(rename_all_fields = "...")]` of the enum,
+/// which applies to the fields of all the struct variants.
+pub fn find_serde_rename_all_fields(attributes: &[syn::Attribute]) -> Option<RenameRule> {
+    find_serde_name_value(attributes, "rename_all_fields")
+        .and_then(|lit_str| RenameRule::from_str(&lit_str.value()).ok())
 }
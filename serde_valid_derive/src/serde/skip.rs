@@ -0,0 +1,7 @@
+use super::has_serde_path_attribute;
+
+/// Whether `#[serde(skip)]` or `#[serde(skip_deserializing)]` leaves the input out.
+pub fn has_serde_skip_deserializing(attributes: &[syn::Attribute]) -> bool {
+    has_serde_path_attribute(attributes, "skip")
+        || has_serde_path_attribute(attributes, "skip_deserializing")
+}
@@ -0,0 +1,29 @@
+use super::{find_serde_name_value, has_serde_path_attribute};
+
+/// The representation of the enum by `#[serde(tag = "...")]`, `#[serde(content = "...")]`
+/// or `#[serde(untagged)]`.
+pub enum EnumTag {
+    External,
+    Internal {
+        tag: syn::LitStr,
+    },
+    Adjacent {
+        tag: syn::LitStr,
+        content: syn::LitStr,
+    },
+    Untagged,
+}
+
+pub fn find_serde_enum_tag(attributes: &[syn::Attribute]) -> EnumTag {
+    if has_serde_path_attribute(attributes, "untagged") {
+        return EnumTag::Untagged;
+    }
+    match (
+        find_serde_name_value(attributes, "tag"),
+        find_serde_name_value(attributes, "content"),
+    ) {
+        (Some(tag), Some(content)) => EnumTag::Adjacent { tag, content },
+        (Some(tag), None) => EnumTag::Internal { tag },
+        _ => EnumTag::External,
+    }
+}
@@ -1,7 +1,7 @@
 mod field;
 mod single_ident_path;
 
-pub use field::{Field, NamedField, UnnamedField};
+pub use field::{ContainsItemField, Field, MapEntry, MapEntryField, NamedField, UnnamedField};
 use proc_macro2::TokenStream;
 pub use single_ident_path::SingleIdentPath;
 
@@ -1,6 +1,10 @@
+mod contains_item;
+mod map_entry;
 mod named;
 mod unnamed;
 
+pub use contains_item::ContainsItemField;
+pub use map_entry::{MapEntry, MapEntryField};
 pub use named::NamedField;
 pub use unnamed::UnnamedField;
 
@@ -17,7 +21,5 @@ pub trait Field {
 
     fn attrs(&self) -> &Vec<syn::Attribute>;
 
-    fn vis(&self) -> &syn::Visibility;
-
     fn ty(&self) -> &syn::Type;
 }
@@ -0,0 +1,55 @@
+use super::Field;
+use quote::quote;
+
+/// The field of `#[validate(contains(...))]`, which is bound to each item of the array.
+#[derive(Debug, Clone)]
+pub struct ContainsItemField<'a> {
+    name: String,
+    ident: syn::Ident,
+    attrs: &'a Vec<syn::Attribute>,
+    ty: &'a syn::Type,
+}
+
+impl<'a> ContainsItemField<'a> {
+    pub fn new(field: &'a impl Field) -> Self {
+        let name = "__contains_item";
+        Self {
+            name: name.to_owned(),
+            ident: syn::Ident::new(name, proc_macro2::Span::call_site()),
+            attrs: field.attrs(),
+            ty: field.ty(),
+        }
+    }
+}
+
+impl<'a> Field for ContainsItemField<'a> {
+    fn name(&self) -> &String {
+        &self.name
+    }
+
+    fn ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    fn key(&self) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        quote!(#name)
+    }
+
+    fn errors_variable(&self) -> proc_macro2::TokenStream {
+        quote!(__contains_vec_errors_map)
+    }
+
+    fn getter_token(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        quote!(#ident)
+    }
+
+    fn attrs(&self) -> &Vec<syn::Attribute> {
+        self.attrs
+    }
+
+    fn ty(&self) -> &syn::Type {
+        self.ty
+    }
+}
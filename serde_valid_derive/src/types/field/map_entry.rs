@@ -0,0 +1,65 @@
+use super::Field;
+use quote::quote;
+
+/// The key or the value of the map entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapEntry {
+    Key,
+    Value,
+}
+
+/// The field of `#[validate(keys(...))]` or `#[validate(values(...))]`,
+/// which is bound to each entry of the map, and whose errors are keyed by the entry's key.
+#[derive(Debug, Clone)]
+pub struct MapEntryField<'a> {
+    name: String,
+    ident: syn::Ident,
+    attrs: &'a Vec<syn::Attribute>,
+    ty: &'a syn::Type,
+}
+
+impl<'a> MapEntryField<'a> {
+    pub fn new(field: &'a impl Field, entry: MapEntry) -> Self {
+        let name = match entry {
+            MapEntry::Key => "__map_key",
+            MapEntry::Value => "__map_value",
+        };
+        Self {
+            name: name.to_owned(),
+            ident: syn::Ident::new(name, proc_macro2::Span::call_site()),
+            attrs: field.attrs(),
+            ty: field.ty(),
+        }
+    }
+}
+
+impl<'a> Field for MapEntryField<'a> {
+    fn name(&self) -> &String {
+        &self.name
+    }
+
+    fn ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    fn key(&self) -> proc_macro2::TokenStream {
+        quote!(::std::string::ToString::to_string(__map_key))
+    }
+
+    fn errors_variable(&self) -> proc_macro2::TokenStream {
+        quote!(__property_vec_errors_map)
+    }
+
+    fn getter_token(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        quote!(#ident)
+    }
+
+    fn attrs(&self) -> &Vec<syn::Attribute> {
+        self.attrs
+    }
+
+    fn ty(&self) -> &syn::Type {
+        self.ty
+    }
+}
@@ -49,10 +49,6 @@ impl<'a> Field for NamedField<'a> {
         self.field.attrs.as_ref()
     }
 
-    fn vis(&self) -> &syn::Visibility {
-        &self.field.vis
-    }
-
     fn ty(&self) -> &syn::Type {
         &self.field.ty
     }
@@ -54,10 +54,6 @@ impl<'a> Field for UnnamedField<'a> {
         self.field.attrs.as_ref()
     }
 
-    fn vis(&self) -> &syn::Visibility {
-        &self.field.vis
-    }
-
     fn ty(&self) -> &syn::Type {
         &self.field.ty
     }
@@ -1,11 +1,15 @@
 mod array;
-mod common;
+pub mod common;
 mod field;
 mod generic;
+mod groups;
 mod meta;
 mod numeric;
 mod object;
 mod string;
+mod when;
 
 pub use field::{FieldValidators, Validator};
-pub use meta::extract_meta_validator;
+pub use groups::has_validation_groups;
+pub use meta::{extract_meta_validator, is_validate_attribute_of, uses_validation_group};
+pub use when::{split_when_predicate, WhenPredicate};
@@ -0,0 +1,55 @@
+use crate::{
+    serde::rename::RenameMap,
+    types::Field,
+    validate::{common::new_message_tokens, Validator},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Contains validation of the array items.
+///
+/// The item validator is evaluated as the predicate of each item,
+/// and its errors are only used to count the matching items.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/array.html#contains>
+pub fn extract_array_contains_validator(
+    field: &impl Field,
+    item_validator: Validator,
+    min_contains: Option<usize>,
+    max_contains: Option<usize>,
+    message_fn: Option<TokenStream>,
+    rename_map: &RenameMap,
+) -> Validator {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let message = new_message_tokens(quote!(error_params), message_fn);
+    let min_contains = min_contains.unwrap_or(1);
+    let max_contains = match max_contains {
+        Some(max_contains) => quote!(::std::option::Option::Some(#max_contains)),
+        None => quote!(::std::option::Option::None),
+    };
+
+    quote!(
+        if let Err(error_params) = ::serde_valid::ValidateContains::validate_contains(
+            #field_ident,
+            #min_contains,
+            #max_contains,
+            |__contains_item| {
+                let mut __contains_vec_errors_map =
+                    ::serde_valid::validation::PropertyVecErrorsMap::new();
+                #item_validator
+                __contains_vec_errors_map.is_empty()
+            },
+        ) {
+            #errors
+                .entry(#rename.into())
+                .or_default()
+                .push(::serde_valid::validation::Error::Contains(
+                    #message
+                ));
+        }
+    )
+}
@@ -1,6 +1,6 @@
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
-use crate::validate::common::get_numeric;
+use crate::validate::common::{get_numeric, new_message_tokens};
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -31,8 +31,7 @@ macro_rules! extract_array_length_validator{
                 let field_key = field.key();
                 let rename = rename_map.get(field_name).unwrap_or(&field_key);
                 let [<$ErrorType:snake>] = get_numeric(validation_value)?;
-                let message =
-                    message_fn.unwrap_or(quote!(::serde_valid::[<$ErrorType ErrorParams>]::to_default_message));
+                let message = new_message_tokens(quote!(error_params), message_fn);
                 let errors = field.errors_variable();
 
                 Ok(quote!(
@@ -40,15 +39,11 @@ macro_rules! extract_array_length_validator{
                         #field_ident,
                         #[<$ErrorType:snake>],
                     ) {
-                        use ::serde_valid::error::ToDefaultMessage;
                         #errors
-                            .entry(#rename)
+                            .entry(#rename.into())
                             .or_default()
                             .push(::serde_valid::validation::Error::$ErrorType(
-                                ::serde_valid::error::Message::new(
-                                    error_params,
-                                    #message
-                                )
+                                #message
                             ));
                     }
                 ))
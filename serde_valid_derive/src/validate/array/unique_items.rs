@@ -1,4 +1,8 @@
-use crate::{serde::rename::RenameMap, types::Field, validate::Validator};
+use crate::{
+    serde::rename::RenameMap,
+    types::Field,
+    validate::{common::new_message_tokens, Validator},
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -20,23 +24,17 @@ fn inner_extract_array_unique_items_validator(
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
-    let message = message_fn.unwrap_or(quote!(
-        ::serde_valid::UniqueItemsErrorParams::to_default_message
-    ));
+    let message = new_message_tokens(quote!(error_params), message_fn);
 
     quote!(
         if let Err(error_params) = ::serde_valid::ValidateUniqueItems::validate_unique_items(
             #field_ident
         ) {
-            use ::serde_valid::error::ToDefaultMessage;
             #errors
-                .entry(#rename)
+                .entry(#rename.into())
                 .or_default()
                 .push(::serde_valid::validation::Error::UniqueItems(
-                    ::serde_valid::error::Message::new(
-                        error_params,
-                        #message
-                    )
+                    #message
                 ));
         }
     )
@@ -1,8 +1,8 @@
 mod lit;
 mod message;
 
-pub use lit::{get_numeric, get_str};
-pub use message::extract_message_fn_tokens;
+pub use lit::{get_literal, get_numeric, get_str};
+pub use message::{extract_message_fn_tokens, into_error_tokens, new_message_tokens};
 
 macro_rules! count {
     () => (0usize);
@@ -71,6 +71,10 @@ enum_str! {
     pub enum MetaListValidation {
         Enumerate = "enumerate",
         Custom = "custom",
+        CustomAsync = "custom_async",
+        Keys = "keys",
+        Values = "values",
+        Contains = "contains",
     }
 }
 
@@ -88,6 +92,9 @@ enum_str! {
         MaxProperties = "max_properties",
         MultipleOf = "multiple_of",
         Pattern = "pattern",
+        Format = "format",
+        ConstValue = "const_value",
+        Expr = "expr",
     }
 }
 
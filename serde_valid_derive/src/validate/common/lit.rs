@@ -15,7 +15,7 @@ impl<'a> ToTokens for LitNumeric<'a> {
     }
 }
 
-pub fn get_numeric(lit: &syn::Lit) -> Result<LitNumeric, crate::Errors> {
+pub fn get_numeric(lit: &syn::Lit) -> Result<LitNumeric<'_>, crate::Errors> {
     match lit {
         syn::Lit::Int(int) => Ok(LitNumeric::Int(int)),
         syn::Lit::Float(float) => Ok(LitNumeric::Float(float)),
@@ -29,3 +29,15 @@ pub fn get_str(lit: &syn::Lit) -> Result<&syn::LitStr, crate::Errors> {
         _ => Err(vec![crate::Error::str_literal_only(lit)]),
     }
 }
+
+/// The literal which can be the value of `serde_valid::Literal`.
+pub fn get_literal(lit: &syn::Lit) -> Result<&syn::Lit, crate::Errors> {
+    match lit {
+        syn::Lit::Str(_)
+        | syn::Lit::Int(_)
+        | syn::Lit::Float(_)
+        | syn::Lit::Bool(_)
+        | syn::Lit::Char(_) => Ok(lit),
+        _ => Err(vec![crate::Error::literal_not_support(lit)]),
+    }
+}
@@ -3,10 +3,14 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use std::str::FromStr;
 
-use super::{get_str, MetaListMessage, MetaNameValueMessage, MetaPathMessage};
+use super::{
+    get_str, MetaListMessage, MetaListValidation, MetaNameValueMessage, MetaNameValueValidation,
+    MetaPathMessage, MetaPathValidation,
+};
 
 pub fn extract_message_fn_tokens(
     nested_meta: &syn::NestedMeta,
+    validation_name: &str,
 ) -> Result<TokenStream, crate::Errors> {
     match nested_meta {
         syn::NestedMeta::Meta(meta) => match meta {
@@ -14,7 +18,7 @@ pub fn extract_message_fn_tokens(
                 extract_message_fn_tokens_from_meta_list(message_fn_list)
             }
             syn::Meta::NameValue(name_value) => {
-                extract_message_fn_tokens_from_name_value(name_value)
+                extract_message_fn_tokens_from_name_value(name_value, validation_name)
             }
             syn::Meta::Path(path) => {
                 let path_label = SingleIdentPath::new(path).ident().to_string();
@@ -78,12 +82,15 @@ fn extract_message_fn_tokens_from_meta_list(
 
 fn extract_message_fn_tokens_from_name_value(
     syn::MetaNameValue { path, lit, .. }: &syn::MetaNameValue,
+    validation_name: &str,
 ) -> Result<TokenStream, crate::Errors> {
     let path_ident = SingleIdentPath::new(path).ident();
     let path_label = path_ident.to_string();
 
     match MetaNameValueMessage::from_str(&path_label) {
-        Ok(MetaNameValueMessage::Message) => get_message_fn_from_lit(lit),
+        Ok(MetaNameValueMessage::Message) => {
+            get_message_fn_from_lit(lit, error_params_fields(validation_name))
+        }
         Err(unknown) => if MetaListMessage::from_str(&path_label).is_ok() {
             Err(crate::Error::validate_meta_list_need_value(
                 path,
@@ -128,6 +135,150 @@ fn get_message_fn_from_nested_meta(
     }
 }
 
-fn get_message_fn_from_lit(lit: &syn::Lit) -> Result<TokenStream, crate::Errors> {
-    get_str(lit).map(|lit_str| quote!(|_| { #lit_str.to_string() }))
+/// The method call to convert the composited error params into the error.
+///
+/// See [`new_message_tokens`] for the default message.
+pub fn into_error_tokens(message_fn: Option<TokenStream>) -> TokenStream {
+    match message_fn {
+        Some(message_fn) => quote!(into_error_by(#message_fn)),
+        None => quote!(into_error()),
+    }
+}
+
+/// The message of the error params.
+///
+/// Without `message` or `message_fn`, the default message is kept
+/// so that the message catalog can replace it.
+pub fn new_message_tokens(params: TokenStream, message_fn: Option<TokenStream>) -> TokenStream {
+    match message_fn {
+        Some(message_fn) => quote!(::serde_valid::error::Message::new(#params, #message_fn)),
+        None => quote!(::serde_valid::error::Message::new_default(#params)),
+    }
+}
+
+/// Build the message function from the template like `"must be <= {maximum}."`.
+///
+/// The placeholders are the fields of the `*ErrorParams`, and `{{` / `}}` are the escaped braces.
+fn get_message_fn_from_lit(
+    lit: &syn::Lit,
+    fields: &[&'static str],
+) -> Result<TokenStream, crate::Errors> {
+    let lit_str = get_str(lit)?;
+    let template = lit_str.value();
+
+    let mut format = String::new();
+    let mut args = vec![];
+    let mut chars = template.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                format.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                format.push_str("}}");
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                let mut closed = false;
+                for ch in chars.by_ref() {
+                    if ch == '}' {
+                        closed = true;
+                        break;
+                    }
+                    placeholder.push(ch);
+                }
+                if !closed {
+                    return Err(vec![crate::Error::message_template_unclosed_placeholder(
+                        lit_str,
+                    )]);
+                }
+
+                let (name, spec) = match placeholder.split_once(':') {
+                    Some((name, spec)) => (name.trim(), Some(spec)),
+                    None => (placeholder.trim(), None),
+                };
+                if !fields.contains(&name) {
+                    return Err(vec![crate::Error::message_template_unknown_placeholder(
+                        lit_str, name, fields,
+                    )]);
+                }
+
+                let field = syn::Ident::new(name, lit_str.span());
+                args.push(if LIST_FIELDS.contains(&name) {
+                    quote!(::serde_valid::error::render_param_value(&__params.#field))
+                } else if OPTION_FIELDS.contains(&name) {
+                    quote!(__params
+                        .#field
+                        .map(|__value| __value.to_string())
+                        .unwrap_or_default())
+                } else {
+                    quote!(__params.#field)
+                });
+                match spec {
+                    Some(spec) => format.push_str(&format!("{{:{spec}}}")),
+                    None => format.push_str("{}"),
+                }
+            }
+            '}' => {
+                return Err(vec![crate::Error::message_template_unmatched_brace(
+                    lit_str,
+                )]);
+            }
+            _ => format.push(ch),
+        }
+    }
+
+    if args.is_empty() {
+        let message = template.replace("{{", "{").replace("}}", "}");
+        Ok(quote!(|_| { #message.to_string() }))
+    } else {
+        let format = syn::LitStr::new(&format, lit_str.span());
+        Ok(quote!(|__params| { format!(#format, #(#args),*) }))
+    }
+}
+
+/// The fields of the list type, which are joined with `", "` in the message.
+const LIST_FIELDS: [&str; 2] = ["enumerate", "duplicates"];
+
+/// The fields of the `Option` type, which are empty in the message if they are `None`.
+const OPTION_FIELDS: [&str; 1] = ["max_contains"];
+
+/// The fields of the `*ErrorParams` of the validation.
+fn error_params_fields(validation_name: &str) -> &'static [&'static str] {
+    if let Ok(validation) = MetaNameValueValidation::from_str(validation_name) {
+        match validation {
+            MetaNameValueValidation::Minimum => &["minimum", "value"],
+            MetaNameValueValidation::Maximum => &["maximum", "value"],
+            MetaNameValueValidation::ExclusiveMinimum => &["exclusive_minimum", "value"],
+            MetaNameValueValidation::ExclusiveMaximum => &["exclusive_maximum", "value"],
+            MetaNameValueValidation::MinLength => &["min_length", "length"],
+            MetaNameValueValidation::MaxLength => &["max_length", "length"],
+            MetaNameValueValidation::MinItems => &["min_items", "length"],
+            MetaNameValueValidation::MaxItems => &["max_items", "length"],
+            MetaNameValueValidation::MinProperties => &["min_properties", "size"],
+            MetaNameValueValidation::MaxProperties => &["max_properties", "size"],
+            MetaNameValueValidation::MultipleOf => &["multiple_of", "value"],
+            MetaNameValueValidation::Pattern => &["pattern"],
+            MetaNameValueValidation::Format => &["format"],
+            MetaNameValueValidation::ConstValue => &["const_value", "value"],
+            MetaNameValueValidation::Expr => &[],
+        }
+    } else if let Ok(validation) = MetaListValidation::from_str(validation_name) {
+        match validation {
+            MetaListValidation::Enumerate => &["enumerate", "value"],
+            MetaListValidation::Contains => &["min_contains", "max_contains", "matches"],
+            MetaListValidation::Custom
+            | MetaListValidation::CustomAsync
+            | MetaListValidation::Keys
+            | MetaListValidation::Values => &[],
+        }
+    } else if let Ok(validation) = MetaPathValidation::from_str(validation_name) {
+        match validation {
+            MetaPathValidation::UniqueItems => &["duplicates"],
+        }
+    } else {
+        &[]
+    }
 }
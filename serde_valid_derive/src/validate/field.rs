@@ -9,17 +9,27 @@ pub type Validator = TokenStream;
 pub struct FieldValidators<'a, F: Field + Clone + 'a> {
     field: Cow<'a, F>,
     validators: Vec<Validator>,
+    uses_group: bool,
 }
 
 impl<'a, F: Field + Clone> FieldValidators<'a, F> {
-    pub fn new(field: Cow<'a, F>, validators: Vec<Validator>) -> Self {
-        Self { field, validators }
+    pub fn new(field: Cow<'a, F>, validators: Vec<Validator>, uses_group: bool) -> Self {
+        Self {
+            field,
+            validators,
+            uses_group,
+        }
     }
 
     pub fn ident(&self) -> &syn::Ident {
         self.field.ident()
     }
 
+    /// Whether the validators refer to the active group of `Validate::validate_group`.
+    pub fn uses_group(&self) -> bool {
+        self.uses_group
+    }
+
     pub fn is_empty(&self) -> bool {
         self.validators.is_empty()
     }
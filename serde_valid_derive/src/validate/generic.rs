@@ -1,5 +1,13 @@
+mod const_value;
 mod custom;
 mod enumerate;
+mod expr;
 
-pub use custom::extract_generic_custom_validator;
+pub use const_value::extract_generic_const_value_validator;
+pub use custom::{
+    custom_fn_args_tokens, extract_generic_custom_async_validator,
+    extract_generic_custom_closure_validator, extract_generic_custom_validator,
+    split_custom_fn_args,
+};
 pub use enumerate::extract_generic_enumerate_validator;
+pub use expr::extract_generic_expr_validator;
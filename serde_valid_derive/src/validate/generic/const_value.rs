@@ -0,0 +1,44 @@
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use crate::validate::common::{get_literal, into_error_tokens};
+use crate::validate::Validator;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn extract_generic_const_value_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_fn: Option<TokenStream>,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_generic_const_value_validator(field, validation_value, message_fn, rename_map)
+}
+
+fn inner_extract_generic_const_value_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_fn: Option<TokenStream>,
+    rename_map: &RenameMap,
+) -> Result<TokenStream, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let const_value = get_literal(validation_value)?;
+    let into_error = into_error_tokens(message_fn);
+
+    Ok(quote!(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedConstValue::validate_composited_const_value(
+            #field_ident,
+            #const_value,
+        ) {
+            use ::serde_valid::validation::IntoError;
+
+            #errors
+                .entry(#rename.into())
+                .or_default()
+                .push(__composited_error_params.#into_error);
+        }
+    ))
+}
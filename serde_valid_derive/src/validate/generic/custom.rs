@@ -1,49 +1,228 @@
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
+use crate::validate::when::split_comma_separated;
 use crate::validate::Validator;
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use std::iter::FromIterator;
+use syn::spanned::Spanned;
+
+/// Split the arguments of `#[validate(custom(path_to_fn(arg, ...)))]` from the attribute.
+///
+/// The arguments are any expressions like `1 + 2`, which are not valid meta items,
+/// so they are replaced by the variables bound in [`custom_fn_args_tokens`] before parsing.
+pub fn split_custom_fn_args(attribute: &syn::Attribute) -> (syn::Attribute, Vec<syn::Expr>) {
+    let group = match attribute.tokens.clone().into_iter().next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => group,
+        _ => return (attribute.clone(), vec![]),
+    };
+
+    let mut items = split_comma_separated(group.stream());
+    let (item, args) = match items.first().and_then(custom_fn_args_item) {
+        Some(split) => split,
+        None => return (attribute.clone(), vec![]),
+    };
+    items[0] = item;
+
+    let mut tokens = proc_macro2::Group::new(Delimiter::Parenthesis, quote!(#(#items),*));
+    tokens.set_span(group.span());
+
+    (
+        syn::Attribute {
+            tokens: TokenStream::from(TokenTree::Group(tokens)),
+            ..attribute.clone()
+        },
+        args,
+    )
+}
+
+/// Bind the arguments split by [`split_custom_fn_args`] around the validator.
+pub fn custom_fn_args_tokens(args: &[syn::Expr], validator: TokenStream) -> TokenStream {
+    if args.is_empty() {
+        return validator;
+    }
+    let bindings = args.iter().enumerate().map(|(index, arg)| {
+        let variable = custom_fn_arg_variable(index, arg);
+        quote!(let #variable = #arg;)
+    });
+    quote!({
+        #(#bindings)*
+        #validator
+    })
+}
+
+/// Rewrite `custom(path_to_fn(arg, ...))` to `custom(path_to_fn(__custom_fn_arg_0, ...))`.
+fn custom_fn_args_item(item: &TokenStream) -> Option<(TokenStream, Vec<syn::Expr>)> {
+    let mut tokens = item.clone().into_iter();
+    let (name, group) = match (tokens.next(), tokens.next(), tokens.next()) {
+        (Some(TokenTree::Ident(name)), Some(TokenTree::Group(group)), None)
+            if (name == "custom" || name == "custom_async")
+                && group.delimiter() == Delimiter::Parenthesis =>
+        {
+            (name, group)
+        }
+        _ => return None,
+    };
+
+    let (fn_name, args) = syn::parse::Parser::parse2(
+        |input: syn::parse::ParseStream| {
+            let fn_name: syn::Path = input.parse()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let args = syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(
+                &content,
+            )?;
+            Ok((fn_name, args))
+        },
+        group.stream(),
+    )
+    .ok()?;
+
+    let args = args.into_iter().collect::<Vec<_>>();
+    let variables = args
+        .iter()
+        .enumerate()
+        .map(|(index, arg)| custom_fn_arg_variable(index, arg));
+    let mut fn_group =
+        proc_macro2::Group::new(Delimiter::Parenthesis, quote!(#fn_name(#(#variables),*)));
+    fn_group.set_span(group.span());
+
+    Some((quote!(#name #fn_group), args))
+}
+
+fn custom_fn_arg_variable(index: usize, arg: &syn::Expr) -> syn::Ident {
+    let mut variable = format_ident!("__custom_fn_arg_{}", index);
+    variable.set_span(arg.span());
+    variable
+}
 
 pub fn extract_generic_custom_validator(
     field: &impl Field,
-    syn::MetaList { path, nested, .. }: &syn::MetaList,
+    validation_list: &syn::MetaList,
+    rename_map: &RenameMap,
+    with_context: bool,
+) -> Result<Validator, crate::Errors> {
+    let field_ident = field.ident();
+    let (custom_fn_name, custom_fn_args) = extract_custom_fn_from_list(validation_list)?;
+
+    let custom_fn_args = if with_context {
+        quote!(#field_ident, #custom_fn_args __context)
+    } else {
+        quote!(#field_ident, #custom_fn_args)
+    };
+
+    Ok(custom_validator_tokens(
+        field,
+        quote!(#custom_fn_name(#custom_fn_args)),
+        rename_map,
+    ))
+}
+
+pub fn extract_generic_custom_async_validator(
+    field: &impl Field,
+    validation_list: &syn::MetaList,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
-    let field_name = field.name();
     let field_ident = field.ident();
+    let (custom_fn_name, custom_fn_args) = extract_custom_fn_from_list(validation_list)?;
+
+    Ok(custom_validator_tokens(
+        field,
+        quote!(#custom_fn_name(#field_ident, #custom_fn_args).await),
+        rename_map,
+    ))
+}
+
+/// Extract `#[validate(custom = |value| ...)]`, which can not be parsed as `syn::Meta`.
+///
+/// Returns `None` when the attribute is not the closure form.
+pub fn extract_generic_custom_closure_validator(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    rename_map: &RenameMap,
+) -> Option<Result<Validator, crate::Errors>> {
+    let is_closure_form = attribute
+        .parse_args_with(|input: syn::parse::ParseStream| {
+            let path: syn::Path = input.parse()?;
+            input.parse::<syn::Token![=]>()?;
+            input.parse::<TokenStream>()?;
+            Ok(path.is_ident("custom"))
+        })
+        .unwrap_or(false);
+    if !is_closure_form {
+        return None;
+    }
+
+    let field_ident = field.ident();
+    let closure = match attribute.parse_args_with(|input: syn::parse::ParseStream| {
+        input.parse::<syn::Path>()?;
+        input.parse::<syn::Token![=]>()?;
+        input.parse::<syn::ExprClosure>()
+    }) {
+        Ok(closure) => closure,
+        Err(error) => {
+            return Some(Err(vec![
+                crate::Error::validate_custom_closure_parse_error(attribute, &error),
+            ]))
+        }
+    };
+
+    Some(Ok(custom_validator_tokens(
+        field,
+        quote!((#closure)(#field_ident)),
+        rename_map,
+    )))
+}
+
+fn custom_validator_tokens(
+    field: &impl Field,
+    custom_fn_call: TokenStream,
+    rename_map: &RenameMap,
+) -> Validator {
+    let field_name = field.name();
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
 
-    let custom_fn_name = match nested.len() {
-        0 => Err(crate::Error::validate_custom_need_item(path)),
-        1 => extract_custom_fn_name(&nested[0]),
-        _ => Err(crate::Error::validate_custom_tail_error(nested)),
-    }
-    .map_err(|error| vec![error])?;
-
-    Ok(quote!(
-        if let Err(__error) = #custom_fn_name(#field_ident) {
+    quote!(
+        if let Err(__errors) = #custom_fn_call {
             #errors
-                .entry(#rename)
+                .entry(#rename.into())
                 .or_default()
-                .push(__error);
+                .extend(::serde_valid::validation::IntoVecErrors::into_vec_errors(__errors));
         };
-    ))
+    )
 }
 
-fn extract_custom_fn_name(nested_meta: &syn::NestedMeta) -> Result<TokenStream, crate::Error> {
+/// Extract the function name and the trailing arguments, each followed by a comma.
+fn extract_custom_fn_from_list(
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+) -> Result<(TokenStream, TokenStream), crate::Errors> {
+    match nested.len() {
+        0 => Err(vec![crate::Error::validate_custom_need_item(path)]),
+        1 => extract_custom_fn(&nested[0]),
+        _ => Err(vec![crate::Error::validate_custom_tail_error(nested)]),
+    }
+}
+
+fn extract_custom_fn(
+    nested_meta: &syn::NestedMeta,
+) -> Result<(TokenStream, TokenStream), crate::Errors> {
     match nested_meta {
         syn::NestedMeta::Meta(meta) => match meta {
             syn::Meta::List(list) => {
                 let fn_name = &list.path;
-                Ok(quote!(#fn_name))
+                Ok((quote!(#fn_name), extract_custom_fn_args(list)))
             }
             syn::Meta::NameValue(name_value) => {
-                Err(crate::Error::meta_name_value_not_support(name_value))
+                Err(vec![crate::Error::meta_name_value_not_support(name_value)])
             }
-            syn::Meta::Path(fn_name) => Ok(quote!(#fn_name)),
+            syn::Meta::Path(fn_name) => Ok((quote!(#fn_name), quote!())),
         },
-        syn::NestedMeta::Lit(lit) => Err(crate::Error::literal_not_support(lit)),
+        syn::NestedMeta::Lit(lit) => Err(vec![crate::Error::literal_not_support(lit)]),
     }
 }
+
+fn extract_custom_fn_args(syn::MetaList { nested, .. }: &syn::MetaList) -> TokenStream {
+    TokenStream::from_iter(nested.iter().map(|arg| quote!(#arg,)))
+}
@@ -1,5 +1,6 @@
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
+use crate::validate::common::into_error_tokens;
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -27,30 +28,26 @@ fn inner_extract_generic_enumerate_validator(
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
     let enumerate = get_enumerate(item_list)?;
-    let message = message_fn.unwrap_or(quote!(
-        ::serde_valid::EnumerateErrorParams::to_default_message
-    ));
+    let into_error = into_error_tokens(message_fn);
 
     Ok(quote!(
         if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedEnumerate::validate_composited_enumerate(
             #field_ident,
             &[#enumerate],
         ) {
-            use ::serde_valid::error::ToDefaultMessage;
             use ::serde_valid::validation::IntoError;
 
             #errors
-                .entry(#rename)
+                .entry(#rename.into())
                 .or_default()
-                .push(__composited_error_params.into_error_by(#message)
-            );
+                .push(__composited_error_params.#into_error);
         }
     ))
 }
 
 fn get_enumerate(
     syn::MetaList { path, nested, .. }: &syn::MetaList,
-) -> Result<Lits, crate::Errors> {
+) -> Result<Lits<'_>, crate::Errors> {
     let mut errors = vec![];
     let mut enumerate = Lits::new();
 
@@ -0,0 +1,39 @@
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use crate::validate::common::get_str;
+use crate::validate::Validator;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn extract_generic_expr_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_fn: Option<TokenStream>,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+
+    let expr_str = get_str(validation_value)?;
+    let expr = expr_str
+        .parse::<syn::Expr>()
+        .map_err(|error| vec![crate::Error::validate_expr_parse_error(expr_str, &error)])?;
+    let message = match message_fn {
+        Some(message_fn) => quote!((#message_fn)(&())),
+        None => quote!("The condition is not satisfied.".to_string()),
+    };
+
+    // The expression may use `self` instead of the field variable.
+    Ok(quote!(
+        let _ = #field_ident;
+        if !(#expr) {
+            #errors
+                .entry(#rename.into())
+                .or_default()
+                .push(::serde_valid::validation::Error::Custom(#message));
+        }
+    ))
+}
@@ -0,0 +1,97 @@
+use crate::types::CommaSeparatedNestedMetas;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Split `groups(...)` from the items of `#[validate(..., groups(Create, Update))]`.
+///
+/// Returns the list without `groups(...)` and the group types, if any.
+pub fn split_validation_groups(
+    list: &syn::MetaList,
+) -> Result<(syn::MetaList, Option<Vec<syn::Path>>), crate::Errors> {
+    let mut errors = vec![];
+    let mut groups = None;
+    let mut nested = CommaSeparatedNestedMetas::new();
+
+    for (index, nested_meta) in list.nested.iter().enumerate() {
+        match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::List(groups_list))
+                if index != 0 && groups_list.path.is_ident("groups") =>
+            {
+                if groups.is_some() {
+                    errors.push(crate::Error::validate_groups_duplicated(nested_meta));
+                    continue;
+                }
+                match collect_group_paths(groups_list) {
+                    Ok(group_paths) => groups = Some(group_paths),
+                    Err(group_errors) => errors.extend(group_errors),
+                }
+            }
+            _ => nested.push(nested_meta.clone()),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok((
+            syn::MetaList {
+                path: list.path.clone(),
+                paren_token: list.paren_token,
+                nested,
+            },
+            groups,
+        ))
+    } else {
+        Err(errors)
+    }
+}
+
+/// Whether the `#[validate(...)]` belongs to the groups.
+pub fn has_validation_groups(list: &syn::MetaList) -> bool {
+    list.nested.iter().skip(1).any(|nested_meta| {
+        matches!(
+            nested_meta,
+            syn::NestedMeta::Meta(syn::Meta::List(groups_list))
+                if groups_list.path.is_ident("groups")
+        )
+    })
+}
+
+/// Run the validator only when one of the groups is active.
+///
+/// The group types must implement `ValidationGroup`, so the undeclared groups are the compile errors.
+pub fn group_validator_tokens(groups: &[syn::Path], validator: TokenStream) -> TokenStream {
+    quote!(
+        if matches!(
+            __group,
+            Some(__group) if #(__group == <#groups as ::serde_valid::ValidationGroup>::group_id())||*
+        ) {
+            #validator
+        }
+    )
+}
+
+fn collect_group_paths(groups_list: &syn::MetaList) -> Result<Vec<syn::Path>, crate::Errors> {
+    if groups_list.nested.is_empty() {
+        return Err(vec![crate::Error::validate_groups_need_item(
+            &groups_list.path,
+        )]);
+    }
+
+    let mut errors = vec![];
+    let group_paths = groups_list
+        .nested
+        .iter()
+        .filter_map(|nested_meta| match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) => Some(path.clone()),
+            _ => {
+                errors.push(crate::Error::validate_groups_allow_type_path(nested_meta));
+                None
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(group_paths)
+    } else {
+        Err(errors)
+    }
+}
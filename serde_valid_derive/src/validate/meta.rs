@@ -6,28 +6,119 @@ mod nested_meta_path;
 
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
+use crate::validate::generic::{
+    custom_fn_args_tokens, extract_generic_custom_closure_validator, split_custom_fn_args,
+};
+use crate::validate::groups::{
+    group_validator_tokens, has_validation_groups, split_validation_groups,
+};
+use crate::validate::when::{split_when_predicate, when_validator_tokens};
 use crate::validate::Validator;
 use meta_path::extract_validator_from_meta_path;
 
 use self::meta_list::extract_validator_from_meta_list;
 
+/// Whether the attribute runs in `AsyncValidate`, or in `Validate` otherwise.
+///
+/// The nested `#[validate]` runs in both, for the sync and the async checks of the field.
+pub fn is_validate_attribute_of(attribute: &syn::Attribute, asynchronous: bool) -> bool {
+    is_async_validate_attribute(attribute) == asynchronous
+        || is_nested_validate_attribute(attribute)
+}
+
+fn is_nested_validate_attribute(attribute: &syn::Attribute) -> bool {
+    matches!(parse_validate_meta(attribute), Some(syn::Meta::Path(_)))
+}
+
+/// Whether the attribute is `#[validate(custom_async(...))]`, which runs in `AsyncValidate`.
+fn is_async_validate_attribute(attribute: &syn::Attribute) -> bool {
+    match parse_validate_meta(attribute) {
+        Some(syn::Meta::List(list)) => matches!(
+            list.nested.first(),
+            Some(syn::NestedMeta::Meta(syn::Meta::List(validation)))
+                if validation.path.is_ident("custom_async")
+        ),
+        _ => false,
+    }
+}
+
+/// Whether the attribute refers to the active group,
+/// by `groups(...)` or by the nested `#[validate]` without the context.
+pub fn uses_validation_group(attribute: &syn::Attribute, context: Option<&syn::Type>) -> bool {
+    match parse_validate_meta(attribute) {
+        Some(syn::Meta::List(list)) => has_validation_groups(&list),
+        Some(syn::Meta::Path(_)) => context.is_none(),
+        _ => false,
+    }
+}
+
+/// Parse the attribute without `when = ...` and the custom function arguments.
+fn parse_validate_meta(attribute: &syn::Attribute) -> Option<syn::Meta> {
+    let (attribute, _) = split_when_predicate(attribute).ok()?;
+    let (attribute, _) = split_custom_fn_args(&attribute);
+    attribute.parse_meta().ok()
+}
+
 pub fn extract_meta_validator(
     field: &impl Field,
     attribute: &syn::Attribute,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+) -> Result<Validator, crate::Errors> {
+    let (attribute, predicate) = split_when_predicate(attribute)?;
+    let validator =
+        extract_meta_validator_without_when(field, &attribute, rename_map, context, asynchronous)?;
+    match predicate {
+        Some(predicate) => Ok(when_validator_tokens(&predicate, validator)),
+        None => Ok(validator),
+    }
+}
+
+fn extract_meta_validator_without_when(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<Validator, crate::Errors> {
-    match attribute.parse_meta() {
+    let (custom_attribute, custom_fn_args) = split_custom_fn_args(attribute);
+    match custom_attribute.parse_meta() {
         Ok(syn::Meta::List(list)) => {
-            extract_validator_from_meta_list(field, attribute, &list, rename_map)
+            let (list, groups) = split_validation_groups(&list)?;
+            match groups {
+                Some(_) if context.is_some() => {
+                    Err(vec![crate::Error::validate_groups_context_not_support(
+                        &list,
+                    )])
+                }
+                Some(_) if is_async_validate_attribute(attribute) => {
+                    Err(vec![crate::Error::validate_groups_async_not_support(&list)])
+                }
+                Some(groups) => {
+                    extract_validator_from_meta_list(field, attribute, &list, rename_map, context)
+                        .map(|validator| custom_fn_args_tokens(&custom_fn_args, validator))
+                        .map(|validator| group_validator_tokens(&groups, validator))
+                }
+                None => {
+                    extract_validator_from_meta_list(field, attribute, &list, rename_map, context)
+                        .map(|validator| custom_fn_args_tokens(&custom_fn_args, validator))
+                }
+            }
+        }
+        Ok(syn::Meta::Path(_)) => {
+            extract_validator_from_meta_path(field, rename_map, context, asynchronous)
         }
-        Ok(syn::Meta::Path(_)) => extract_validator_from_meta_path(field, rename_map),
         Ok(syn::Meta::NameValue(name_value)) => {
             Err(vec![crate::Error::validate_meta_name_value_not_support(
                 name_value,
             )])
         }
-        Err(error) => Err(vec![crate::Error::validate_attribute_parse_error(
-            attribute, &error,
-        )]),
+        Err(error) => extract_generic_custom_closure_validator(field, attribute, rename_map)
+            .unwrap_or_else(|| {
+                Err(vec![crate::Error::validate_attribute_parse_error(
+                    attribute, &error,
+                )])
+            }),
     }
 }
@@ -2,20 +2,37 @@ use super::nested_meta_list::extract_validator_from_nested_meta_list;
 use super::nested_meta_name_value::extract_validator_from_nested_meta_name_value;
 use super::nested_meta_path::extract_validator_from_nested_meta_path;
 use crate::serde::rename::RenameMap;
-use crate::types::Field;
+use crate::types::{Field, SingleIdentPath};
 use crate::validate::common::extract_message_fn_tokens;
 use crate::validate::Validator;
+use syn::spanned::Spanned;
 
 pub fn extract_validator_from_meta_list(
     field: &impl Field,
     attribute: &syn::Attribute,
     syn::MetaList { nested, .. }: &syn::MetaList,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
     let mut errors = vec![];
+    let validation_name = match nested.first() {
+        Some(syn::NestedMeta::Meta(meta)) => SingleIdentPath::new(meta.path()).ident().to_string(),
+        _ => String::new(),
+    };
+    let with_context = nested.len() == 2 && is_context_path(&nested[1]);
+    if with_context {
+        if validation_name != "custom" {
+            errors.push(crate::Error::validate_context_allow_custom_only(&nested[1]));
+        } else if context.is_none() {
+            errors.push(crate::Error::validate_context_not_declared(
+                nested[1].span(),
+            ));
+        }
+    }
     let messaeg_fn = match nested.len() {
         0..=1 => None,
-        2 => match extract_message_fn_tokens(&nested[1]) {
+        2 if with_context => None,
+        2 => match extract_message_fn_tokens(&nested[1], &validation_name) {
             Ok(message_fn) => Some(message_fn),
             Err(message_fn_errors) => {
                 errors.extend(message_fn_errors);
@@ -33,21 +50,35 @@ pub fn extract_validator_from_meta_list(
     if !nested.is_empty() {
         let meta_item = &nested[0];
         match meta_item {
-            syn::NestedMeta::Meta(meta) => match meta {
-                syn::Meta::Path(path) => {
-                    extract_validator_from_nested_meta_path(field, path, messaeg_fn, rename_map)
+            syn::NestedMeta::Meta(meta) => {
+                let validator = match meta {
+                    syn::Meta::Path(path) => {
+                        extract_validator_from_nested_meta_path(field, path, messaeg_fn, rename_map)
+                    }
+                    syn::Meta::List(list) => extract_validator_from_nested_meta_list(
+                        field,
+                        attribute,
+                        list,
+                        messaeg_fn,
+                        rename_map,
+                        context,
+                        with_context,
+                    ),
+                    syn::Meta::NameValue(name_value) => {
+                        extract_validator_from_nested_meta_name_value(
+                            field, attribute, name_value, messaeg_fn, rename_map,
+                        )
+                    }
+                };
+                match validator {
+                    Ok(validator) if errors.is_empty() => Ok(validator),
+                    Ok(_) => Err(errors),
+                    Err(validator_errors) => {
+                        errors.extend(validator_errors);
+                        Err(errors)
+                    }
                 }
-                syn::Meta::List(list) => {
-                    extract_validator_from_nested_meta_list(field, list, messaeg_fn, rename_map)
-                }
-                syn::Meta::NameValue(name_value) => extract_validator_from_nested_meta_name_value(
-                    field, attribute, name_value, messaeg_fn, rename_map,
-                ),
             }
-            .map_err(|validator_errors| {
-                errors.extend(validator_errors);
-                errors
-            }),
             syn::NestedMeta::Lit(lit) => {
                 errors.push(crate::Error::validate_meta_literal_not_support(lit));
                 Err(errors)
@@ -58,3 +89,10 @@ pub fn extract_validator_from_meta_list(
         Err(errors)
     }
 }
+
+fn is_context_path(nested_meta: &syn::NestedMeta) -> bool {
+    matches!(
+        nested_meta,
+        syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("context")
+    )
+}
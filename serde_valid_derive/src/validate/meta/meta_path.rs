@@ -1,3 +1,4 @@
+use crate::serde::flatten::has_serde_flatten;
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
 use crate::validate::Validator;
@@ -7,35 +8,118 @@ use quote::quote;
 pub fn extract_validator_from_meta_path(
     field: &impl Field,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<Validator, crate::Errors> {
-    Ok(inner_extract_validator_from_meta_path(field, rename_map))
+    let validate = nested_validate_tokens(field, context, asynchronous);
+    if has_serde_flatten(field.attrs()) {
+        Ok(inner_extract_flatten_validator_from_meta_path(
+            field, &validate, rename_map,
+        ))
+    } else {
+        Ok(inner_extract_validator_from_meta_path(
+            field, &validate, rename_map,
+        ))
+    }
+}
+
+/// The nested validation, which passes the context through when the context type is declared,
+/// runs the async checks in `AsyncValidate`, or passes the active group otherwise.
+fn nested_validate_tokens(
+    field: &impl Field,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+) -> TokenStream {
+    let field_ident = field.ident();
+    match context {
+        Some(_) => quote!(::serde_valid::ValidateWithContext::validate_with_context(
+            #field_ident,
+            __context
+        )),
+        None if asynchronous => quote!(
+            ::serde_valid::AsyncValidate::validate_async_checks(#field_ident).await
+        ),
+        None => quote!(match __group {
+            Some(__group) => #field_ident.validate_group_id(__group),
+            None => #field_ident.validate(),
+        }),
+    }
 }
 
 fn inner_extract_validator_from_meta_path(
     field: &impl Field,
+    validate: &TokenStream,
     rename_map: &RenameMap,
 ) -> TokenStream {
-    let field_ident = field.ident();
     let field_name = field.name();
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
 
     quote!(
-        if let Err(__inner_errors) = #field_ident.validate() {
+        if let Err(__inner_errors) = #validate {
             match __inner_errors {
                 ::serde_valid::validation::Errors::Object(__object_errors) => {
-                    #errors.entry(#rename).or_default().push(
+                    #errors.entry(#rename.into()).or_default().push(
                         ::serde_valid::validation::Error::Properties(__object_errors)
                     );
                 }
                 ::serde_valid::validation::Errors::Array(__array_errors) => {
-                    #errors.entry(#rename).or_default().push(
+                    #errors.entry(#rename.into()).or_default().push(
+                        ::serde_valid::validation::Error::Items(__array_errors)
+                    );
+                }
+                ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                    #errors.entry(#rename.into()).or_default().extend(__new_type_errors);
+                }
+            }
+        }
+    )
+}
+
+/// The errors of `#[serde(flatten)]` field are merged into the parent properties,
+/// because the field key does not exist in the document.
+fn inner_extract_flatten_validator_from_meta_path(
+    field: &impl Field,
+    validate: &TokenStream,
+    rename_map: &RenameMap,
+) -> TokenStream {
+    let field_name = field.name();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+
+    quote!(
+        if let Err(__inner_errors) = #validate {
+            match __inner_errors {
+                ::serde_valid::validation::Errors::Object(__object_errors) => {
+                    __rule_vec_errors.extend(__object_errors.errors);
+
+                    for (__property, __property_errors) in __object_errors.properties {
+                        match __property_errors {
+                            ::serde_valid::validation::Errors::Object(__object_errors) => {
+                                #errors.entry(__property).or_default().push(
+                                    ::serde_valid::validation::Error::Properties(__object_errors)
+                                );
+                            }
+                            ::serde_valid::validation::Errors::Array(__array_errors) => {
+                                #errors.entry(__property).or_default().push(
+                                    ::serde_valid::validation::Error::Items(__array_errors)
+                                );
+                            }
+                            ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                                #errors.entry(__property).or_default().extend(__new_type_errors);
+                            }
+                        }
+                    }
+                }
+                ::serde_valid::validation::Errors::Array(__array_errors) => {
+                    #errors.entry(#rename.into()).or_default().push(
                         ::serde_valid::validation::Error::Items(__array_errors)
                     );
                 }
                 ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
-                    #errors.entry(#rename).or_default().extend(__new_type_errors);
+                    __rule_vec_errors.extend(__new_type_errors);
                 }
             }
         }
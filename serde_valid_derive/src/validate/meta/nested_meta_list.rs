@@ -1,18 +1,25 @@
+use super::meta_list::extract_validator_from_meta_list;
 use crate::serde::rename::RenameMap;
-use crate::types::{Field, SingleIdentPath};
+use crate::types::{ContainsItemField, Field, MapEntry, MapEntryField, SingleIdentPath};
+use crate::validate::array::extract_array_contains_validator;
 use crate::validate::common::MetaListValidation;
 use crate::validate::generic::{
-    extract_generic_custom_validator, extract_generic_enumerate_validator,
+    extract_generic_custom_async_validator, extract_generic_custom_validator,
+    extract_generic_enumerate_validator,
 };
+use crate::validate::object::extract_object_map_entries_validator;
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use std::str::FromStr;
 
 pub fn extract_validator_from_nested_meta_list(
     field: &impl Field,
+    attribute: &syn::Attribute,
     validation_list: &syn::MetaList,
     message_fn: Option<TokenStream>,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+    with_context: bool,
 ) -> Result<Validator, crate::Errors> {
     let syn::MetaList {
         path: validation_name,
@@ -25,8 +32,37 @@ pub fn extract_validator_from_nested_meta_list(
             extract_generic_enumerate_validator(field, validation_list, message_fn, rename_map)
         }
         Ok(MetaListValidation::Custom) => {
-            extract_generic_custom_validator(field, validation_list, rename_map)
+            extract_generic_custom_validator(field, validation_list, rename_map, with_context)
         }
+        Ok(MetaListValidation::CustomAsync) => {
+            extract_generic_custom_async_validator(field, validation_list, rename_map)
+        }
+        Ok(MetaListValidation::Keys) => extract_map_entries_validator(
+            field,
+            MapEntry::Key,
+            attribute,
+            validation_list,
+            message_fn,
+            rename_map,
+            context,
+        ),
+        Ok(MetaListValidation::Values) => extract_map_entries_validator(
+            field,
+            MapEntry::Value,
+            attribute,
+            validation_list,
+            message_fn,
+            rename_map,
+            context,
+        ),
+        Ok(MetaListValidation::Contains) => extract_contains_validator(
+            field,
+            attribute,
+            validation_list,
+            message_fn,
+            rename_map,
+            context,
+        ),
         Err(unknown) => Err(vec![crate::Error::validate_unknown_type(
             validation_name,
             &unknown,
@@ -36,3 +72,132 @@ pub fn extract_validator_from_nested_meta_list(
         )]),
     }
 }
+
+/// `keys(...)` and `values(...)` take any field validation, which applies to each entry of the map.
+fn extract_map_entries_validator(
+    field: &impl Field,
+    entry: MapEntry,
+    attribute: &syn::Attribute,
+    validation_list: &syn::MetaList,
+    message_fn: Option<TokenStream>,
+    rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+) -> Result<Validator, crate::Errors> {
+    if message_fn.is_some() {
+        return Err(vec![
+            crate::Error::validate_map_entries_message_not_support(validation_list),
+        ]);
+    }
+    if validation_list.nested.is_empty() {
+        return Err(vec![crate::Error::validate_map_entries_need_validation(
+            validation_list,
+        )]);
+    }
+
+    let entry_field = MapEntryField::new(field, entry);
+    let entry_validator = extract_validator_from_meta_list(
+        &entry_field,
+        attribute,
+        validation_list,
+        rename_map,
+        context,
+    )?;
+
+    Ok(extract_object_map_entries_validator(
+        field,
+        entry,
+        entry_validator,
+        rename_map,
+    ))
+}
+
+/// `contains(...)` takes any field validation as the predicate of each item,
+/// with the optional `min_contains = N` and `max_contains = N`.
+fn extract_contains_validator(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    validation_list: &syn::MetaList,
+    message_fn: Option<TokenStream>,
+    rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+) -> Result<Validator, crate::Errors> {
+    let mut errors = vec![];
+    let mut min_contains = None;
+    let mut max_contains = None;
+    let mut item_list = syn::MetaList {
+        path: validation_list.path.clone(),
+        paren_token: validation_list.paren_token,
+        nested: Default::default(),
+    };
+
+    for nested_meta in validation_list.nested.iter() {
+        let count = match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("min_contains") =>
+            {
+                Some((&mut min_contains, name_value))
+            }
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("max_contains") =>
+            {
+                Some((&mut max_contains, name_value))
+            }
+            _ => None,
+        };
+        match count {
+            Some((count, syn::MetaNameValue { path, lit, .. })) => {
+                if count.is_some() {
+                    errors.push(crate::Error::validate_contains_count_duplicated(path));
+                }
+                match lit {
+                    syn::Lit::Int(int) => match int.base10_parse::<usize>() {
+                        Ok(value) => *count = Some(value),
+                        Err(_) => errors.push(crate::Error::usize_literal_only(lit)),
+                    },
+                    _ => errors.push(crate::Error::usize_literal_only(lit)),
+                }
+            }
+            None => item_list.nested.push(nested_meta.clone()),
+        }
+    }
+
+    if let (Some(min), Some(max)) = (min_contains, max_contains) {
+        if min > max {
+            errors.push(crate::Error::validate_contains_min_greater_than_max(
+                validation_list,
+                min,
+                max,
+            ));
+        }
+    }
+
+    match item_list.nested.first() {
+        None => errors.push(crate::Error::validate_contains_need_validation(
+            validation_list,
+        )),
+        Some(syn::NestedMeta::Meta(syn::Meta::List(list)))
+            if list.path.is_ident("custom_async") =>
+        {
+            errors.push(crate::Error::validate_contains_async_not_support(
+                validation_list,
+            ))
+        }
+        Some(_) => {}
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let item_field = ContainsItemField::new(field);
+    let item_validator =
+        extract_validator_from_meta_list(&item_field, attribute, &item_list, rename_map, context)?;
+
+    Ok(extract_array_contains_validator(
+        field,
+        item_validator,
+        min_contains,
+        max_contains,
+        message_fn,
+        rename_map,
+    ))
+}
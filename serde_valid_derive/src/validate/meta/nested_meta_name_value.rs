@@ -4,6 +4,9 @@ use crate::validate::array::{
     extract_array_max_items_validator, extract_array_min_items_validator,
 };
 use crate::validate::common::MetaNameValueValidation;
+use crate::validate::generic::{
+    extract_generic_const_value_validator, extract_generic_expr_validator,
+};
 use crate::validate::numeric::{
     extract_numeric_exclusive_maximum_validator, extract_numeric_exclusive_minimum_validator,
     extract_numeric_maximum_validator, extract_numeric_minimum_validator,
@@ -13,8 +16,8 @@ use crate::validate::object::{
     extract_object_max_properties_validator, extract_object_min_properties_validator,
 };
 use crate::validate::string::{
-    extract_string_max_length_validator, extract_string_min_length_validator,
-    extract_string_pattern_validator,
+    extract_string_format_validator, extract_string_max_length_validator,
+    extract_string_min_length_validator, extract_string_pattern_validator,
 };
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
@@ -79,6 +82,15 @@ pub fn extract_validator_from_nested_meta_name_value(
         Ok(MetaNameValueValidation::Pattern) => {
             extract_string_pattern_validator(field, validation_value, message_fn, rename_map)
         }
+        Ok(MetaNameValueValidation::Format) => {
+            extract_string_format_validator(field, validation_value, message_fn, rename_map)
+        }
+        Ok(MetaNameValueValidation::ConstValue) => {
+            extract_generic_const_value_validator(field, validation_value, message_fn, rename_map)
+        }
+        Ok(MetaNameValueValidation::Expr) => {
+            extract_generic_expr_validator(field, validation_value, message_fn, rename_map)
+        }
         Err(unknown) => Err(vec![crate::Error::validate_unknown_type(
             validation_name,
             &unknown,
@@ -1,6 +1,6 @@
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
-use crate::validate::common::get_numeric;
+use crate::validate::common::{get_numeric, into_error_tokens};
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -32,22 +32,19 @@ macro_rules! extract_numeric_range_validator{
                 let rename = rename_map.get(field_name).unwrap_or(&field_key);
                 let errors = field.errors_variable();
                 let [<$ErrorType:snake>] = get_numeric(validation_value)?;
-                let message =
-                    message_fn.unwrap_or(quote!(::serde_valid::[<$ErrorType ErrorParams>]::to_default_message));
+                let into_error = into_error_tokens(message_fn);
 
                 Ok(quote!(
                     if let Err(__composited_error_params) = ::serde_valid::validation::[<ValidateComposited $ErrorType>]::[<validate_composited_ $ErrorType:snake>](
                         #field_ident,
                         #[<$ErrorType:snake>],
                     ) {
-                        use ::serde_valid::error::ToDefaultMessage;
                         use ::serde_valid::validation::IntoError;
 
                         #errors
-                            .entry(#rename)
+                            .entry(#rename.into())
                             .or_default()
-                            .push(__composited_error_params.into_error_by(#message)
-                        );
+                            .push(__composited_error_params.#into_error);
                     }
                 ))
             }
@@ -1,4 +1,6 @@
+mod map_entries;
 mod size_properties;
+pub use map_entries::extract_object_map_entries_validator;
 pub use size_properties::{
     extract_object_max_properties_validator, extract_object_min_properties_validator,
 };
@@ -0,0 +1,50 @@
+use crate::error::object_errors_tokens;
+use crate::serde::rename::RenameMap;
+use crate::types::{Field, MapEntry};
+use crate::validate::Validator;
+use quote::quote;
+
+/// Keys and values validation of the map entries.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/object.html#property-names>
+pub fn extract_object_map_entries_validator(
+    field: &impl Field,
+    entry: MapEntry,
+    entry_validator: Validator,
+    rename_map: &RenameMap,
+) -> Validator {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let entry_pattern = match entry {
+        MapEntry::Key => quote!((__map_key, _)),
+        MapEntry::Value => quote!((__map_key, __map_value)),
+    };
+    let entry_errors = object_errors_tokens();
+
+    quote!(
+        let __map_entry_errors = {
+            let __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+            let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+
+            // `for_each` keeps the static pattern of the entry validator out of the loop lint.
+            #field_ident.iter().for_each(|#entry_pattern| {
+                #entry_validator
+            });
+
+            if __property_vec_errors_map.is_empty() {
+                None
+            } else {
+                Some(#entry_errors)
+            }
+        };
+        if let Some(::serde_valid::validation::Errors::Object(__object_errors)) = __map_entry_errors {
+            #errors
+                .entry(#rename.into())
+                .or_default()
+                .push(::serde_valid::validation::Error::Properties(__object_errors));
+        }
+    )
+}
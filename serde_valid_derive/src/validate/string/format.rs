@@ -0,0 +1,44 @@
+use crate::{
+    serde::rename::RenameMap,
+    types::Field,
+    validate::{
+        common::{get_str, into_error_tokens},
+        Validator,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Extract `#[validate(format = "...")]`.
+///
+/// The format is any name of the built-in formats and the registered ones,
+/// which is resolved at runtime by `serde_valid::format`.
+pub fn extract_string_format_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_fn: Option<TokenStream>,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let format = get_str(validation_value)?;
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let into_error = into_error_tokens(message_fn);
+
+    Ok(quote!(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedFormat::validate_composited_format(
+            #field_ident,
+            #format,
+        ) {
+
+            use ::serde_valid::validation::IntoError;
+
+            #errors
+                .entry(#rename.into())
+                .or_default()
+                .push(__composited_error_params.#into_error);
+        }
+    ))
+}
@@ -1,7 +1,10 @@
 use crate::{
     serde::rename::RenameMap,
     types::Field,
-    validate::{common::get_str, Validator},
+    validate::{
+        common::{get_str, into_error_tokens},
+        Validator,
+    },
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -27,9 +30,7 @@ fn inner_extract_string_pattern_validator(
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
     let pattern = get_str(validation_value)?;
-    let message = message_fn.unwrap_or(quote!(
-        ::serde_valid::PatternErrorParams::to_default_message
-    ));
+    let into_error = into_error_tokens(message_fn);
     let pattern_ident = syn::Ident::new(
         &format!("{}_PATTERN", &field_ident).to_uppercase(),
         field_ident.span(),
@@ -43,14 +44,12 @@ fn inner_extract_string_pattern_validator(
             __pattern,
         ) {
 
-            use ::serde_valid::error::ToDefaultMessage;
             use ::serde_valid::validation::IntoError;
 
             #errors
-                .entry(#rename)
+                .entry(#rename.into())
                 .or_default()
-                .push(__composited_error_params.into_error_by(#message)
-            );
+                .push(__composited_error_params.#into_error);
         }
     ))
 }
@@ -0,0 +1,151 @@
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::quote;
+
+/// The predicate of `#[validate(..., when = path_to_fn)]` or `#[validate(..., when = "expr")]`.
+pub enum WhenPredicate {
+    Fn(syn::Path),
+    Expr(syn::Expr),
+}
+
+impl WhenPredicate {
+    /// The condition tokens over `self`.
+    pub fn condition_tokens(&self) -> TokenStream {
+        match self {
+            Self::Fn(path) => quote!(#path(self)),
+            Self::Expr(expr) => quote!((#expr)),
+        }
+    }
+
+    /// The field and the literal of the predicate like `"self.country == \"DE\""`,
+    /// which can be expressed in JSON Schema.
+    pub fn field_equals_literal(&self) -> Option<(&syn::Ident, &syn::Lit)> {
+        let Self::Expr(syn::Expr::Binary(syn::ExprBinary {
+            left,
+            op: syn::BinOp::Eq(_),
+            right,
+            ..
+        })) = self
+        else {
+            return None;
+        };
+
+        match (self_field(left), right.as_ref()) {
+            (Some(field), syn::Expr::Lit(syn::ExprLit { lit, .. })) => Some((field, lit)),
+            _ => match (left.as_ref(), self_field(right)) {
+                (syn::Expr::Lit(syn::ExprLit { lit, .. }), Some(field)) => Some((field, lit)),
+                _ => None,
+            },
+        }
+    }
+}
+
+fn self_field(expr: &syn::Expr) -> Option<&syn::Ident> {
+    match expr {
+        syn::Expr::Field(syn::ExprField {
+            base,
+            member: syn::Member::Named(field),
+            ..
+        }) => match base.as_ref() {
+            syn::Expr::Path(syn::ExprPath { path, .. }) if path.is_ident("self") => Some(field),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Split `when = ...` from the items of `#[validate(...)]`.
+///
+/// `when = path_to_fn` is not a valid meta item, so the item is removed
+/// from the attribute tokens before parsing the rest.
+pub fn split_when_predicate(
+    attribute: &syn::Attribute,
+) -> Result<(syn::Attribute, Option<WhenPredicate>), crate::Errors> {
+    let group = match attribute.tokens.clone().into_iter().next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => group,
+        _ => return Ok((attribute.clone(), None)),
+    };
+
+    let mut errors = vec![];
+    let mut predicate = None;
+    let mut items = vec![];
+    for item in split_comma_separated(group.stream()) {
+        match when_value(&item) {
+            Some(value) if predicate.is_some() => {
+                errors.push(crate::Error::validate_when_duplicated(&value));
+            }
+            Some(value) => match parse_when_predicate(value) {
+                Ok(when_predicate) => predicate = Some(when_predicate),
+                Err(error) => errors.push(error),
+            },
+            None => items.push(item),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut tokens = proc_macro2::Group::new(Delimiter::Parenthesis, quote!(#(#items),*));
+    tokens.set_span(group.span());
+
+    Ok((
+        syn::Attribute {
+            tokens: TokenStream::from(TokenTree::Group(tokens)),
+            ..attribute.clone()
+        },
+        predicate,
+    ))
+}
+
+/// Run the validator only when the predicate holds.
+pub fn when_validator_tokens(predicate: &WhenPredicate, validator: TokenStream) -> TokenStream {
+    let condition = predicate.condition_tokens();
+    quote!(
+        if #condition {
+            #validator
+        }
+    )
+}
+
+pub fn split_comma_separated(tokens: TokenStream) -> Vec<TokenStream> {
+    let mut items = vec![];
+    let mut item = vec![];
+    for token in tokens {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == ',' => {
+                items.push(TokenStream::from_iter(std::mem::take(&mut item)));
+            }
+            token => item.push(token),
+        }
+    }
+    if !item.is_empty() {
+        items.push(TokenStream::from_iter(item));
+    }
+    items
+}
+
+fn when_value(item: &TokenStream) -> Option<TokenStream> {
+    let mut tokens = item.clone().into_iter();
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Ident(ident)), Some(TokenTree::Punct(punct)))
+            if ident == "when"
+                && punct.as_char() == '='
+                && punct.spacing() == proc_macro2::Spacing::Alone =>
+        {
+            Some(TokenStream::from_iter(tokens))
+        }
+        _ => None,
+    }
+}
+
+fn parse_when_predicate(value: TokenStream) -> Result<WhenPredicate, crate::Error> {
+    if let Ok(lit_str) = syn::parse2::<syn::LitStr>(value.clone()) {
+        return lit_str
+            .parse::<syn::Expr>()
+            .map(WhenPredicate::Expr)
+            .map_err(|error| crate::Error::validate_when_parse_error(&value, &error));
+    }
+    syn::parse2::<syn::Path>(value.clone())
+        .map(WhenPredicate::Fn)
+        .map_err(|error| crate::Error::validate_when_parse_error(&value, &error))
+}
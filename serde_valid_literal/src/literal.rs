@@ -1,8 +1,10 @@
+use std::borrow::Cow;
+
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum Literal {
     Bool(bool),
     Number(crate::Number),
-    String(&'static str),
+    String(Cow<'static, str>),
     Char(char),
     Null,
 }
@@ -24,7 +26,13 @@ where
 
 impl std::convert::From<&'static str> for Literal {
     fn from(item: &'static str) -> Self {
-        Literal::String(item)
+        Literal::String(Cow::Borrowed(item))
+    }
+}
+
+impl std::convert::From<String> for Literal {
+    fn from(item: String) -> Self {
+        Literal::String(Cow::Owned(item))
     }
 }
 
@@ -57,3 +65,18 @@ impl std::fmt::Display for Literal {
         }
     }
 }
+
+impl serde::Serialize for Literal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Literal::Bool(value) => serializer.serialize_bool(*value),
+            Literal::Number(value) => value.serialize(serializer),
+            Literal::String(value) => serializer.serialize_str(value),
+            Literal::Char(value) => serializer.serialize_char(*value),
+            Literal::Null => serializer.serialize_unit(),
+        }
+    }
+}
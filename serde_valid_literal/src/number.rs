@@ -36,32 +36,32 @@ pub enum Number {
 impl std::fmt::Display for Number {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match *self {
-            Number::I8(num) => write!(f, "{:?}", num),
-            Number::I16(num) => write!(f, "{:?}", num),
-            Number::I32(num) => write!(f, "{:?}", num),
-            Number::I64(num) => write!(f, "{:?}", num),
-            Number::I128(num) => write!(f, "{:?}", num),
-            Number::Isize(num) => write!(f, "{:?}", num),
-            Number::U8(num) => write!(f, "{:?}", num),
-            Number::U16(num) => write!(f, "{:?}", num),
-            Number::U32(num) => write!(f, "{:?}", num),
-            Number::U64(num) => write!(f, "{:?}", num),
-            Number::U128(num) => write!(f, "{:?}", num),
-            Number::Usize(num) => write!(f, "{:?}", num),
-            Number::NonZeroI8(num) => write!(f, "{:?}", num),
-            Number::NonZeroI16(num) => write!(f, "{:?}", num),
-            Number::NonZeroI32(num) => write!(f, "{:?}", num),
-            Number::NonZeroI64(num) => write!(f, "{:?}", num),
-            Number::NonZeroI128(num) => write!(f, "{:?}", num),
-            Number::NonZeroIsize(num) => write!(f, "{:?}", num),
-            Number::NonZeroU8(num) => write!(f, "{:?}", num),
-            Number::NonZeroU16(num) => write!(f, "{:?}", num),
-            Number::NonZeroU32(num) => write!(f, "{:?}", num),
-            Number::NonZeroU64(num) => write!(f, "{:?}", num),
-            Number::NonZeroU128(num) => write!(f, "{:?}", num),
-            Number::NonZeroUsize(num) => write!(f, "{:?}", num),
-            Number::F32(num) => write!(f, "{:?}", num),
-            Number::F64(num) => write!(f, "{:?}", num),
+            Number::I8(num) => std::fmt::Debug::fmt(&num, f),
+            Number::I16(num) => std::fmt::Debug::fmt(&num, f),
+            Number::I32(num) => std::fmt::Debug::fmt(&num, f),
+            Number::I64(num) => std::fmt::Debug::fmt(&num, f),
+            Number::I128(num) => std::fmt::Debug::fmt(&num, f),
+            Number::Isize(num) => std::fmt::Debug::fmt(&num, f),
+            Number::U8(num) => std::fmt::Debug::fmt(&num, f),
+            Number::U16(num) => std::fmt::Debug::fmt(&num, f),
+            Number::U32(num) => std::fmt::Debug::fmt(&num, f),
+            Number::U64(num) => std::fmt::Debug::fmt(&num, f),
+            Number::U128(num) => std::fmt::Debug::fmt(&num, f),
+            Number::Usize(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroI8(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroI16(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroI32(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroI64(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroI128(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroIsize(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroU8(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroU16(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroU32(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroU64(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroU128(num) => std::fmt::Debug::fmt(&num, f),
+            Number::NonZeroUsize(num) => std::fmt::Debug::fmt(&num, f),
+            Number::F32(num) => std::fmt::Debug::fmt(&num, f),
+            Number::F64(num) => std::fmt::Debug::fmt(&num, f),
         }
     }
 }
@@ -110,3 +110,39 @@ impl_from_trait!(NonZeroU128);
 impl_from_trait!(NonZeroUsize);
 impl_from_trait!(f32);
 impl_from_trait!(f64);
+
+impl serde::Serialize for Number {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            Number::I8(num) => serializer.serialize_i8(num),
+            Number::I16(num) => serializer.serialize_i16(num),
+            Number::I32(num) => serializer.serialize_i32(num),
+            Number::I64(num) => serializer.serialize_i64(num),
+            Number::I128(num) => serializer.serialize_i128(num),
+            Number::Isize(num) => serializer.serialize_i64(num as i64),
+            Number::U8(num) => serializer.serialize_u8(num),
+            Number::U16(num) => serializer.serialize_u16(num),
+            Number::U32(num) => serializer.serialize_u32(num),
+            Number::U64(num) => serializer.serialize_u64(num),
+            Number::U128(num) => serializer.serialize_u128(num),
+            Number::Usize(num) => serializer.serialize_u64(num as u64),
+            Number::NonZeroI8(num) => serializer.serialize_i8(num.get()),
+            Number::NonZeroI16(num) => serializer.serialize_i16(num.get()),
+            Number::NonZeroI32(num) => serializer.serialize_i32(num.get()),
+            Number::NonZeroI64(num) => serializer.serialize_i64(num.get()),
+            Number::NonZeroI128(num) => serializer.serialize_i128(num.get()),
+            Number::NonZeroIsize(num) => serializer.serialize_i64(num.get() as i64),
+            Number::NonZeroU8(num) => serializer.serialize_u8(num.get()),
+            Number::NonZeroU16(num) => serializer.serialize_u16(num.get()),
+            Number::NonZeroU32(num) => serializer.serialize_u32(num.get()),
+            Number::NonZeroU64(num) => serializer.serialize_u64(num.get()),
+            Number::NonZeroU128(num) => serializer.serialize_u128(num.get()),
+            Number::NonZeroUsize(num) => serializer.serialize_u64(num.get() as u64),
+            Number::F32(num) => serializer.serialize_f32(num),
+            Number::F64(num) => serializer.serialize_f64(num),
+        }
+    }
+}
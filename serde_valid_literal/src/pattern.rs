@@ -11,8 +11,18 @@ macro_rules! impl_from_trait {
     };
 }
 
-impl_from_trait!(regex::Regex);
-impl_from_trait!(&regex::Regex);
+impl From<regex::Regex> for Pattern {
+    fn from(item: regex::Regex) -> Self {
+        Self(item.as_str().to_owned())
+    }
+}
+
+impl From<&regex::Regex> for Pattern {
+    fn from(item: &regex::Regex) -> Self {
+        Self(item.as_str().to_owned())
+    }
+}
+
 impl_from_trait!(String);
 impl_from_trait!(&str);
 
@@ -21,3 +31,12 @@ impl std::fmt::Display for Pattern {
         write!(f, "{:}", self.0)
     }
 }
+
+impl serde::Serialize for Pattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
@@ -1,14 +1,17 @@
+mod catalog;
 mod generic;
 mod message;
 mod params;
 
-pub use generic::EnumerateErrorParams;
+pub(crate) use catalog::render_template;
+pub use catalog::{render_param_value, EnglishMessageCatalog, JsonMessageCatalog, MessageCatalog};
+pub use generic::{ConstErrorParams, EnumerateErrorParams};
 pub use message::{Message, ToDefaultMessage};
 pub use params::{
-    ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams, MaxItemsErrorParams,
-    MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams, MinItemsErrorParams,
-    MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams, MultipleOfErrorParams,
-    PatternErrorParams, UniqueItemsErrorParams,
+    ContainsErrorParams, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
+    FormatErrorParams, MaxItemsErrorParams, MaxLengthErrorParams, MaxPropertiesErrorParams,
+    MaximumErrorParams, MinItemsErrorParams, MinLengthErrorParams, MinPropertiesErrorParams,
+    MinimumErrorParams, MultipleOfErrorParams, PatternErrorParams, UniqueItemsErrorParams,
 };
 
 #[derive(Debug, thiserror::Error)]
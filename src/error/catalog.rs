@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+/// The catalog of the message templates to localize the default messages.
+///
+/// The template is looked up by the [error code](crate::validation::Error::code),
+/// and the placeholders like `{maximum}` are replaced by the fields of the error params.
+/// `{{` and `}}` are the escaped braces.
+///
+/// The messages given by `message` or `message_fn`, and the custom errors are kept as they are.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::error::MessageCatalog;
+/// use serde_valid::Validate;
+///
+/// struct Japanese;
+///
+/// impl MessageCatalog for Japanese {
+///     fn template(&self, code: &str) -> Option<&str> {
+///         match code {
+///             "maximum" => Some("値は`{maximum}`以下にしてください。"),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct SampleStruct {
+///     #[validate(maximum = 10)]
+///     val: i32,
+///     #[validate(min_length = 2)]
+///     name: String,
+/// }
+///
+/// let s = SampleStruct {
+///     val: 11,
+///     name: "a".to_owned(),
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_value(s.validate().unwrap_err().localize(&Japanese)).unwrap(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["値は`10`以下にしてください。"]
+///             },
+///             "name": {
+///                 "errors": ["The length of the value must be `>= 2`."]
+///             }
+///         }
+///     })
+/// );
+/// ```
+pub trait MessageCatalog {
+    /// The message template of the error code, or `None` to keep the default message.
+    fn template(&self, code: &str) -> Option<&str>;
+}
+
+/// The built-in English catalog, which has the same messages as the default messages.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnglishMessageCatalog;
+
+impl MessageCatalog for EnglishMessageCatalog {
+    fn template(&self, code: &str) -> Option<&str> {
+        Some(match code {
+            "minimum" => "The number must be `>= {minimum}`.",
+            "maximum" => "The number must be `<= {maximum}`.",
+            "exclusive_minimum" => "The number must be `> {exclusive_minimum}`.",
+            "exclusive_maximum" => "The number must be `< {exclusive_maximum}`.",
+            "multiple_of" => "The value must be multiple of `{multiple_of}`.",
+            "min_length" => "The length of the value must be `>= {min_length}`.",
+            "max_length" => "The length of the value must be `<= {max_length}`.",
+            "pattern" => "The value must match the pattern of \"{pattern}\".",
+            "format" => "The value must match the format of \"{format}\".",
+            "min_items" => "The length of the items must be `>= {min_items}`.",
+            "max_items" => "The length of the items must be `<= {max_items}`.",
+            "unique_items" => "The items must be unique.",
+            "contains" => "The items must contain `>= {min_contains}` matching items.",
+            "max_contains" => "The items must contain `<= {max_contains}` matching items.",
+            "min_properties" => "The size of the properties must be `>= {min_properties}`.",
+            "max_properties" => "The size of the properties must be `<= {max_properties}`.",
+            "enumerate" => "The value must be in [{enumerate}].",
+            "const_value" => "The value must be `{const_value}`.",
+            _ => return None,
+        })
+    }
+}
+
+/// The catalog loaded from the JSON message bundle,
+/// which maps the error codes to the message templates.
+///
+/// ```rust
+/// use serde_valid::error::{JsonMessageCatalog, MessageCatalog};
+///
+/// let catalog: JsonMessageCatalog = r#"{
+///     "maximum": "Le nombre doit être `<= {maximum}`."
+/// }"#
+/// .parse()
+/// .unwrap();
+///
+/// assert_eq!(
+///     catalog.template("maximum"),
+///     Some("Le nombre doit être `<= {maximum}`.")
+/// );
+/// assert_eq!(catalog.template("minimum"), None);
+/// ```
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(transparent)]
+pub struct JsonMessageCatalog(HashMap<String, String>);
+
+impl JsonMessageCatalog {
+    pub fn from_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(value)
+    }
+
+    pub fn from_reader<R>(reader: R) -> Result<Self, serde_json::Error>
+    where
+        R: std::io::Read,
+    {
+        serde_json::from_reader(reader)
+    }
+}
+
+impl std::str::FromStr for JsonMessageCatalog {
+    type Err = serde_json::Error;
+
+    fn from_str(json: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(json)
+    }
+}
+
+impl MessageCatalog for JsonMessageCatalog {
+    fn template(&self, code: &str) -> Option<&str> {
+        self.0.get(code).map(String::as_str)
+    }
+}
+
+/// Render the value of the error params in the messages.
+///
+/// The strings are rendered without the quotes, and the lists are joined with `", "`,
+/// such as `{duplicates}` of `#[validate(unique_items, message = "...")]`.
+pub fn render_param_value<Value>(value: &Value) -> String
+where
+    Value: serde::Serialize + ?Sized,
+{
+    serde_json::to_value(value)
+        .map(|value| render_value(&value))
+        .unwrap_or_default()
+}
+
+/// Replace the placeholders of the template by the serialized error params.
+pub(crate) fn render_template<Params>(template: &str, params: &Params) -> String
+where
+    Params: serde::Serialize,
+{
+    let params = match serde_json::to_value(params) {
+        Ok(serde_json::Value::Object(params)) => params,
+        _ => serde_json::Map::new(),
+    };
+
+    let mut message = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                message.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                message.push('}');
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                let mut closed = false;
+                for ch in chars.by_ref() {
+                    if ch == '}' {
+                        closed = true;
+                        break;
+                    }
+                    placeholder.push(ch);
+                }
+                match params.get(placeholder.trim()) {
+                    Some(value) if closed => message.push_str(&render_value(value)),
+                    _ => {
+                        message.push('{');
+                        message.push_str(&placeholder);
+                        if closed {
+                            message.push('}');
+                        }
+                    }
+                }
+            }
+            _ => message.push(ch),
+        }
+    }
+    message
+}
+
+fn render_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(string) => string.to_owned(),
+        serde_json::Value::Array(array) => array
+            .iter()
+            .map(render_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{
+        ConstErrorParams, ContainsErrorParams, EnumerateErrorParams, FormatErrorParams,
+        MaximumErrorParams, PatternErrorParams, ToDefaultMessage, UniqueItemsErrorParams,
+    };
+
+    fn english<Params>(code: &str, params: &Params) -> String
+    where
+        Params: serde::Serialize,
+    {
+        render_template(EnglishMessageCatalog.template(code).unwrap(), params)
+    }
+
+    #[test]
+    fn test_english_catalog_is_same_as_default_message() {
+        let maximum = MaximumErrorParams::new(1.5, 2.0);
+        assert_eq!(english("maximum", &maximum), maximum.to_default_message());
+
+        let pattern = PatternErrorParams::new(regex::Regex::new(r"^\d+$").unwrap());
+        assert_eq!(english("pattern", &pattern), pattern.to_default_message());
+
+        let format = FormatErrorParams::new("email");
+        assert_eq!(english("format", &format), format.to_default_message());
+
+        let enumerate = EnumerateErrorParams::new(&[1, 2, 3], 4);
+        assert_eq!(
+            english("enumerate", &enumerate),
+            enumerate.to_default_message()
+        );
+
+        let const_value = ConstErrorParams::new("v1", "v2");
+        assert_eq!(
+            english("const_value", &const_value),
+            const_value.to_default_message()
+        );
+
+        let contains = ContainsErrorParams::new(2, Some(3), 1);
+        assert_eq!(
+            english("contains", &contains),
+            contains.to_default_message()
+        );
+
+        let max_contains = ContainsErrorParams::new(2, Some(3), 4);
+        assert_eq!(
+            english("max_contains", &max_contains),
+            max_contains.to_default_message()
+        );
+
+        let unique_items = UniqueItemsErrorParams::new([1]);
+        assert_eq!(
+            english("unique_items", &unique_items),
+            unique_items.to_default_message()
+        );
+    }
+
+    #[test]
+    fn test_render_template_escape_and_unknown_placeholder() {
+        assert_eq!(
+            render_template(
+                "{{{maximum}}} {unknown} {maximum",
+                &MaximumErrorParams::new(10, 11)
+            ),
+            "{10} {unknown} {maximum"
+        );
+    }
+}
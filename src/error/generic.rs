@@ -1,2 +1,4 @@
+mod const_value;
 mod enumerate;
+pub use const_value::ConstErrorParams;
 pub use enumerate::EnumerateErrorParams;
@@ -0,0 +1,26 @@
+use crate::{error::ToDefaultMessage, validation::Literal};
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConstErrorParams {
+    pub const_value: Literal,
+    pub value: Literal,
+}
+
+impl ConstErrorParams {
+    pub fn new<T, V>(const_value: T, value: V) -> Self
+    where
+        T: Into<Literal>,
+        V: Into<Literal>,
+    {
+        Self {
+            const_value: const_value.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl ToDefaultMessage for ConstErrorParams {
+    fn to_default_message(&self) -> String {
+        format!("The value must be `{}`.", self.const_value)
+    }
+}
@@ -2,19 +2,22 @@ use itertools::Itertools;
 
 use crate::{error::ToDefaultMessage, validation::Literal};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct EnumerateErrorParams {
     pub enumerate: Vec<Literal>,
+    pub value: Literal,
 }
 
 impl EnumerateErrorParams {
-    pub fn new<T>(enumerate: &[T]) -> Self
+    pub fn new<T, V>(enumerate: &[T], value: V) -> Self
     where
         T: Into<Literal> + std::fmt::Debug + Clone,
+        V: Into<Literal>,
     {
         Self {
             // FIXME: remove clone.
             enumerate: (*enumerate).iter().map(|x| x.clone().into()).collect(),
+            value: value.into(),
         }
     }
 }
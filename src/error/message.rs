@@ -14,7 +14,7 @@ where
     Params: ToDefaultMessage,
 {
     params: Params,
-    format_fn: for<'a> fn(&'a Params) -> String,
+    format_fn: Option<for<'a> fn(&'a Params) -> String>,
 }
 
 impl<Params> Message<Params>
@@ -22,12 +22,28 @@ where
     Params: ToDefaultMessage,
 {
     pub fn new(params: Params, format_fn: fn(&Params) -> String) -> Self {
-        Self { params, format_fn }
+        Self {
+            params,
+            format_fn: Some(format_fn),
+        }
+    }
+
+    /// The message formatted by [`ToDefaultMessage`],
+    /// which can be replaced by the [`MessageCatalog`](crate::error::MessageCatalog).
+    pub fn new_default(params: Params) -> Self {
+        Self {
+            params,
+            format_fn: None,
+        }
     }
 
     pub fn params(&self) -> &Params {
         &self.params
     }
+
+    pub fn is_default(&self) -> bool {
+        self.format_fn.is_none()
+    }
 }
 
 impl<Params> std::fmt::Debug for Message<Params>
@@ -44,6 +60,9 @@ where
     Params: ToDefaultMessage,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", { self.format_fn }(&self.params))
+        match self.format_fn {
+            Some(format_fn) => write!(f, "{}", format_fn(&self.params)),
+            None => write!(f, "{}", self.params.to_default_message()),
+        }
     }
 }
@@ -7,17 +7,20 @@ macro_rules! struct_error_params {
         #[default_message=$default_message:literal]
         pub struct $ErrorParams:ident {
             pub $limit:ident: $type:ty,
+            pub $value:ident: $value_type:ty,
         }
     ) => {
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, serde::Serialize)]
         pub struct $ErrorParams {
             pub $limit: $type,
+            pub $value: $value_type,
         }
 
         impl $ErrorParams {
-            pub fn new<N: Into<$type>>($limit: N) -> Self {
+            pub fn new<N: Into<$type>, V: Into<$value_type>>($limit: N, $value: V) -> Self {
                 Self {
                     $limit: $limit.into(),
+                    $value: $value.into(),
                 }
             }
         }
@@ -29,23 +32,6 @@ macro_rules! struct_error_params {
             }
         }
     };
-
-    (
-        #[derive(Debug, Clone)]
-        #[default_message=$default_message:literal]
-        pub struct $ErrorParams:ident {
-        }
-    ) => {
-        #[derive(Debug, Clone)]
-        pub struct $ErrorParams {}
-
-        impl ToDefaultMessage for $ErrorParams {
-            #[inline]
-            fn to_default_message(&self) -> String {
-                format!($default_message)
-            }
-        }
-    };
 }
 
 // Number
@@ -54,6 +40,7 @@ struct_error_params!(
     #[default_message = "The number must be `>= {}`."]
     pub struct MinimumErrorParams {
         pub minimum: Number,
+        pub value: Number,
     }
 );
 
@@ -62,6 +49,7 @@ struct_error_params!(
     #[default_message = "The number must be `<= {}`."]
     pub struct MaximumErrorParams {
         pub maximum: Number,
+        pub value: Number,
     }
 );
 
@@ -70,6 +58,7 @@ struct_error_params!(
     #[default_message = "The number must be `> {}`."]
     pub struct ExclusiveMinimumErrorParams {
         pub exclusive_minimum: Number,
+        pub value: Number,
     }
 );
 
@@ -78,6 +67,7 @@ struct_error_params!(
     #[default_message = "The number must be `< {}`."]
     pub struct ExclusiveMaximumErrorParams {
         pub exclusive_maximum: Number,
+        pub value: Number,
     }
 );
 
@@ -86,6 +76,7 @@ struct_error_params!(
     #[default_message = "The value must be multiple of `{}`."]
     pub struct MultipleOfErrorParams {
         pub multiple_of: Number,
+        pub value: Number,
     }
 );
 
@@ -95,6 +86,7 @@ struct_error_params!(
     #[default_message = "The length of the value must be `>= {}`."]
     pub struct MinLengthErrorParams {
         pub min_length: usize,
+        pub length: usize,
     }
 );
 
@@ -103,16 +95,52 @@ struct_error_params!(
     #[default_message = "The length of the value must be `<= {}`."]
     pub struct MaxLengthErrorParams {
         pub max_length: usize,
+        pub length: usize,
     }
 );
 
-struct_error_params!(
-    #[derive(Debug, Clone)]
-    #[default_message = "The value must match the pattern of \"{}\"."]
-    pub struct PatternErrorParams {
-        pub pattern: Pattern,
+/// The value is not captured, because the patterns often check the secrets like the passwords.
+/// [`FormatErrorParams`] follows the same policy.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PatternErrorParams {
+    pub pattern: Pattern,
+}
+
+impl PatternErrorParams {
+    pub fn new<P: Into<Pattern>>(pattern: P) -> Self {
+        Self {
+            pattern: pattern.into(),
+        }
     }
-);
+}
+
+impl ToDefaultMessage for PatternErrorParams {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        format!("The value must match the pattern of \"{}\".", self.pattern)
+    }
+}
+
+/// The value is not captured in the same way as [`PatternErrorParams`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FormatErrorParams {
+    pub format: String,
+}
+
+impl FormatErrorParams {
+    pub fn new<F: Into<String>>(format: F) -> Self {
+        Self {
+            format: format.into(),
+        }
+    }
+}
+
+impl ToDefaultMessage for FormatErrorParams {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        format!("The value must match the format of \"{}\".", self.format)
+    }
+}
 
 // Array
 struct_error_params!(
@@ -120,6 +148,7 @@ struct_error_params!(
     #[default_message = "The length of the items must be `<= {}`."]
     pub struct MaxItemsErrorParams {
         pub max_items: usize,
+        pub length: usize,
     }
 );
 
@@ -128,14 +157,74 @@ struct_error_params!(
     #[default_message = "The length of the items must be `>= {}`."]
     pub struct MinItemsErrorParams {
         pub min_items: usize,
+        pub length: usize,
     }
 );
 
-struct_error_params!(
-    #[derive(Debug, Clone)]
-    #[default_message = "The items must be unique."]
-    pub struct UniqueItemsErrorParams {}
-);
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UniqueItemsErrorParams {
+    /// The duplicated items, serialized as they are in the document.
+    pub duplicates: Vec<serde_json::Value>,
+}
+
+impl UniqueItemsErrorParams {
+    pub fn new<T: serde::Serialize>(duplicates: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            duplicates: duplicates
+                .into_iter()
+                .map(|item| serde_json::to_value(item).unwrap_or(serde_json::Value::Null))
+                .collect(),
+        }
+    }
+}
+
+impl ToDefaultMessage for UniqueItemsErrorParams {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        "The items must be unique.".to_string()
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ContainsErrorParams {
+    pub min_contains: usize,
+    pub max_contains: Option<usize>,
+    pub matches: usize,
+}
+
+impl ContainsErrorParams {
+    pub fn new(min_contains: usize, max_contains: Option<usize>, matches: usize) -> Self {
+        Self {
+            min_contains,
+            max_contains,
+            matches,
+        }
+    }
+
+    /// Whether the error is caused by `max_contains`, not by `min_contains`.
+    pub fn exceeds_max_contains(&self) -> bool {
+        self.matches >= self.min_contains
+            && self
+                .max_contains
+                .is_some_and(|max_contains| self.matches > max_contains)
+    }
+}
+
+impl ToDefaultMessage for ContainsErrorParams {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        match self.max_contains {
+            Some(max_contains) if self.exceeds_max_contains() => format!(
+                "The items must contain `<= {}` matching items.",
+                max_contains
+            ),
+            _ => format!(
+                "The items must contain `>= {}` matching items.",
+                self.min_contains
+            ),
+        }
+    }
+}
 
 // Object
 struct_error_params!(
@@ -143,6 +232,7 @@ struct_error_params!(
     #[default_message = "The size of the properties must be `<= {}`."]
     pub struct MaxPropertiesErrorParams {
         pub max_properties: usize,
+        pub size: usize,
     }
 );
 
@@ -151,5 +241,6 @@ struct_error_params!(
     #[default_message = "The size of the properties must be `>= {}`."]
     pub struct MinPropertiesErrorParams {
         pub min_properties: usize,
+        pub size: usize,
     }
 );
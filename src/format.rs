@@ -0,0 +1,160 @@
+//! The registry of the formats of `#[validate(format = "...")]`.
+//!
+//! The formats registered here are used by [`ValidateFormat`](crate::ValidateFormat),
+//! `#[validate(format = "...")]` and [`SchemaValidator`](crate::schema::SchemaValidator),
+//! in addition to the built-in formats of JSON Schema.
+//! Register the formats before the validation, because the unknown format panics.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! serde_valid::format::register("sku", |value| {
+//!     value.len() == 8 && value.starts_with("SKU-")
+//! });
+//!
+//! #[derive(Validate)]
+//! struct Item {
+//!     #[validate(format = "sku")]
+//!     sku: String,
+//! }
+//!
+//! assert!(Item { sku: "SKU-1234".to_owned() }.validate().is_ok());
+//! assert_eq!(
+//!     Item { sku: "1234".to_owned() }.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "sku": {
+//!                 "errors": ["The value must match the format of \"sku\"."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+
+mod builtin;
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+pub use builtin::BUILTIN_FORMATS;
+
+type FormatChecker = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+static REGISTRY: Lazy<RwLock<HashMap<String, FormatChecker>>> = Lazy::new(Default::default);
+
+/// The format implemented by the user type, which is registered by [`register_format`].
+///
+/// ```rust
+/// use serde_valid::format::Format;
+/// use serde_valid::ValidateFormat;
+///
+/// struct Semver;
+///
+/// impl Format for Semver {
+///     const NAME: &'static str = "semver";
+///
+///     fn is_valid(value: &str) -> bool {
+///         let parts = value.split('.').collect::<Vec<_>>();
+///         parts.len() == 3 && parts.iter().all(|part| part.parse::<u64>().is_ok())
+///     }
+/// }
+///
+/// serde_valid::format::register_format::<Semver>();
+///
+/// assert!("1.2.3".validate_format("semver").is_ok());
+/// assert!("1.2".validate_format("semver").is_err());
+/// ```
+pub trait Format {
+    /// The name of the format, like `"iban"`.
+    const NAME: &'static str;
+
+    fn is_valid(value: &str) -> bool;
+}
+
+/// Register the checker of the format.
+///
+/// The registered format takes precedence over the built-in format of the same name,
+/// and the registration of the same name replaces the previous checker.
+pub fn register<F>(name: impl Into<String>, checker: F)
+where
+    F: Fn(&str) -> bool + Send + Sync + 'static,
+{
+    REGISTRY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(name.into(), Arc::new(checker));
+}
+
+/// Register the format implemented by the [`Format`] trait.
+pub fn register_format<F>()
+where
+    F: Format + 'static,
+{
+    register(F::NAME, F::is_valid);
+}
+
+/// Whether the format is registered or built-in.
+pub fn is_known(name: &str) -> bool {
+    find_registered(name).is_some() || BUILTIN_FORMATS.contains(&name)
+}
+
+/// Check the value by the format, or `None` if the format is unknown.
+pub fn check(name: &str, value: &str) -> Option<bool> {
+    match find_registered(name) {
+        Some(checker) => Some(checker(value)),
+        None => builtin::check_builtin_format(name, value),
+    }
+}
+
+fn find_registered(name: &str) -> Option<FormatChecker> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(name)
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_format() {
+        assert!(!is_known("test-even-length"));
+        assert_eq!(check("test-even-length", "ab"), None);
+
+        register("test-even-length", |value| value.len() % 2 == 0);
+
+        assert!(is_known("test-even-length"));
+        assert_eq!(check("test-even-length", "ab"), Some(true));
+        assert_eq!(check("test-even-length", "abc"), Some(false));
+    }
+
+    #[test]
+    fn test_register_format_trait() {
+        struct Lowercase;
+
+        impl Format for Lowercase {
+            const NAME: &'static str = "test-lowercase";
+
+            fn is_valid(value: &str) -> bool {
+                value.chars().all(|ch| ch.is_lowercase())
+            }
+        }
+
+        register_format::<Lowercase>();
+
+        assert_eq!(check("test-lowercase", "abc"), Some(true));
+        assert_eq!(check("test-lowercase", "Abc"), Some(false));
+    }
+
+    #[test]
+    fn test_builtin_format_is_known() {
+        assert!(BUILTIN_FORMATS.iter().all(|format| is_known(format)));
+        assert_eq!(check("ipv4", "127.0.0.1"), Some(true));
+    }
+}
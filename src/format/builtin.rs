@@ -0,0 +1,377 @@
+//! The checkers of the built-in formats of JSON Schema.
+//!
+//! See <https://json-schema.org/understanding-json-schema/reference/string.html#built-in-formats>
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// The names of the built-in formats.
+pub const BUILTIN_FORMATS: &[&str] = &[
+    "email",
+    "uri",
+    "uri-reference",
+    "hostname",
+    "ipv4",
+    "ipv6",
+    "uuid",
+    "date",
+    "time",
+    "date-time",
+    "duration",
+    "json-pointer",
+    "regex",
+];
+
+/// Check the value by the built-in format, or `None` if the format is unknown.
+pub fn check_builtin_format(format: &str, value: &str) -> Option<bool> {
+    Some(match format {
+        "email" => is_email(value),
+        "uri" => is_uri(value),
+        "uri-reference" => is_uri_reference(value),
+        "hostname" => is_hostname(value),
+        "ipv4" => is_ipv4(value),
+        "ipv6" => is_ipv6(value),
+        "uuid" => is_uuid(value),
+        "date" => is_date(value),
+        "time" => is_time(value),
+        "date-time" => is_date_time(value),
+        "duration" => is_duration(value),
+        "json-pointer" => is_json_pointer(value),
+        "regex" => is_regex(value),
+        _ => return None,
+    })
+}
+
+/// The addr-spec of RFC 5322 without the quoted local part and the comments.
+fn is_email(value: &str) -> bool {
+    let (local, domain) = match value.rsplit_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    if local.is_empty() || local.len() > 64 {
+        return false;
+    }
+    let is_atext = |ch: char| ch.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(ch);
+    if !local
+        .split('.')
+        .all(|atom| !atom.is_empty() && atom.chars().all(is_atext))
+    {
+        return false;
+    }
+
+    match domain
+        .strip_prefix('[')
+        .and_then(|domain| domain.strip_suffix(']'))
+    {
+        Some(literal) => match literal.strip_prefix("IPv6:") {
+            Some(ipv6) => is_ipv6(ipv6),
+            None => is_ipv4(literal),
+        },
+        None => is_hostname(domain),
+    }
+}
+
+/// The hostname of RFC 1123.
+fn is_hostname(value: &str) -> bool {
+    let value = value.strip_suffix('.').unwrap_or(value);
+    !value.is_empty()
+        && value.len() <= 253
+        && value.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+        })
+}
+
+fn is_ipv4(value: &str) -> bool {
+    Ipv4Addr::from_str(value).is_ok()
+}
+
+fn is_ipv6(value: &str) -> bool {
+    Ipv6Addr::from_str(value).is_ok()
+}
+
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(i, ch)| match i {
+            8 | 13 | 18 | 23 => ch == '-',
+            _ => ch.is_ascii_hexdigit(),
+        })
+}
+
+/// The full-date of RFC 3339.
+fn is_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return false;
+    }
+    let (year, month, day) = match (
+        parse_digits(&bytes[0..4]),
+        parse_digits(&bytes[5..7]),
+        parse_digits(&bytes[8..10]),
+    ) {
+        (Some(year), Some(month), Some(day)) => (year, month, day),
+        _ => return false,
+    };
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days_in_month).contains(&day)
+}
+
+/// The full-time of RFC 3339, which requires the time offset.
+fn is_time(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() < 9 || bytes[2] != b':' || bytes[5] != b':' {
+        return false;
+    }
+    match (
+        parse_digits(&bytes[0..2]),
+        parse_digits(&bytes[3..5]),
+        parse_digits(&bytes[6..8]),
+    ) {
+        (Some(hour), Some(minute), Some(second)) if hour <= 23 && minute <= 59 && second <= 60 => {}
+        _ => return false,
+    }
+
+    let mut rest = &value[8..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(fraction.len());
+        if digits == 0 {
+            return false;
+        }
+        rest = &fraction[digits..];
+    }
+
+    match rest {
+        "Z" | "z" => true,
+        offset => {
+            let bytes = offset.as_bytes();
+            bytes.len() == 6
+                && (bytes[0] == b'+' || bytes[0] == b'-')
+                && bytes[3] == b':'
+                && matches!(
+                    (parse_digits(&bytes[1..3]), parse_digits(&bytes[4..6])),
+                    (Some(hour), Some(minute)) if hour <= 23 && minute <= 59
+                )
+        }
+    }
+}
+
+/// The date-time of RFC 3339.
+fn is_date_time(value: &str) -> bool {
+    match value.find(['T', 't']) {
+        Some(index) => is_date(&value[..index]) && is_time(&value[index + 1..]),
+        None => false,
+    }
+}
+
+/// The duration of RFC 3339 Appendix A.
+fn is_duration(value: &str) -> bool {
+    static DURATION: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"^P(?:\d+W|(?:\d+Y)?(?:\d+M)?(?:\d+D)?(?:T(?:\d+H)?(?:\d+M)?(?:\d+S)?)?)$")
+            .unwrap()
+    });
+    value != "P" && !value.ends_with('T') && DURATION.is_match(value)
+}
+
+/// The JSON Pointer of RFC 6901.
+fn is_json_pointer(value: &str) -> bool {
+    (value.is_empty() || value.starts_with('/'))
+        && value
+            .split('~')
+            .skip(1)
+            .all(|escaped| escaped.starts_with('0') || escaped.starts_with('1'))
+}
+
+fn is_regex(value: &str) -> bool {
+    Regex::new(value).is_ok()
+}
+
+/// The absolute URI of RFC 3986.
+fn is_uri(value: &str) -> bool {
+    match value.split_once(':') {
+        Some((scheme, _)) => is_scheme(scheme) && is_uri_reference_chars(value),
+        None => false,
+    }
+}
+
+/// The URI or the relative reference of RFC 3986.
+fn is_uri_reference(value: &str) -> bool {
+    let first_segment = value.split(['/', '?', '#']).next().unwrap_or_default();
+    if first_segment.contains(':') {
+        is_uri(value)
+    } else {
+        is_uri_reference_chars(value)
+    }
+}
+
+fn is_scheme(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+    matches!(chars.next(), Some(ch) if ch.is_ascii_alphabetic())
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || "+-.".contains(ch))
+}
+
+fn is_uri_reference_chars(value: &str) -> bool {
+    if value.matches('#').count() > 1 {
+        return false;
+    }
+    let bytes = value.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' => {
+                if !(index + 2 < bytes.len()
+                    && bytes[index + 1].is_ascii_hexdigit()
+                    && bytes[index + 2].is_ascii_hexdigit())
+                {
+                    return false;
+                }
+                index += 3;
+                continue;
+            }
+            ch if ch.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@/?#[]".contains(&ch) => {}
+            _ => return false,
+        }
+        index += 1;
+    }
+    true
+}
+
+fn parse_digits(digits: &[u8]) -> Option<u32> {
+    digits.iter().try_fold(0, |number, digit| {
+        digit
+            .is_ascii_digit()
+            .then(|| number * 10 + u32::from(digit - b'0'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(format: &str, value: &str) -> bool {
+        check_builtin_format(format, value).unwrap()
+    }
+
+    #[test]
+    fn test_builtin_formats_are_known() {
+        for format in BUILTIN_FORMATS {
+            assert!(check_builtin_format(format, "").is_some());
+        }
+        assert!(check_builtin_format("unknown", "").is_none());
+    }
+
+    #[test]
+    fn test_email_format() {
+        assert!(check("email", "joe.bloggs@example.com"));
+        assert!(check("email", "te~st+tag@example.com"));
+        assert!(check("email", "joe@[127.0.0.1]"));
+        assert!(check("email", "joe@[IPv6:::1]"));
+        assert!(!check("email", "joe.example.com"));
+        assert!(!check("email", ".joe@example.com"));
+        assert!(!check("email", "jo..e@example.com"));
+        assert!(!check("email", "joe@-example.com"));
+    }
+
+    #[test]
+    fn test_hostname_format() {
+        assert!(check("hostname", "www.example.com"));
+        assert!(check("hostname", "xn--4gbwdl.xn--wgbh1c"));
+        assert!(!check("hostname", "-a-host-name-that-starts-with--"));
+        assert!(!check("hostname", "not_a_valid_host_name"));
+        assert!(!check("hostname", &"a".repeat(64)));
+        assert!(!check("hostname", ""));
+    }
+
+    #[test]
+    fn test_ip_formats() {
+        assert!(check("ipv4", "192.168.0.1"));
+        assert!(!check("ipv4", "256.256.256.256"));
+        assert!(!check("ipv4", "087.10.0.1"));
+        assert!(check("ipv6", "::1"));
+        assert!(check("ipv6", "2001:db8::8a2e:370:7334"));
+        assert!(!check("ipv6", "12345::"));
+        assert!(!check("ipv6", "192.168.0.1"));
+    }
+
+    #[test]
+    fn test_uuid_format() {
+        assert!(check("uuid", "2eb8aa08-aa98-11ea-b4aa-73b441d16380"));
+        assert!(!check("uuid", "2eb8aa08-aa98-11ea-b4aa-73b441d1638"));
+        assert!(!check("uuid", "2eb8aa08aa9811eab4aa73b441d16380"));
+        assert!(!check("uuid", "2eb8aa08-aa98-11ea-b4aa-73b441d1638g"));
+    }
+
+    #[test]
+    fn test_date_and_time_formats() {
+        assert!(check("date", "2020-02-29"));
+        assert!(!check("date", "2021-02-29"));
+        assert!(!check("date", "2020-13-01"));
+        assert!(!check("date", "2020/09/10"));
+        assert!(!check("date", "20é0-09-10"));
+        assert!(check("time", "08:30:06Z"));
+        assert!(check("time", "23:59:60.123+09:00"));
+        assert!(!check("time", "08:30:06"));
+        assert!(!check("time", "24:00:00Z"));
+        assert!(!check("time", "08:30:06.Z"));
+        assert!(check("date-time", "1963-06-19T08:30:06.283185Z"));
+        assert!(check("date-time", "1963-06-19t08:30:06-05:00"));
+        assert!(!check("date-time", "1963-06-19 08:30:06Z"));
+        assert!(!check("date-time", "1963-06-19"));
+    }
+
+    #[test]
+    fn test_duration_format() {
+        assert!(check("duration", "P4DT12H30M5S"));
+        assert!(check("duration", "PT1M"));
+        assert!(check("duration", "P2W"));
+        assert!(!check("duration", "P"));
+        assert!(!check("duration", "P1DT"));
+        assert!(!check("duration", "PT1D"));
+        assert!(!check("duration", "P1W1D"));
+    }
+
+    #[test]
+    fn test_json_pointer_format() {
+        assert!(check("json-pointer", ""));
+        assert!(check("json-pointer", "/foo/0/a~1b/m~0n"));
+        assert!(!check("json-pointer", "foo"));
+        assert!(!check("json-pointer", "/foo~2"));
+        assert!(!check("json-pointer", "/foo~"));
+    }
+
+    #[test]
+    fn test_regex_format() {
+        assert!(check("regex", r"^\d{4}$"));
+        assert!(!check("regex", "^(abc]"));
+    }
+
+    #[test]
+    fn test_uri_formats() {
+        assert!(check("uri", "https://example.com/path?query=1#fragment"));
+        assert!(check("uri", "urn:isbn:0451450523"));
+        assert!(check("uri", "http://example.com/%E3%81%82"));
+        assert!(!check("uri", "//example.com/path"));
+        assert!(!check("uri", "http://example.com/a b"));
+        assert!(!check("uri", "http://example.com/%zz"));
+        assert!(check("uri-reference", "//example.com/path"));
+        assert!(check("uri-reference", "../path?query#fragment"));
+        assert!(check("uri-reference", "https://example.com"));
+        assert!(!check("uri-reference", "1http://example.com"));
+        assert!(!check("uri-reference", "#a#b"));
+    }
+}
@@ -1,7 +1,11 @@
 mod from_json_reader;
+mod from_json_reader_async;
 mod from_json_slice;
+mod from_json_slice_async;
 mod from_json_str;
+mod from_json_str_async;
 mod from_json_value;
+mod from_json_value_async;
 mod to_json_string;
 mod to_json_value;
 mod to_json_writer;
@@ -9,9 +13,13 @@ mod to_json_writer;
 pub use serde_json::{json, Map, Value};
 
 pub use from_json_reader::FromJsonReader;
+pub use from_json_reader_async::FromJsonReaderAsync;
 pub use from_json_slice::FromJsonSlice;
+pub use from_json_slice_async::FromJsonSliceAsync;
 pub use from_json_str::FromJsonStr;
+pub use from_json_str_async::FromJsonStrAsync;
 pub use from_json_value::FromJsonValue;
+pub use from_json_value_async::FromJsonValueAsync;
 pub use to_json_string::ToJsonString;
 pub use to_json_value::ToJsonValue;
 pub use to_json_writer::ToJsonWriter;
@@ -0,0 +1,50 @@
+pub trait FromJsonReaderAsync
+where
+    Self: Sized,
+{
+    /// Convert from json reader, with the async validations.
+    ///
+    /// ```should_panic
+    /// use std::fs::File;
+    /// use serde::Deserialize;
+    /// use serde_valid::{AsyncValidate, Validate};
+    /// use serde_valid::json::FromJsonReaderAsync;
+    ///
+    /// async fn exists(_val: &i32) -> Result<(), serde_valid::validation::Error> {
+    ///     Ok(())
+    /// }
+    ///
+    /// #[derive(Debug, Validate, AsyncValidate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     #[validate(custom_async(exists))]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = pollster::block_on(TestStruct::from_json_reader_async(File::open("foo.txt").unwrap()));
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json_reader_async<R>(
+        reader: R,
+    ) -> impl std::future::Future<Output = Result<Self, crate::Error<serde_json::Error>>>
+    where
+        R: std::io::Read;
+}
+
+impl<T> FromJsonReaderAsync for T
+where
+    T: serde::de::DeserializeOwned + crate::AsyncValidate,
+{
+    async fn from_json_reader_async<R>(reader: R) -> Result<Self, crate::Error<serde_json::Error>>
+    where
+        R: std::io::Read,
+    {
+        let model: T = serde_json::from_reader(reader)?;
+        model
+            .validate_async()
+            .await
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
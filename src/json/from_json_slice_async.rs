@@ -0,0 +1,46 @@
+pub trait FromJsonSliceAsync<'de>
+where
+    Self: Sized,
+{
+    /// Convert from json slice, with the async validations.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::{AsyncValidate, Validate};
+    /// use serde_valid::json::FromJsonSliceAsync;
+    ///
+    /// async fn exists(_val: &str) -> Result<(), serde_valid::validation::Error> {
+    ///     Ok(())
+    /// }
+    ///
+    /// #[derive(Debug, Validate, AsyncValidate, Deserialize)]
+    /// struct TestStruct<'a> {
+    ///     #[validate(min_length = 1)]
+    ///     #[validate(custom_async(exists))]
+    ///     val: &'a str,
+    /// }
+    ///
+    /// let s = pollster::block_on(TestStruct::from_json_slice_async(br#"{ "val": "abcde" }"#));
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json_slice_async(
+        slice: &'de [u8],
+    ) -> impl std::future::Future<Output = Result<Self, crate::Error<serde_json::Error>>>;
+}
+
+impl<'de, T> FromJsonSliceAsync<'de> for T
+where
+    T: serde::de::Deserialize<'de> + crate::AsyncValidate,
+{
+    async fn from_json_slice_async(
+        slice: &'de [u8],
+    ) -> Result<Self, crate::Error<serde_json::Error>> {
+        let model: T = serde_json::from_slice(slice)?;
+        model
+            .validate_async()
+            .await
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
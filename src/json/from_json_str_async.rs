@@ -0,0 +1,44 @@
+pub trait FromJsonStrAsync<'de>
+where
+    Self: Sized,
+{
+    /// Convert from json str, with the async validations.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::{AsyncValidate, Validate};
+    /// use serde_valid::json::FromJsonStrAsync;
+    ///
+    /// async fn exists(_val: &str) -> Result<(), serde_valid::validation::Error> {
+    ///     Ok(())
+    /// }
+    ///
+    /// #[derive(Debug, Validate, AsyncValidate, Deserialize)]
+    /// struct TestStruct<'a> {
+    ///     #[validate(min_length = 1)]
+    ///     #[validate(custom_async(exists))]
+    ///     val: &'a str,
+    /// }
+    ///
+    /// let s = pollster::block_on(TestStruct::from_json_str_async(r#"{ "val": "abcde" }"#));
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json_str_async(
+        str: &'de str,
+    ) -> impl std::future::Future<Output = Result<Self, crate::Error<serde_json::Error>>>;
+}
+
+impl<'de, T> FromJsonStrAsync<'de> for T
+where
+    T: serde::de::Deserialize<'de> + crate::AsyncValidate,
+{
+    async fn from_json_str_async(str: &'de str) -> Result<Self, crate::Error<serde_json::Error>> {
+        let model: Self = serde_json::from_str(str)?;
+        model
+            .validate_async()
+            .await
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,46 @@
+pub trait FromJsonValueAsync
+where
+    Self: Sized,
+{
+    /// Convert from [`serde_json::Value`](serde_json::Value), with the async validations.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::{AsyncValidate, Validate};
+    /// use serde_valid::json::{json, FromJsonValueAsync};
+    ///
+    /// async fn exists(_val: &i32) -> Result<(), serde_valid::validation::Error> {
+    ///     Ok(())
+    /// }
+    ///
+    /// #[derive(Debug, Validate, AsyncValidate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     #[validate(custom_async(exists))]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = pollster::block_on(TestStruct::from_json_value_async(json!({ "val": 1234 })));
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json_value_async(
+        value: serde_json::Value,
+    ) -> impl std::future::Future<Output = Result<Self, crate::Error<serde_json::Error>>>;
+}
+
+impl<T> FromJsonValueAsync for T
+where
+    T: serde::de::DeserializeOwned + crate::AsyncValidate,
+{
+    async fn from_json_value_async(
+        value: serde_json::Value,
+    ) -> Result<Self, crate::Error<serde_json::Error>> {
+        let model: T = serde_json::from_value(value)?;
+        model
+            .validate_async()
+            .await
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -43,6 +43,7 @@
 //! | String  | `#[validate(max_length = 5)]`        | [`ValidateMaxLength`](ValidateMaxLength)               | [maxLength](https://json-schema.org/understanding-json-schema/reference/string.html#length)            |
 //! | String  | `#[validate(min_length = 5)]`        | [`ValidateMinLength`](ValidateMinLength)               | [minLength](https://json-schema.org/understanding-json-schema/reference/string.html#length)            |
 //! | String  | `#[validate(pattern = r"^\d{5}$")]`  | [`ValidatePattern`](ValidatePattern)                   | [pattern](https://json-schema.org/understanding-json-schema/reference/string.html#regular-expressions) |
+//! | String  | `#[validate(format = "email")]`      | [`ValidateFormat`](ValidateFormat)                     | [format](https://json-schema.org/understanding-json-schema/reference/string.html#built-in-formats)     |
 //! | Numeric | `#[validate(maximum = 5)]`           | [`ValidateMaximum`](ValidateMaximum)                   | [maximum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range)              |
 //! | Numeric | `#[validate(minimum = 5)]`           | [`ValidateMinimum`](ValidateMinimum)                   | [minimum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range)              |
 //! | Numeric | `#[validate(exclusive_maximum = 5)]` | [`ValidateExclusiveMaximum`](ValidateExclusiveMaximum) | [exclusiveMaximum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range)     |
@@ -50,10 +51,64 @@
 //! | Numeric | `#[validate(multiple_of = 5)]`       | [`ValidateMultipleOf`](ValidateMultipleOf)             | [multipleOf](https://json-schema.org/understanding-json-schema/reference/numeric.html#multiples)       |
 //! | Object  | `#[validate(max_properties = 5)]`    | [`ValidateMaxProperties`](ValidateMaxProperties)       | [maxProperties](https://json-schema.org/understanding-json-schema/reference/object.html#size)          |
 //! | Object  | `#[validate(min_properties = 5)]`    | [`ValidateMinProperties`](ValidateMinProperties)       | [minProperties](https://json-schema.org/understanding-json-schema/reference/object.html#size)          |
+//! | Object  | `#[validate(keys(pattern = r"^[a-z_]+$"))]` | - (any validation of the keys)          | [propertyNames](https://json-schema.org/understanding-json-schema/reference/object.html#property-names) |
+//! | Object  | `#[validate(values(max_length = 64))]` | - (any validation of the values)                   | [additionalProperties](https://json-schema.org/understanding-json-schema/reference/object.html#additional-properties) |
 //! | Array   | `#[validate(max_items = 5)]`         | [`ValidateMaxItems`](ValidateMaxItems)                 | [maxItems](https://json-schema.org/understanding-json-schema/reference/array.html#length)              |
 //! | Array   | `#[validate(min_items = 5)]`         | [`ValidateMinItems`](ValidateMinItems)                 | [minItems](https://json-schema.org/understanding-json-schema/reference/array.html#length)              |
 //! | Array   | `#[validate(unique_items)]`          | [`ValidateUniqueItems`](ValidateUniqueItems)           | [uniqueItems](https://json-schema.org/understanding-json-schema/reference/array.html#unique_items)     |
+//! | Array   | `#[validate(contains(enumerate("admin"), min_contains = 1))]` | [`ValidateContains`](ValidateContains) | [contains](https://json-schema.org/understanding-json-schema/reference/array.html#contains)            |
 //! | Generic | `#[validate(enumerate(5, 10, 15))]`  | [`ValidateEnumerate`](ValidateEnumerate)               | [enum](https://json-schema.org/understanding-json-schema/reference/generic.html#enumerated-values)     |
+//! | Generic | `#[validate(const_value = "v1")]`    | [`ValidateConstValue`](ValidateConstValue)             | [const](https://json-schema.org/understanding-json-schema/reference/generic.html#constant-values)      |
+//!
+//! The `format` supports the built-in formats of `email`, `uri`, `uri-reference`, `hostname`,
+//! `ipv4`, `ipv6`, `uuid`, `date`, `time`, `date-time` (RFC 3339), `duration`, `json-pointer`
+//! and `regex`. The domain formats like `iban` can be registered by [`format::register`]
+//! and declared like `#[validate(format = "iban")]` in the same way.
+//! The validation with the unregistered format panics, because it is the mistake of the program.
+//!
+//! The `contains` takes any field validation as the predicate of the items, like
+//! `contains(minimum = 10)` or `contains(custom(is_admin))`, and the count of the matching items
+//! must be `>= min_contains` (default `1`) and `<= max_contains` if it is given.
+//!
+//! The nested `#[validate]` fields can be the sequences like `Vec<T>`, `[T; N]`, `VecDeque<T>`,
+//! `HashSet<T>`, `BTreeSet<T>`, `&[T]` and the tuples, the pointers like `Box<T>`, `Rc<T>`, `Arc<T>`
+//! and `Cow<T>`, `Option<T>`, or the maps of `HashMap<K, V>`, `BTreeMap<K, V>` and `IndexMap<K, V>`
+//! whose errors are keyed by the map keys. The errors of the `HashSet<T>` items are reported
+//! on the set without the index, because its iteration order changes between the processes.
+//! The validations like `max_length` also apply element-wise through the sequences, the tuples,
+//! `Box<[T]>` and `Cow<[T]>`, and to the inner value of `Box<T>`, `&T`, `Rc<T>` and `Arc<T>`,
+//! like `Box<i32>` or `&String`.
+//! The nesting of `Box<T>` and `&T` over the sequences like `Box<Vec<T>>` is not supported.
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct ServiceConfig {
+//!     #[validate(minimum = 1)]
+//!     port: u16,
+//! }
+//!
+//! let configs = HashMap::from([("api".to_owned(), ServiceConfig { port: 0 })]);
+//!
+//! assert_eq!(
+//!     configs.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "api": {
+//!                 "errors": [],
+//!                 "properties": {
+//!                     "port": { "errors": ["The number must be `>= 1`."] }
+//!                 }
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
 //!
 //! ## Complete Constructor (Deserialization)
 //!
@@ -110,6 +165,57 @@
 //! );
 //! ```
 //!
+//! ## JSON Schema
+//!
+//! For publishing the schema, provides [`JsonSchema`](schema::JsonSchema) derive.
+//! The schema is built from the field types and the same `#[validate(...)]` attributes.
+//! It follows the serde attributes `rename`, `rename_all`, `flatten`, `default` and `skip`,
+//! and the enum representation by `tag`, `content` or `untagged`.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::{JsonSchema, Validate};
+//!
+//! #[derive(JsonSchema, Validate)]
+//! struct SampleStruct {
+//!     #[validate(maximum = 100)]
+//!     val: i32,
+//! }
+//!
+//! assert_eq!(
+//!     SampleStruct::json_schema_document(),
+//!     json!({
+//!         "$schema": "https://json-schema.org/draft/2020-12/schema",
+//!         "type": "object",
+//!         "properties": {
+//!             "val": {
+//!                 "type": "integer",
+//!                 "maximum": 100
+//!             }
+//!         },
+//!         "required": ["val"]
+//!     })
+//! );
+//! ```
+//!
+//! For the untyped values, [`SchemaValidator`](schema::SchemaValidator) compiles
+//! the JSON Schema document and validates `serde_json::Value` with the same errors format.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::schema::SchemaValidator;
+//!
+//! let validator = SchemaValidator::compile(&json!({
+//!     "type": "object",
+//!     "properties": {
+//!         "val": { "type": "integer", "maximum": 100 }
+//!     }
+//! }))
+//! .unwrap();
+//!
+//! assert!(validator.validate(&json!({ "val": 101 })).is_err());
+//! ```
+//!
 //! ## Custom Message
 //!
 //! For user custom message, Serde Valid provides `message_fn` or `message`.
@@ -119,8 +225,11 @@
 //! use serde_valid::Validate;
 //!
 //! #[inline]
-//! fn min_error_message(_params: &serde_valid::MinItemsErrorParams) -> String {
-//!     "this is min custom message_fn.".to_string()
+//! fn min_error_message(params: &serde_valid::MinItemsErrorParams) -> String {
+//!     format!(
+//!         "this is min custom message_fn: got {}, expected >= {}.",
+//!         params.length, params.min_items
+//!     )
 //! }
 //!
 //! #[derive(Validate)]
@@ -139,7 +248,7 @@
 //!         "properties": {
 //!             "val": {
 //!                 "errors": [
-//!                     "this is min custom message_fn.",
+//!                     "this is min custom message_fn: got 3, expected >= 4.",
 //!                     "this is max custom message."
 //!                 ]
 //!             }
@@ -149,6 +258,61 @@
 //! );
 //! ```
 //!
+//! The `message` can refer to the fields of the error params with the placeholders,
+//! which are checked at compile time.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(max_length = 3, message = "must be at most {max_length} characters, got {length}.")]
+//!     val: String,
+//! }
+//!
+//! let s = SampleStruct { val: "abcd".to_owned() };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {
+//!                 "errors": ["must be at most 3 characters, got 4."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ### Localization
+//!
+//! The default messages can be rendered in another language by the [`MessageCatalog`](error::MessageCatalog).
+//! [`JsonMessageCatalog`](error::JsonMessageCatalog) loads the message bundle from JSON.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::error::JsonMessageCatalog;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct(#[validate(maximum = 5)] i32);
+//!
+//! let catalog: JsonMessageCatalog = r#"{ "maximum": "Le nombre doit être `<= {maximum}`." }"#
+//!     .parse()
+//!     .unwrap();
+//!
+//! assert_eq!(
+//!     SampleStruct(6).validate().unwrap_err().localize(&catalog).to_string(),
+//!     json!({
+//!         "errors": ["Le nombre doit être `<= 5`."]
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
 //! ## Custom method
 //!
 //! You can use your custom validation using by `#[validate(custom)]`.
@@ -171,6 +335,51 @@
 //! assert!(s.validate().is_ok());
 //! ```
 //!
+//! The arguments, which can be any expressions, are passed to the function after the field reference.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! fn in_range(val: &i32, min: i32, max: i32) -> Result<(), serde_valid::validation::Error> {
+//!     if (min..=max).contains(val) {
+//!         Ok(())
+//!     } else {
+//!         Err(serde_valid::validation::Error::Custom(format!(
+//!             "The value must be in {min}..={max}."
+//!         )))
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(custom(in_range(1, 10)))]
+//!     val: i32,
+//! }
+//!
+//! let s = SampleStruct { val: 11 };
+//!
+//! assert!(s.validate().is_err());
+//! ```
+//!
+//! For the small checks, write the closure by `#[validate(custom = |...| ...)]`,
+//! or the boolean expression by `#[validate(expr = "...", message = "...")]`.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(custom = |val: &i32| if *val >= 0 { Ok(()) } else { Err(serde_valid::validation::Error::Custom("negative".to_owned())) })]
+//!     start: i32,
+//!     #[validate(expr = "self.start <= self.end", message = "The end must be after the start.")]
+//!     end: i32,
+//! }
+//!
+//! let s = SampleStruct { start: 2, end: 1 };
+//!
+//! assert!(s.validate().is_err());
+//! ```
+//!
 //! ## Rules
 //!
 //! If you want to check multi fields validation, can use `#[rule]`.
@@ -228,6 +437,247 @@
 //! assert!(s.validate().is_ok());
 //! ```
 //!
+//! The rules and the custom validations can also return `Vec<Error>` to report every problem at once,
+//! or [`Errors`](validation::Errors) which is merged into the errors tree.
+//! See [`IntoVecErrors`](validation::IntoVecErrors).
+//!
+//! If the rule error belongs to the field, set the field by `target`.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! fn password_confirmed(password: &str, confirm_password: &str) -> Result<(), serde_valid::validation::Error> {
+//!     if password == confirm_password {
+//!         Ok(())
+//!     } else {
+//!         Err(serde_valid::validation::Error::Custom(
+//!             "The passwords do not match.".to_owned(),
+//!         ))
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! #[rule(password_confirmed(password, confirm_password), target = confirm_password)]
+//! struct SampleStruct {
+//!     password: String,
+//!     confirm_password: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     password: "password".to_owned(),
+//!     confirm_password: "passw0rd".to_owned(),
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "confirm_password": {
+//!                 "errors": ["The passwords do not match."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! The rule of the enum receives the whole value as `self`,
+//! and the unit variant can also have the rule without arguments.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! fn plan_rule(plan: &Plan) -> Result<(), serde_valid::validation::Error> {
+//!     match plan {
+//!         Plan::Team { seats } if *seats < 2 => Err(serde_valid::validation::Error::Custom(
+//!             "The team plan needs at least 2 seats.".to_owned(),
+//!         )),
+//!         _ => Ok(()),
+//!     }
+//! }
+//!
+//! fn legacy_rule() -> Result<(), serde_valid::validation::Error> {
+//!     Err(serde_valid::validation::Error::Custom(
+//!         "`Legacy` is not allowed after 2025.".to_owned(),
+//!     ))
+//! }
+//!
+//! #[derive(Validate)]
+//! #[rule(plan_rule(self))]
+//! enum Plan {
+//!     Team { seats: u32 },
+//!     #[rule(legacy_rule())]
+//!     Legacy,
+//! }
+//!
+//! assert_eq!(
+//!     Plan::Team { seats: 1 }.validate().unwrap_err().to_string(),
+//!     json!({ "errors": ["The team plan needs at least 2 seats."] }).to_string()
+//! );
+//! assert_eq!(
+//!     Plan::Legacy.validate().unwrap_err().to_string(),
+//!     json!({ "errors": ["`Legacy` is not allowed after 2025."] }).to_string()
+//! );
+//! ```
+//!
+//! ## Validation Context
+//!
+//! If the validation needs the runtime values, declare the context type by `#[validate(context = "...")]`,
+//! and validate by [`ValidateWithContext`](ValidateWithContext).
+//! The context is passed to `#[validate(custom(...), context)]`, to `ctx` of `#[rule]`,
+//! and to the nested `#[validate]` fields, including the items of `Vec<T>`, `Option<T>` and the maps.
+//! The nested types implementing [`Validate`] by hand are wrapped by [`WithoutContext`].
+//! The types with the context do not implement [`Validate`], so they are deserialized by serde
+//! and then validated by [`ValidateWithContext::validate_with_context`].
+//!
+//! ```rust
+//! use serde_valid::{Validate, ValidateWithContext};
+//!
+//! struct Limits {
+//!     max_total: i32,
+//! }
+//!
+//! fn total_limit(limits: &Limits, a: &i32, b: &i32) -> Result<(), serde_valid::validation::Error> {
+//!     if a + b <= limits.max_total {
+//!         Ok(())
+//!     } else {
+//!         Err(serde_valid::validation::Error::Custom(
+//!             "The total is over the limit.".to_owned(),
+//!         ))
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! #[validate(context = "Limits")]
+//! #[rule(total_limit(ctx, a, b))]
+//! struct SampleStruct {
+//!     a: i32,
+//!     b: i32,
+//! }
+//!
+//! let s = SampleStruct { a: 3, b: 4 };
+//!
+//! assert!(s.validate_with_context(&Limits { max_total: 10 }).is_ok());
+//! assert!(s.validate_with_context(&Limits { max_total: 5 }).is_err());
+//! ```
+//!
+//! ## Validation Groups
+//!
+//! The validations with `groups(...)` run only by [`Validate::validate_group`] of the groups,
+//! in addition to the validations without groups. [`Validate::validate`] runs the validations without groups only.
+//! The groups are the types implementing [`ValidationGroup`](ValidationGroup),
+//! so the misspelled groups are the compile errors.
+//! The nested `#[validate]` fields receive the same group.
+//!
+//! ```rust
+//! use serde_valid::{Validate, ValidationGroup};
+//!
+//! struct Create;
+//! impl ValidationGroup for Create {}
+//!
+//! struct Update;
+//! impl ValidationGroup for Update {}
+//!
+//! #[derive(Validate)]
+//! struct UserDto {
+//!     #[validate(expr = "id.is_none()", message = "The id is forbidden on create.", groups(Create))]
+//!     #[validate(expr = "id.is_some()", message = "The id is required on update.", groups(Update))]
+//!     id: Option<u64>,
+//!     #[validate(min_length = 1)]
+//!     name: String,
+//! }
+//!
+//! let s = UserDto { id: Some(1), name: "Alice".to_owned() };
+//!
+//! assert!(s.validate().is_ok());
+//! assert!(s.validate_group(Create).is_err());
+//! assert!(s.validate_group(Update).is_ok());
+//! ```
+//!
+//! ## Conditional Validation
+//!
+//! The validation with `when = path_to_fn` or `when = "expr"` runs only when the predicate over `self` holds.
+//!
+//! In [`JsonSchema`], only the predicate of the form `"self.field == literal"` (or `"literal == self.field"`)
+//! becomes `if` / `then` of JSON Schema, because JSON Schema can't evaluate the Rust code.
+//! The keywords with the other predicates, such as `when = path_to_fn`, `!=`, `&&` or the method calls,
+//! are left out of the schema, so the schema accepts the values which the predicate would reject.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! fn is_shipping(order: &Order) -> bool {
+//!     order.shipping
+//! }
+//!
+//! #[derive(Validate)]
+//! struct Order {
+//!     shipping: bool,
+//!     country: String,
+//!     #[validate(pattern = r"^DE\d{9}$", when = "self.country == \"DE\"")]
+//!     vat_number: String,
+//!     #[validate(min_length = 1, when = is_shipping)]
+//!     address: String,
+//! }
+//!
+//! let s = Order {
+//!     shipping: false,
+//!     country: "FR".to_owned(),
+//!     vat_number: "FR12345678901".to_owned(),
+//!     address: "".to_owned(),
+//! };
+//!
+//! assert!(s.validate().is_ok());
+//! ```
+//!
+//! ## Async Validation
+//!
+//! If the validation needs to await the I/O, such as the database lookup,
+//! derive [`AsyncValidate`](AsyncValidate) and use `#[validate(custom_async(...))]` or `#[rule_async(...)]`.
+//! The async checks run after the sync checks, and the errors are merged.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_valid::{AsyncValidate, Validate};
+//! use serde_valid::json::{json, FromJsonValueAsync};
+//!
+//! async fn username_not_taken(username: &str) -> Result<(), serde_valid::validation::Error> {
+//!     if username == "taken" {
+//!         Err(serde_valid::validation::Error::Custom(
+//!             "The username is already taken.".to_owned(),
+//!         ))
+//!     } else {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[derive(Debug, Deserialize, Validate, AsyncValidate)]
+//! struct SampleStruct {
+//!     #[validate(min_length = 1)]
+//!     #[validate(custom_async(username_not_taken))]
+//!     username: String,
+//! }
+//!
+//! let err = pollster::block_on(SampleStruct::from_json_value_async(json!({ "username": "taken" })))
+//!     .unwrap_err();
+//!
+//! assert_eq!(
+//!     err.as_validation_errors().unwrap().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "username": {
+//!                 "errors": ["The username is already taken."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
 //! ## Validate Traits
 //!
 //! By implementing the validation trait, Your original type can uses Serde Valid validations.
@@ -435,49 +885,142 @@
 //!     .to_string()
 //! );
 //! ```
+//!
+//! ### Flat Errors
+//! [`Errors::flatten`](validation::Errors::flatten) lists the errors with the JSON Pointer of the invalid value.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(maximum = 5)]
+//!     vals: Vec<i32>,
+//! }
+//!
+//! let s = SampleStruct { vals: vec![1, 6] };
+//!
+//! assert_eq!(
+//!     serde_json::to_value(s.validate().unwrap_err().flatten()).unwrap(),
+//!     json!([
+//!         {
+//!             "path": "/vals/1",
+//!             "message": "The number must be `<= 5`."
+//!         }
+//!     ])
+//! );
+//! ```
+//!
+//! ### Structured Errors
+//! [`Errors::structured`](validation::Errors::structured) serializes each error
+//! with the stable `code` and the `params`, for the clients which build their own messages.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct(#[validate(maximum = 5)] i32);
+//!
+//! let s = SampleStruct(6);
+//!
+//! assert_eq!(
+//!     serde_json::to_value(s.validate().unwrap_err().structured()).unwrap(),
+//!     json!({
+//!         "errors": [
+//!             {
+//!                 "code": "maximum",
+//!                 "params": { "maximum": 5, "value": 6 },
+//!                 "message": "The number must be `<= 5`."
+//!             }
+//!         ]
+//!     })
+//! );
+//! ```
 
-pub mod error;
-mod traits;
-pub mod validation;
-
-use indexmap::IndexMap;
-
-pub use error::{
-    EnumerateErrorParams, Error, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
-    MaxItemsErrorParams, MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams,
-    MinItemsErrorParams, MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams,
-    MultipleOfErrorParams, PatternErrorParams, UniqueItemsErrorParams,
-};
-pub use validation::{
-    ValidateEnumerate, ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaxItems,
-    ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems, ValidateMinLength,
-    ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
-    ValidateUniqueItems,
-};
-
-pub trait Validate {
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors>;
-}
-
-impl<T> Validate for Vec<T>
-where
-    T: Validate,
-{
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
-        for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
-                items.insert(index, errors);
-            }
-        }
-
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Array(
-                validation::ArrayErrors::new(vec![], items),
-            ))
-        }
+pub mod error;
+pub mod format;
+pub mod schema;
+mod traits;
+pub mod validation;
+
+use indexmap::IndexMap;
+
+pub use error::{
+    ConstErrorParams, ContainsErrorParams, EnumerateErrorParams, Error,
+    ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams, FormatErrorParams,
+    MaxItemsErrorParams, MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams,
+    MinItemsErrorParams, MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams,
+    MultipleOfErrorParams, PatternErrorParams, UniqueItemsErrorParams,
+};
+pub use schema::JsonSchema;
+pub use validation::{
+    ValidateConstValue, ValidateContains, ValidateEnumerate, ValidateExclusiveMaximum,
+    ValidateExclusiveMinimum, ValidateFormat, ValidateMaxItems, ValidateMaxLength,
+    ValidateMaxProperties, ValidateMaximum, ValidateMinItems, ValidateMinLength,
+    ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
+    ValidateUniqueItems,
+};
+
+pub trait Validate {
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors>;
+
+    /// Validate with the validations of the group, in addition to the validations without groups.
+    ///
+    /// The nested `#[validate]` fields receive the same group.
+    /// Without any groups in the type, this is same as [`Validate::validate`].
+    fn validate_group<G>(&self, group: G) -> std::result::Result<(), self::validation::Errors>
+    where
+        Self: Sized,
+        G: ValidationGroup,
+    {
+        let _ = group;
+        self.validate_group_id(G::group_id())
+    }
+
+    /// [`Validate::validate_group`] by the id of the group, which the nested fields receive.
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        let _ = group;
+        self.validate()
+    }
+}
+
+/// The group of the validations, which is named by `#[validate(..., groups(...))]`
+/// and selected by [`Validate::validate_group`].
+///
+/// ```rust
+/// use serde_valid::ValidationGroup;
+///
+/// struct Create;
+///
+/// impl ValidationGroup for Create {}
+/// ```
+pub trait ValidationGroup: 'static {
+    fn group_id() -> std::any::TypeId
+    where
+        Self: Sized,
+    {
+        std::any::TypeId::of::<Self>()
+    }
+}
+
+impl<T> Validate for Vec<T>
+where
+    T: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), T::validate)
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), |item| item.validate_group_id(group))
     }
 }
 
@@ -486,23 +1029,180 @@ where
     T: Validate,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
-        for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
-                items.insert(index, errors);
+        validate_items(self.iter(), T::validate)
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), |item| item.validate_group_id(group))
+    }
+}
+
+impl<T> Validate for [T]
+where
+    T: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), T::validate)
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), |item| item.validate_group_id(group))
+    }
+}
+
+impl<T> Validate for std::collections::VecDeque<T>
+where
+    T: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), T::validate)
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), |item| item.validate_group_id(group))
+    }
+}
+
+impl<T, S> Validate for std::collections::HashSet<T, S>
+where
+    T: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        validate_set_items(self.iter(), T::validate)
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_set_items(self.iter(), |item| item.validate_group_id(group))
+    }
+}
+
+impl<T> Validate for std::collections::BTreeSet<T>
+where
+    T: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), T::validate)
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), |item| item.validate_group_id(group))
+    }
+}
+
+macro_rules! impl_validate_for_pointer {
+    ($($Pointer:ty),+) => {
+        $(
+            impl<T> Validate for $Pointer
+            where
+                T: Validate + ?Sized,
+            {
+                fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+                    (**self).validate()
+                }
+
+                fn validate_group_id(
+                    &self,
+                    group: std::any::TypeId,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    (**self).validate_group_id(group)
+                }
             }
-        }
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Array(
-                validation::ArrayErrors::new(vec![], items),
-            ))
-        }
+            impl<T, C> ValidateWithContext<C> for $Pointer
+            where
+                T: ValidateWithContext<C> + ?Sized,
+                C: ?Sized,
+            {
+                fn validate_with_context(
+                    &self,
+                    context: &C,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    (**self).validate_with_context(context)
+                }
+            }
+        )+
+    };
+}
+
+impl_validate_for_pointer!(&T, Box<T>, std::rc::Rc<T>, std::sync::Arc<T>);
+
+impl<T> Validate for std::borrow::Cow<'_, T>
+where
+    T: Validate + ToOwned + ?Sized,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        (**self).validate()
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        (**self).validate_group_id(group)
     }
 }
 
+macro_rules! impl_validate_for_tuple {
+    ($(($($T:ident $index:tt),+))+) => {
+        $(
+            impl<$($T),+> Validate for ($($T,)+)
+            where
+                $($T: Validate,)+
+            {
+                fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+                    collect_item_results([$(self.$index.validate()),+])
+                }
+
+                fn validate_group_id(
+                    &self,
+                    group: std::any::TypeId,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    collect_item_results([$(self.$index.validate_group_id(group)),+])
+                }
+            }
+
+            impl<$($T,)+ C> ValidateWithContext<C> for ($($T,)+)
+            where
+                $($T: ValidateWithContext<C>,)+
+                C: ?Sized,
+            {
+                fn validate_with_context(
+                    &self,
+                    context: &C,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    collect_item_results([$(self.$index.validate_with_context(context)),+])
+                }
+            }
+        )+
+    };
+}
+
+impl_validate_for_tuple! {
+    (T0 0)
+    (T0 0, T1 1)
+    (T0 0, T1 1, T2 2)
+    (T0 0, T1 1, T2 2, T3 3)
+    (T0 0, T1 1, T2 2, T3 3, T4 4)
+    (T0 0, T1 1, T2 2, T3 3, T4 4, T5 5)
+    (T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6)
+    (T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7)
+}
+
 impl<T> Validate for Option<T>
 where
     T: Validate,
@@ -513,9 +1213,690 @@ where
             None => Ok(()),
         }
     }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        match self {
+            Some(value) => value.validate_group_id(group),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<K, V, S> Validate for std::collections::HashMap<K, V, S>
+where
+    K: std::fmt::Display,
+    V: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        validate_properties(self.iter(), V::validate)
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_properties(self.iter(), |value| value.validate_group_id(group))
+    }
+}
+
+impl<K, V> Validate for std::collections::BTreeMap<K, V>
+where
+    K: std::fmt::Display,
+    V: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        validate_properties(self.iter(), V::validate)
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_properties(self.iter(), |value| value.validate_group_id(group))
+    }
+}
+
+impl<K, V, S> Validate for IndexMap<K, V, S>
+where
+    K: std::fmt::Display,
+    V: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        validate_properties(self.iter(), V::validate)
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_properties(self.iter(), |value| value.validate_group_id(group))
+    }
+}
+
+fn validate_items<'a, T>(
+    items: impl Iterator<Item = &'a T>,
+    validate: impl FnMut(&'a T) -> std::result::Result<(), self::validation::Errors>,
+) -> std::result::Result<(), self::validation::Errors>
+where
+    T: ?Sized + 'a,
+{
+    collect_item_results(items.map(validate))
+}
+
+/// Validate the items of the set, like [`validate_items`].
+///
+/// The errors of the items are reported on the set without the index,
+/// because the iteration order of `HashSet` changes between the processes.
+fn validate_set_items<'a, T>(
+    items: impl Iterator<Item = &'a T>,
+    validate: impl FnMut(&'a T) -> std::result::Result<(), self::validation::Errors>,
+) -> std::result::Result<(), self::validation::Errors>
+where
+    T: 'a,
+{
+    collect_set_item_results(items.map(validate))
+}
+
+async fn validate_items_async<'a, T, F>(
+    items: impl Iterator<Item = &'a T>,
+    validate: impl Fn(&'a T) -> F,
+) -> std::result::Result<(), self::validation::Errors>
+where
+    T: ?Sized + 'a,
+    F: std::future::Future<Output = std::result::Result<(), self::validation::Errors>>,
+{
+    let mut results = vec![];
+    for item in items {
+        results.push(validate(item).await);
+    }
+    collect_item_results(results)
+}
+
+fn collect_item_results(
+    results: impl IntoIterator<Item = std::result::Result<(), self::validation::Errors>>,
+) -> std::result::Result<(), self::validation::Errors> {
+    let item_errors = results
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, result)| result.err().map(|errors| (index, errors)))
+        .collect::<IndexMap<_, _>>();
+
+    if item_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(self::validation::Errors::Array(
+            validation::ArrayErrors::new(vec![], item_errors),
+        ))
+    }
+}
+
+fn collect_set_item_results(
+    results: impl IntoIterator<Item = std::result::Result<(), self::validation::Errors>>,
+) -> std::result::Result<(), self::validation::Errors> {
+    let errors = results
+        .into_iter()
+        .filter_map(std::result::Result::err)
+        .flat_map(self::validation::IntoVecErrors::into_vec_errors)
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(self::validation::Errors::Array(
+            validation::ArrayErrors::new(errors, IndexMap::new()),
+        ))
+    }
+}
+
+fn validate_properties<'a, K, V>(
+    properties: impl Iterator<Item = (&'a K, &'a V)>,
+    mut validate: impl FnMut(&'a V) -> std::result::Result<(), self::validation::Errors>,
+) -> std::result::Result<(), self::validation::Errors>
+where
+    K: std::fmt::Display + 'a,
+    V: 'a,
+{
+    collect_property_results(properties.map(|(key, value)| (key, validate(value))))
+}
+
+async fn validate_properties_async<'a, K, V, F>(
+    properties: impl Iterator<Item = (&'a K, &'a V)>,
+    validate: impl Fn(&'a V) -> F,
+) -> std::result::Result<(), self::validation::Errors>
+where
+    K: std::fmt::Display + 'a,
+    V: 'a,
+    F: std::future::Future<Output = std::result::Result<(), self::validation::Errors>>,
+{
+    let mut results = vec![];
+    for (key, value) in properties {
+        results.push((key, validate(value).await));
+    }
+    collect_property_results(results)
+}
+
+/// The errors of the map values are keyed by the map keys at runtime.
+fn collect_property_results<K>(
+    results: impl IntoIterator<Item = (K, std::result::Result<(), self::validation::Errors>)>,
+) -> std::result::Result<(), self::validation::Errors>
+where
+    K: std::fmt::Display,
+{
+    let property_errors = results
+        .into_iter()
+        .filter_map(|(key, result)| result.err().map(|errors| (key.to_string().into(), errors)))
+        .collect::<validation::PropertyErrorsMap>();
+
+    if property_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(self::validation::Errors::Object(
+            validation::ObjectErrors::new(vec![], property_errors),
+        ))
+    }
+}
+
+/// Validation with the runtime context, such as the configuration or the lookup table.
+///
+/// Derived by `#[validate(context = "...")]` on the struct or the enum.
+/// `#[derive(Validate)]` without the context implements this for any context by ignoring it,
+/// so the nested fields without the context still work.
+/// The types implementing [`Validate`] by hand are nested through [`WithoutContext`].
+///
+/// The types with the context do not implement [`Validate`],
+/// so the helpers like `from_json_str` and the other ones of [`Validate`] are not available.
+/// Deserialize them by serde, and then call [`ValidateWithContext::validate_with_context`].
+///
+/// ```rust
+/// use serde_valid::{Validate, ValidateWithContext};
+///
+/// struct Config {
+///     max_tags: usize,
+/// }
+///
+/// fn tags_limit(tags: &[String], config: &Config) -> Result<(), serde_valid::validation::Error> {
+///     if tags.len() <= config.max_tags {
+///         Ok(())
+///     } else {
+///         Err(serde_valid::validation::Error::Custom(
+///             "Too many tags.".to_owned(),
+///         ))
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// #[validate(context = "Config")]
+/// struct SampleStruct {
+///     #[validate(custom(tags_limit), context)]
+///     tags: Vec<String>,
+/// }
+///
+/// let s = SampleStruct {
+///     tags: vec!["a".to_owned(), "b".to_owned()],
+/// };
+///
+/// assert!(s.validate_with_context(&Config { max_tags: 2 }).is_ok());
+/// assert!(s.validate_with_context(&Config { max_tags: 1 }).is_err());
+/// ```
+///
+/// The containers such as `Vec<T>`, `Option<T>` and the maps pass the context to the items.
+pub trait ValidateWithContext<C: ?Sized> {
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors>;
+}
+
+impl<T, C> ValidateWithContext<C> for Vec<T>
+where
+    T: ValidateWithContext<C>,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), |item| item.validate_with_context(context))
+    }
+}
+
+impl<T, C, const N: usize> ValidateWithContext<C> for [T; N]
+where
+    T: ValidateWithContext<C>,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), |item| item.validate_with_context(context))
+    }
+}
+
+impl<T, C> ValidateWithContext<C> for [T]
+where
+    T: ValidateWithContext<C>,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), |item| item.validate_with_context(context))
+    }
+}
+
+impl<T, C> ValidateWithContext<C> for std::collections::VecDeque<T>
+where
+    T: ValidateWithContext<C>,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), |item| item.validate_with_context(context))
+    }
+}
+
+impl<T, S, C> ValidateWithContext<C> for std::collections::HashSet<T, S>
+where
+    T: ValidateWithContext<C>,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_set_items(self.iter(), |item| item.validate_with_context(context))
+    }
+}
+
+impl<T, C> ValidateWithContext<C> for std::collections::BTreeSet<T>
+where
+    T: ValidateWithContext<C>,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items(self.iter(), |item| item.validate_with_context(context))
+    }
+}
+
+impl<T, C> ValidateWithContext<C> for std::borrow::Cow<'_, T>
+where
+    T: ValidateWithContext<C> + ToOwned + ?Sized,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        (**self).validate_with_context(context)
+    }
+}
+
+impl<T, C> ValidateWithContext<C> for Option<T>
+where
+    T: ValidateWithContext<C>,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        match self {
+            Some(value) => value.validate_with_context(context),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<K, V, S, C> ValidateWithContext<C> for std::collections::HashMap<K, V, S>
+where
+    K: std::fmt::Display,
+    V: ValidateWithContext<C>,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_properties(self.iter(), |value| value.validate_with_context(context))
+    }
+}
+
+impl<K, V, C> ValidateWithContext<C> for std::collections::BTreeMap<K, V>
+where
+    K: std::fmt::Display,
+    V: ValidateWithContext<C>,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_properties(self.iter(), |value| value.validate_with_context(context))
+    }
+}
+
+impl<K, V, S, C> ValidateWithContext<C> for IndexMap<K, V, S>
+where
+    K: std::fmt::Display,
+    V: ValidateWithContext<C>,
+    C: ?Sized,
+{
+    fn validate_with_context(
+        &self,
+        context: &C,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_properties(self.iter(), |value| value.validate_with_context(context))
+    }
+}
+
+/// The wrapper to nest the types implementing [`Validate`] by hand in the types with the context,
+/// which validates the inner value ignoring the context.
+///
+/// It is transparent in serde and in [`JsonSchema`].
+///
+/// ```rust
+/// use serde_valid::{Validate, ValidateWithContext, WithoutContext};
+///
+/// struct Email(String);
+///
+/// impl Validate for Email {
+///     fn validate(&self) -> Result<(), serde_valid::validation::Errors> {
+///         if self.0.contains('@') {
+///             Ok(())
+///         } else {
+///             Err(serde_valid::validation::Errors::NewType(vec![
+///                 serde_valid::validation::Error::Custom("Invalid email.".to_owned()),
+///             ]))
+///         }
+///     }
+/// }
+///
+/// struct Config;
+///
+/// #[derive(Validate)]
+/// #[validate(context = "Config")]
+/// struct SampleStruct {
+///     #[validate]
+///     email: WithoutContext<Email>,
+/// }
+///
+/// let s = SampleStruct {
+///     email: WithoutContext(Email("alice".to_owned())),
+/// };
+///
+/// assert!(s.validate_with_context(&Config).is_err());
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct WithoutContext<T>(pub T);
+
+impl<T> std::ops::Deref for WithoutContext<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for WithoutContext<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for WithoutContext<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Validate for WithoutContext<T>
+where
+    T: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        self.0.validate()
+    }
+
+    fn validate_group_id(
+        &self,
+        group: std::any::TypeId,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        self.0.validate_group_id(group)
+    }
+}
+
+impl<T, C> ValidateWithContext<C> for WithoutContext<T>
+where
+    T: Validate,
+    C: ?Sized,
+{
+    fn validate_with_context(&self, _: &C) -> std::result::Result<(), self::validation::Errors> {
+        self.0.validate()
+    }
+}
+
+impl<T> JsonSchema for WithoutContext<T>
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> serde_json::Value {
+        T::json_schema()
+    }
+}
+
+/// Validation with the async checks, such as the lookup of the database.
+///
+/// Derived by `#[derive(AsyncValidate)]` with `#[validate(custom_async(...))]`
+/// and `#[rule_async(...)]`. The async checks run after the [`Validate`] checks,
+/// and their errors are merged into the same [`validation::Errors`].
+///
+/// ```rust
+/// use serde_valid::{AsyncValidate, Validate};
+///
+/// async fn username_not_taken(username: &str) -> Result<(), serde_valid::validation::Error> {
+///     if username == "taken" {
+///         Err(serde_valid::validation::Error::Custom(
+///             "The username is already taken.".to_owned(),
+///         ))
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Validate, AsyncValidate)]
+/// struct SampleStruct {
+///     #[validate(max_length = 8)]
+///     #[validate(custom_async(username_not_taken))]
+///     username: String,
+/// }
+///
+/// let s = SampleStruct {
+///     username: "taken".to_owned(),
+/// };
+///
+/// assert!(s.validate().is_ok());
+/// assert!(pollster::block_on(s.validate_async()).is_err());
+/// ```
+///
+/// The nested `#[validate]` fields run their async checks too,
+/// so their types also derive `AsyncValidate`, even without any async checks.
+pub trait AsyncValidate: Validate {
+    /// Run the async checks only, without the checks of [`Validate`].
+    fn validate_async_checks(
+        &self,
+    ) -> impl std::future::Future<Output = std::result::Result<(), self::validation::Errors>>;
+
+    fn validate_async(
+        &self,
+    ) -> impl std::future::Future<Output = std::result::Result<(), self::validation::Errors>> {
+        async move {
+            match (self.validate(), self.validate_async_checks().await) {
+                (Ok(()), Ok(())) => Ok(()),
+                (Err(errors), Ok(())) | (Ok(()), Err(errors)) => Err(errors),
+                (Err(mut errors), Err(async_errors)) => {
+                    errors.merge(async_errors);
+                    Err(errors)
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_async_validate_for_items {
+    ($([$($generics:tt)*] $Items:ty),+) => {
+        $(
+            impl<$($generics)*> AsyncValidate for $Items
+            where
+                T: AsyncValidate,
+            {
+                async fn validate_async_checks(
+                    &self,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    validate_items_async(self.iter(), |item| item.validate_async_checks()).await
+                }
+            }
+        )+
+    };
+}
+
+impl_async_validate_for_items!(
+    [T] Vec<T>,
+    [T, const N: usize] [T; N],
+    [T] [T],
+    [T] std::collections::VecDeque<T>,
+    [T] std::collections::BTreeSet<T>
+);
+
+impl<T, S> AsyncValidate for std::collections::HashSet<T, S>
+where
+    T: AsyncValidate,
+{
+    async fn validate_async_checks(&self) -> std::result::Result<(), self::validation::Errors> {
+        let mut results = vec![];
+        for item in self {
+            results.push(item.validate_async_checks().await);
+        }
+        collect_set_item_results(results)
+    }
+}
+
+macro_rules! impl_async_validate_for_pointer {
+    ($($Pointer:ty),+) => {
+        $(
+            impl<T> AsyncValidate for $Pointer
+            where
+                T: AsyncValidate + ?Sized,
+            {
+                async fn validate_async_checks(
+                    &self,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    (**self).validate_async_checks().await
+                }
+            }
+        )+
+    };
+}
+
+impl_async_validate_for_pointer!(&T, Box<T>, std::rc::Rc<T>, std::sync::Arc<T>);
+
+impl<T> AsyncValidate for std::borrow::Cow<'_, T>
+where
+    T: AsyncValidate + ToOwned + ?Sized,
+{
+    async fn validate_async_checks(&self) -> std::result::Result<(), self::validation::Errors> {
+        (**self).validate_async_checks().await
+    }
+}
+
+macro_rules! impl_async_validate_for_tuple {
+    ($(($($T:ident $index:tt),+))+) => {
+        $(
+            impl<$($T),+> AsyncValidate for ($($T,)+)
+            where
+                $($T: AsyncValidate,)+
+            {
+                async fn validate_async_checks(
+                    &self,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    collect_item_results([$(self.$index.validate_async_checks().await),+])
+                }
+            }
+        )+
+    };
+}
+
+impl_async_validate_for_tuple! {
+    (T0 0)
+    (T0 0, T1 1)
+    (T0 0, T1 1, T2 2)
+    (T0 0, T1 1, T2 2, T3 3)
+    (T0 0, T1 1, T2 2, T3 3, T4 4)
+    (T0 0, T1 1, T2 2, T3 3, T4 4, T5 5)
+    (T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6)
+    (T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7)
+}
+
+impl<T> AsyncValidate for Option<T>
+where
+    T: AsyncValidate,
+{
+    async fn validate_async_checks(&self) -> std::result::Result<(), self::validation::Errors> {
+        match self {
+            Some(value) => value.validate_async_checks().await,
+            None => Ok(()),
+        }
+    }
 }
 
-pub use serde_valid_derive::Validate;
+macro_rules! impl_async_validate_for_properties {
+    ($([$($generics:tt)*] $Properties:ty),+) => {
+        $(
+            impl<$($generics)*> AsyncValidate for $Properties
+            where
+                K: std::fmt::Display,
+                V: AsyncValidate,
+            {
+                async fn validate_async_checks(
+                    &self,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    validate_properties_async(self.iter(), |value| value.validate_async_checks())
+                        .await
+                }
+            }
+        )+
+    };
+}
+
+impl_async_validate_for_properties!(
+    [K, V, S] std::collections::HashMap<K, V, S>,
+    [K, V] std::collections::BTreeMap<K, V>,
+    [K, V, S] IndexMap<K, V, S>
+);
+
+pub use serde_valid_derive::{AsyncValidate, JsonSchema, Validate};
 
 pub mod json;
 #[cfg(feature = "toml")]
@@ -0,0 +1,7 @@
+mod error;
+mod json_schema;
+mod validator;
+
+pub use error::SchemaError;
+pub use json_schema::{insert_keyword, merge_flatten_schema, JsonSchema, DRAFT_2020_12};
+pub use validator::SchemaValidator;
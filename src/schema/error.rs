@@ -0,0 +1,33 @@
+/// The error of compiling the JSON Schema document.
+#[derive(Debug, thiserror::Error)]
+pub enum SchemaError {
+    #[error("The schema must be an object or a boolean.")]
+    InvalidSchema,
+
+    #[error("The value of `{keyword}` must be {expected}.")]
+    InvalidKeyword {
+        keyword: String,
+        expected: &'static str,
+    },
+
+    #[error("The type `{0}` is not a JSON Schema type.")]
+    UnknownType(String),
+
+    #[error("The format `{0}` is unknown. Register it by `serde_valid::format::register`.")]
+    UnknownFormat(String),
+
+    #[error("The keyword `{0}` is not supported.")]
+    UnsupportedKeyword(String),
+
+    #[error(transparent)]
+    Pattern(#[from] regex::Error),
+}
+
+impl SchemaError {
+    pub(crate) fn invalid_keyword(keyword: &str, expected: &'static str) -> Self {
+        Self::InvalidKeyword {
+            keyword: keyword.to_owned(),
+            expected,
+        }
+    }
+}
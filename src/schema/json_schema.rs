@@ -0,0 +1,360 @@
+use serde_json::{json, Map, Value};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+/// The `$schema` URI of the generated documents.
+pub const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// JSON Schema generation.
+///
+/// `#[derive(JsonSchema)]` builds the schema from the field types,
+/// the `#[validate(...)]` keywords and the serde rename attributes.
+///
+/// See <https://json-schema.org/draft/2020-12/json-schema-validation.html>
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_json::json;
+/// use serde_valid::JsonSchema;
+///
+/// #[derive(Deserialize, JsonSchema)]
+/// struct TestStruct {
+///     #[validate(maximum = 100)]
+///     #[serde(rename = "value")]
+///     val: i32,
+///     #[validate(pattern = r"^\d{4}$")]
+///     code: Option<String>,
+/// }
+///
+/// assert_eq!(
+///     TestStruct::json_schema_document(),
+///     json!({
+///         "$schema": "https://json-schema.org/draft/2020-12/schema",
+///         "type": "object",
+///         "properties": {
+///             "value": {
+///                 "type": "integer",
+///                 "maximum": 100
+///             },
+///             "code": {
+///                 "type": ["string", "null"],
+///                 "pattern": r"^\d{4}$"
+///             }
+///         },
+///         "required": ["value"]
+///     })
+/// );
+/// ```
+pub trait JsonSchema {
+    /// The schema of the type, without the `$schema` keyword.
+    fn json_schema() -> Value;
+
+    /// The schema of the type as a root document.
+    fn json_schema_document() -> Value {
+        let mut document = match Self::json_schema() {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+        document.insert("$schema".to_owned(), Value::from(DRAFT_2020_12));
+        Value::Object(document)
+    }
+}
+
+/// Insert the validation keyword to the schema.
+///
+/// Like the composited validations of `#[derive(Validate)]`,
+/// the keywords except for the array ones are applied to the items of the array.
+pub fn insert_keyword(schema: &mut Value, keyword: &str, value: Value) {
+    if !is_array_keyword(keyword) && has_type(schema, "array") {
+        if let Some(items @ Value::Object(_)) = schema.get_mut("items") {
+            return insert_keyword(items, keyword, value);
+        }
+    }
+
+    // `Option<T>` also accepts `null`, which the listed values do not include.
+    let (keyword, value) = match (keyword, value) {
+        ("enum", Value::Array(mut candidates)) if has_type(schema, "null") => {
+            candidates.push(Value::Null);
+            ("enum", Value::Array(candidates))
+        }
+        ("const", value) if !value.is_null() && has_type(schema, "null") => {
+            ("enum", Value::Array(vec![value, Value::Null]))
+        }
+        (keyword, value) => (keyword, value),
+    };
+
+    if let Value::Object(map) = schema {
+        map.insert(keyword.to_owned(), value);
+    }
+}
+
+/// Merge the object schema of `#[serde(flatten)]` field into the parent schema.
+pub fn merge_flatten_schema(schema: &mut Value, flatten: Value) {
+    let (schema, flatten) = match (schema, flatten) {
+        (Value::Object(schema), Value::Object(flatten)) => (schema, flatten),
+        _ => return,
+    };
+
+    for (keyword, value) in flatten {
+        match (keyword.as_str(), schema.get_mut(&keyword), value) {
+            ("properties", Some(Value::Object(properties)), Value::Object(value)) => {
+                properties.extend(value);
+            }
+            ("required", Some(Value::Array(required)), Value::Array(value)) => {
+                required.extend(value);
+            }
+            ("properties" | "required" | "additionalProperties", None, value) => {
+                schema.insert(keyword, value);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_array_keyword(keyword: &str) -> bool {
+    matches!(
+        keyword,
+        "maxItems" | "minItems" | "uniqueItems" | "contains" | "minContains" | "maxContains"
+    )
+}
+
+fn has_type(schema: &Value, schema_type: &str) -> bool {
+    match schema.get("type") {
+        Some(Value::String(value)) => value == schema_type,
+        Some(Value::Array(values)) => values.iter().any(|value| value == schema_type),
+        _ => false,
+    }
+}
+
+macro_rules! impl_json_schema {
+    ($schema_type:literal, $($type:ty),*) => {
+        $(
+            impl JsonSchema for $type {
+                fn json_schema() -> Value {
+                    json!({ "type": $schema_type })
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema!("null", ());
+impl_json_schema!("boolean", bool);
+impl_json_schema!(
+    "integer",
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    std::num::NonZeroI8,
+    std::num::NonZeroI16,
+    std::num::NonZeroI32,
+    std::num::NonZeroI64,
+    std::num::NonZeroI128,
+    std::num::NonZeroIsize,
+    std::num::NonZeroU8,
+    std::num::NonZeroU16,
+    std::num::NonZeroU32,
+    std::num::NonZeroU64,
+    std::num::NonZeroU128,
+    std::num::NonZeroUsize
+);
+impl_json_schema!("number", f32, f64);
+impl_json_schema!(
+    "string",
+    str,
+    String,
+    std::ffi::OsStr,
+    std::ffi::OsString,
+    std::path::Path,
+    std::path::PathBuf
+);
+impl_json_schema!("object", Map<String, Value>);
+
+impl JsonSchema for char {
+    fn json_schema() -> Value {
+        json!({ "type": "string", "minLength": 1, "maxLength": 1 })
+    }
+}
+
+impl JsonSchema for Value {
+    fn json_schema() -> Value {
+        json!({})
+    }
+}
+
+impl<T> JsonSchema for &T
+where
+    T: JsonSchema + ?Sized,
+{
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
+
+impl<T> JsonSchema for Box<T>
+where
+    T: JsonSchema + ?Sized,
+{
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
+
+impl<T> JsonSchema for Cow<'_, T>
+where
+    T: JsonSchema + ToOwned + ?Sized,
+{
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
+
+impl<T> JsonSchema for Option<T>
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        let mut schema = T::json_schema();
+        match schema.get_mut("type") {
+            Some(Value::String(schema_type)) => {
+                let schema_type = std::mem::take(schema_type);
+                schema["type"] = json!([schema_type, "null"]);
+                schema
+            }
+            Some(Value::Array(schema_types)) => {
+                if !schema_types.iter().any(|schema_type| schema_type == "null") {
+                    schema_types.push(Value::from("null"));
+                }
+                schema
+            }
+            _ => json!({ "anyOf": [schema, { "type": "null" }] }),
+        }
+    }
+}
+
+macro_rules! impl_json_schema_for_array {
+    ($($type:ty),*) => {
+        $(
+            impl<T> JsonSchema for $type
+            where
+                T: JsonSchema,
+            {
+                fn json_schema() -> Value {
+                    json!({ "type": "array", "items": T::json_schema() })
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema_for_array!([T], Vec<T>, VecDeque<T>);
+
+impl<T, const N: usize> JsonSchema for [T; N]
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({
+            "type": "array",
+            "items": T::json_schema(),
+            "minItems": N,
+            "maxItems": N
+        })
+    }
+}
+
+macro_rules! impl_json_schema_for_set {
+    ($($type:ty),*) => {
+        $(
+            impl<T> JsonSchema for $type
+            where
+                T: JsonSchema,
+            {
+                fn json_schema() -> Value {
+                    json!({
+                        "type": "array",
+                        "items": T::json_schema(),
+                        "uniqueItems": true
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema_for_set!(HashSet<T>, BTreeSet<T>);
+
+macro_rules! impl_json_schema_for_map {
+    ($($type:ty),*) => {
+        $(
+            impl<K, V> JsonSchema for $type
+            where
+                V: JsonSchema,
+            {
+                fn json_schema() -> Value {
+                    json!({ "type": "object", "additionalProperties": V::json_schema() })
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema_for_map!(HashMap<K, V>, BTreeMap<K, V>, indexmap::IndexMap<K, V>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_schema_option_type() {
+        assert_eq!(
+            Option::<i32>::json_schema(),
+            json!({ "type": ["integer", "null"] })
+        );
+        assert_eq!(
+            Option::<Value>::json_schema(),
+            json!({ "anyOf": [{}, { "type": "null" }] })
+        );
+    }
+
+    #[test]
+    fn test_json_schema_document() {
+        assert_eq!(
+            i32::json_schema_document(),
+            json!({ "$schema": DRAFT_2020_12, "type": "integer" })
+        );
+    }
+
+    #[test]
+    fn test_insert_keyword() {
+        let mut schema = i32::json_schema();
+        insert_keyword(&mut schema, "maximum", json!(10));
+
+        assert_eq!(schema, json!({ "type": "integer", "maximum": 10 }));
+    }
+
+    #[test]
+    fn test_insert_keyword_to_items() {
+        let mut schema = Vec::<Option<i32>>::json_schema();
+        insert_keyword(&mut schema, "maxItems", json!(3));
+        insert_keyword(&mut schema, "enum", json!([1, 2]));
+
+        assert_eq!(
+            schema,
+            json!({
+                "type": "array",
+                "items": { "type": ["integer", "null"], "enum": [1, 2, null] },
+                "maxItems": 3
+            })
+        );
+    }
+}
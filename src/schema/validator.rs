@@ -0,0 +1,979 @@
+use super::SchemaError;
+use crate::error::ToDefaultMessage;
+use crate::validation::{
+    ArrayErrors, Composited, Error, Errors, IntoError, Literal, ObjectErrors, ValidateContains,
+    ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateFormat, ValidateMaxItems,
+    ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems, ValidateMinLength,
+    ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
+    ValidateUniqueItems, VecErrors,
+};
+use crate::{ConstErrorParams, EnumerateErrorParams};
+use indexmap::IndexMap;
+use serde_json::{Map, Number, Value};
+use std::borrow::Cow;
+
+/// Validator of [`serde_json::Value`] compiled from the JSON Schema document.
+///
+/// The validator uses the same validation traits as `#[derive(Validate)]`,
+/// so the errors have the same format as the typed validation.
+///
+/// Supported keywords are `type`, `enum`, `const`, the numeric, string, array and object
+/// validation keywords, `format`, `properties`, `patternProperties`, `additionalProperties`,
+/// `propertyNames`, `required`, `dependentRequired`, `prefixItems`, `items`, `contains`,
+/// `minContains`, `maxContains`, `allOf`, `anyOf`, `oneOf`, `not` and `if` / `then` / `else`.
+/// The other validation keywords (like `$ref`) are rejected by [`SchemaValidator::compile`]
+/// with [`SchemaError::UnsupportedKeyword`], and the annotations (like `title`) are ignored.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::schema::SchemaValidator;
+///
+/// let validator = SchemaValidator::compile(&json!({
+///     "type": "object",
+///     "properties": {
+///         "val": { "type": "integer", "maximum": 10 }
+///     },
+///     "required": ["val"]
+/// }))
+/// .unwrap();
+///
+/// assert!(validator.validate(&json!({ "val": 5 })).is_ok());
+/// assert_eq!(
+///     validator.validate(&json!({ "val": 15 })).unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The number must be `<= 10`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SchemaValidator {
+    root: Schema,
+}
+
+impl SchemaValidator {
+    /// Compile the JSON Schema document.
+    pub fn compile(schema: &Value) -> Result<Self, SchemaError> {
+        Ok(Self {
+            root: Schema::compile(schema)?,
+        })
+    }
+
+    pub fn validate(&self, value: &Value) -> Result<(), Errors> {
+        let mut collector = Collector::default();
+        self.root.collect(value, &mut collector);
+
+        if collector.is_empty() {
+            Ok(())
+        } else {
+            Err(collector.into_errors(Some(value)))
+        }
+    }
+
+    pub fn is_valid(&self, value: &Value) -> bool {
+        self.root.is_valid(value)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Schema {
+    Bool(bool),
+    Keywords(Box<Keywords>),
+}
+
+#[derive(Debug, Clone, Default)]
+struct Keywords {
+    types: Option<Vec<SchemaType>>,
+    enumerate: Option<Vec<Value>>,
+    const_value: Option<Value>,
+    minimum: Option<Number>,
+    maximum: Option<Number>,
+    exclusive_minimum: Option<Number>,
+    exclusive_maximum: Option<Number>,
+    multiple_of: Option<Number>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<regex::Regex>,
+    format: Option<String>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    unique_items: bool,
+    prefix_items: Vec<Schema>,
+    items: Option<Schema>,
+    contains: Option<Schema>,
+    min_contains: Option<usize>,
+    max_contains: Option<usize>,
+    min_properties: Option<usize>,
+    max_properties: Option<usize>,
+    required: Vec<String>,
+    dependent_required: IndexMap<String, Vec<String>>,
+    properties: IndexMap<String, Schema>,
+    pattern_properties: Vec<(regex::Regex, Schema)>,
+    additional_properties: Option<Schema>,
+    property_names: Option<Schema>,
+    all_of: Vec<Schema>,
+    any_of: Vec<Schema>,
+    one_of: Vec<Schema>,
+    not: Option<Schema>,
+    if_schema: Option<Schema>,
+    then_schema: Option<Schema>,
+    else_schema: Option<Schema>,
+}
+
+/// The validation keywords which are not supported, to not accept the invalid values silently.
+const UNSUPPORTED_KEYWORDS: &[&str] = &[
+    "$ref",
+    "$dynamicRef",
+    "$recursiveRef",
+    "dependentSchemas",
+    "dependencies",
+    "additionalItems",
+    "unevaluatedItems",
+    "unevaluatedProperties",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SchemaType {
+    Null,
+    Boolean,
+    Integer,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+impl SchemaType {
+    fn from_name(name: &str) -> Result<Self, SchemaError> {
+        match name {
+            "null" => Ok(Self::Null),
+            "boolean" => Ok(Self::Boolean),
+            "integer" => Ok(Self::Integer),
+            "number" => Ok(Self::Number),
+            "string" => Ok(Self::String),
+            "array" => Ok(Self::Array),
+            "object" => Ok(Self::Object),
+            _ => Err(SchemaError::UnknownType(name.to_owned())),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::String => "string",
+            Self::Array => "array",
+            Self::Object => "object",
+        }
+    }
+
+    fn is_match(&self, value: &Value) -> bool {
+        match (self, value) {
+            (Self::Null, Value::Null) => true,
+            (Self::Boolean, Value::Bool(_)) => true,
+            (Self::Integer, Value::Number(number)) => {
+                number.is_i64()
+                    || number.is_u64()
+                    || number.as_f64().map(|n| n.fract() == 0.0).unwrap_or(false)
+            }
+            (Self::Number, Value::Number(_)) => true,
+            (Self::String, Value::String(_)) => true,
+            (Self::Array, Value::Array(_)) => true,
+            (Self::Object, Value::Object(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Schema {
+    fn compile(schema: &Value) -> Result<Self, SchemaError> {
+        match schema {
+            Value::Bool(value) => Ok(Self::Bool(*value)),
+            Value::Object(map) => Ok(Self::Keywords(Box::new(Keywords::compile(map)?))),
+            _ => Err(SchemaError::InvalidSchema),
+        }
+    }
+
+    fn is_valid(&self, value: &Value) -> bool {
+        let mut collector = Collector::default();
+        self.collect(value, &mut collector);
+        collector.is_empty()
+    }
+
+    fn collect(&self, value: &Value, collector: &mut Collector) {
+        match self {
+            Self::Bool(true) => {}
+            Self::Bool(false) => collector
+                .errors
+                .push(Error::Custom("The value is not allowed.".to_owned())),
+            Self::Keywords(keywords) => keywords.collect(value, collector),
+        }
+    }
+}
+
+impl Keywords {
+    fn compile(map: &Map<String, Value>) -> Result<Self, SchemaError> {
+        if let Some(keyword) = UNSUPPORTED_KEYWORDS
+            .iter()
+            .find(|keyword| map.contains_key(**keyword))
+        {
+            return Err(SchemaError::UnsupportedKeyword((*keyword).to_owned()));
+        }
+
+        let multiple_of = match get_number(map, "multipleOf")? {
+            Some(number) if number.as_f64().is_some_and(|number| number > 0.0) => Some(number),
+            Some(_) => return Err(SchemaError::invalid_keyword("multipleOf", "a number > 0")),
+            None => None,
+        };
+
+        let pattern = match map.get("pattern") {
+            Some(Value::String(pattern)) => Some(regex::Regex::new(pattern)?),
+            Some(_) => return Err(SchemaError::invalid_keyword("pattern", "a string")),
+            None => None,
+        };
+
+        let format = match map.get("format") {
+            Some(Value::String(format)) if crate::format::is_known(format) => {
+                Some(format.to_owned())
+            }
+            Some(Value::String(format)) => {
+                return Err(SchemaError::UnknownFormat(format.to_owned()))
+            }
+            Some(_) => return Err(SchemaError::invalid_keyword("format", "a string")),
+            None => None,
+        };
+
+        let types = match map.get("type") {
+            Some(Value::String(name)) => Some(vec![SchemaType::from_name(name)?]),
+            Some(Value::Array(names)) => Some(
+                names
+                    .iter()
+                    .map(|name| match name {
+                        Value::String(name) => SchemaType::from_name(name),
+                        _ => Err(SchemaError::invalid_keyword("type", "a string array")),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            Some(_) => {
+                return Err(SchemaError::invalid_keyword(
+                    "type",
+                    "a string or a string array",
+                ))
+            }
+            None => None,
+        };
+
+        let enumerate = match map.get("enum") {
+            Some(Value::Array(candidates)) => Some(candidates.clone()),
+            Some(_) => return Err(SchemaError::invalid_keyword("enum", "an array")),
+            None => None,
+        };
+
+        let unique_items = match map.get("uniqueItems") {
+            Some(Value::Bool(value)) => *value,
+            Some(_) => return Err(SchemaError::invalid_keyword("uniqueItems", "a boolean")),
+            None => false,
+        };
+
+        let required = match map.get("required") {
+            Some(Value::Array(names)) => names
+                .iter()
+                .map(|name| match name {
+                    Value::String(name) => Ok(name.to_owned()),
+                    _ => Err(SchemaError::invalid_keyword("required", "a string array")),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            Some(_) => return Err(SchemaError::invalid_keyword("required", "a string array")),
+            None => vec![],
+        };
+
+        let properties = match map.get("properties") {
+            Some(Value::Object(properties)) => properties
+                .iter()
+                .map(|(name, schema)| Ok((name.to_owned(), Schema::compile(schema)?)))
+                .collect::<Result<IndexMap<_, _>, SchemaError>>()?,
+            Some(_) => return Err(SchemaError::invalid_keyword("properties", "an object")),
+            None => IndexMap::new(),
+        };
+
+        let pattern_properties = match map.get("patternProperties") {
+            Some(Value::Object(properties)) => properties
+                .iter()
+                .map(|(pattern, schema)| {
+                    Ok((regex::Regex::new(pattern)?, Schema::compile(schema)?))
+                })
+                .collect::<Result<Vec<_>, SchemaError>>()?,
+            Some(_) => {
+                return Err(SchemaError::invalid_keyword(
+                    "patternProperties",
+                    "an object",
+                ))
+            }
+            None => vec![],
+        };
+
+        let dependent_required = match map.get("dependentRequired") {
+            Some(Value::Object(dependencies)) => dependencies
+                .iter()
+                .map(|(name, names)| match names {
+                    Value::Array(names) => Ok((
+                        name.to_owned(),
+                        names
+                            .iter()
+                            .map(|name| match name {
+                                Value::String(name) => Ok(name.to_owned()),
+                                _ => Err(()),
+                            })
+                            .collect::<Result<Vec<_>, _>>()?,
+                    )),
+                    _ => Err(()),
+                })
+                .collect::<Result<IndexMap<_, _>, ()>>()
+                .map_err(|_| {
+                    SchemaError::invalid_keyword("dependentRequired", "an object of string arrays")
+                })?,
+            Some(_) => {
+                return Err(SchemaError::invalid_keyword(
+                    "dependentRequired",
+                    "an object of string arrays",
+                ))
+            }
+            None => IndexMap::new(),
+        };
+
+        Ok(Self {
+            types,
+            enumerate,
+            const_value: map.get("const").cloned(),
+            minimum: get_number(map, "minimum")?,
+            maximum: get_number(map, "maximum")?,
+            exclusive_minimum: get_number(map, "exclusiveMinimum")?,
+            exclusive_maximum: get_number(map, "exclusiveMaximum")?,
+            multiple_of,
+            min_length: get_usize(map, "minLength")?,
+            max_length: get_usize(map, "maxLength")?,
+            pattern,
+            format,
+            min_items: get_usize(map, "minItems")?,
+            max_items: get_usize(map, "maxItems")?,
+            unique_items,
+            prefix_items: get_schemas(map, "prefixItems")?,
+            items: get_schema(map, "items")?,
+            contains: get_schema(map, "contains")?,
+            min_contains: get_usize(map, "minContains")?,
+            max_contains: get_usize(map, "maxContains")?,
+            min_properties: get_usize(map, "minProperties")?,
+            max_properties: get_usize(map, "maxProperties")?,
+            required,
+            dependent_required,
+            properties,
+            pattern_properties,
+            additional_properties: get_schema(map, "additionalProperties")?,
+            property_names: get_schema(map, "propertyNames")?,
+            all_of: get_schemas(map, "allOf")?,
+            any_of: get_schemas(map, "anyOf")?,
+            one_of: get_schemas(map, "oneOf")?,
+            not: get_schema(map, "not")?,
+            if_schema: get_schema(map, "if")?,
+            then_schema: get_schema(map, "then")?,
+            else_schema: get_schema(map, "else")?,
+        })
+    }
+
+    fn collect(&self, value: &Value, collector: &mut Collector) {
+        if let Some(types) = &self.types {
+            if !types.iter().any(|schema_type| schema_type.is_match(value)) {
+                let names = types
+                    .iter()
+                    .map(|schema_type| format!("`{}`", schema_type.name()))
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                collector.errors.push(Error::Custom(format!(
+                    "The value must be of type {}.",
+                    names
+                )));
+            }
+        }
+
+        if let Some(enumerate) = &self.enumerate {
+            if !enumerate.iter().any(|item| json_equals(item, value)) {
+                collector.errors.push(
+                    Composited::Single(EnumerateErrorParams {
+                        enumerate: enumerate.iter().map(to_literal).collect(),
+                        value: to_literal(value),
+                    })
+                    .into_error(),
+                );
+            }
+        }
+
+        if let Some(const_value) = &self.const_value {
+            if !json_equals(const_value, value) {
+                collector.errors.push(
+                    Composited::Single(ConstErrorParams {
+                        const_value: to_literal(const_value),
+                        value: to_literal(value),
+                    })
+                    .into_error(),
+                );
+            }
+        }
+
+        match value {
+            Value::Number(number) => self.collect_numeric(number, &mut collector.errors),
+            Value::String(string) => self.collect_string(string, &mut collector.errors),
+            Value::Array(array) => self.collect_array(array, collector),
+            Value::Object(object) => self.collect_object(object, collector),
+            Value::Null | Value::Bool(_) => {}
+        }
+
+        for schema in self.all_of.iter() {
+            schema.collect(value, collector);
+        }
+
+        if !self.any_of.is_empty() && !self.any_of.iter().any(|schema| schema.is_valid(value)) {
+            collector.errors.push(Error::Custom(
+                "The value must be valid against at least one of the `anyOf` schemas.".to_owned(),
+            ));
+        }
+
+        if !self.one_of.is_empty()
+            && self
+                .one_of
+                .iter()
+                .filter(|schema| schema.is_valid(value))
+                .count()
+                != 1
+        {
+            collector.errors.push(Error::Custom(
+                "The value must be valid against exactly one of the `oneOf` schemas.".to_owned(),
+            ));
+        }
+
+        if let Some(not) = &self.not {
+            if not.is_valid(value) {
+                collector.errors.push(Error::Custom(
+                    "The value must not be valid against the `not` schema.".to_owned(),
+                ));
+            }
+        }
+
+        if let Some(if_schema) = &self.if_schema {
+            let schema = if if_schema.is_valid(value) {
+                &self.then_schema
+            } else {
+                &self.else_schema
+            };
+            if let Some(schema) = schema {
+                schema.collect(value, collector);
+            }
+        }
+    }
+
+    fn collect_numeric(&self, number: &Number, errors: &mut VecErrors) {
+        if let Some(minimum) = &self.minimum {
+            push_error(
+                errors,
+                validate_number(
+                    number,
+                    minimum,
+                    ValidateMinimum::validate_minimum,
+                    ValidateMinimum::validate_minimum,
+                    ValidateMinimum::validate_minimum,
+                ),
+            );
+        }
+        if let Some(maximum) = &self.maximum {
+            push_error(
+                errors,
+                validate_number(
+                    number,
+                    maximum,
+                    ValidateMaximum::validate_maximum,
+                    ValidateMaximum::validate_maximum,
+                    ValidateMaximum::validate_maximum,
+                ),
+            );
+        }
+        if let Some(exclusive_minimum) = &self.exclusive_minimum {
+            push_error(
+                errors,
+                validate_number(
+                    number,
+                    exclusive_minimum,
+                    ValidateExclusiveMinimum::validate_exclusive_minimum,
+                    ValidateExclusiveMinimum::validate_exclusive_minimum,
+                    ValidateExclusiveMinimum::validate_exclusive_minimum,
+                ),
+            );
+        }
+        if let Some(exclusive_maximum) = &self.exclusive_maximum {
+            push_error(
+                errors,
+                validate_number(
+                    number,
+                    exclusive_maximum,
+                    ValidateExclusiveMaximum::validate_exclusive_maximum,
+                    ValidateExclusiveMaximum::validate_exclusive_maximum,
+                    ValidateExclusiveMaximum::validate_exclusive_maximum,
+                ),
+            );
+        }
+        if let Some(multiple_of) = &self.multiple_of {
+            push_error(
+                errors,
+                validate_number(
+                    number,
+                    multiple_of,
+                    ValidateMultipleOf::validate_multiple_of,
+                    ValidateMultipleOf::validate_multiple_of,
+                    ValidateMultipleOf::validate_multiple_of,
+                ),
+            );
+        }
+    }
+
+    fn collect_string(&self, string: &str, errors: &mut VecErrors) {
+        if let Some(min_length) = self.min_length {
+            push_error(errors, string.validate_min_length(min_length));
+        }
+        if let Some(max_length) = self.max_length {
+            push_error(errors, string.validate_max_length(max_length));
+        }
+        if let Some(pattern) = &self.pattern {
+            push_error(errors, string.validate_pattern(pattern));
+        }
+        if let Some(format) = &self.format {
+            push_error(errors, string.validate_format(format));
+        }
+    }
+
+    fn collect_array(&self, array: &Vec<Value>, collector: &mut Collector) {
+        if let Some(min_items) = self.min_items {
+            push_error(&mut collector.errors, array.validate_min_items(min_items));
+        }
+        if let Some(max_items) = self.max_items {
+            push_error(&mut collector.errors, array.validate_max_items(max_items));
+        }
+        if self.unique_items {
+            push_error(&mut collector.errors, array.validate_unique_items());
+        }
+        if let Some(contains) = &self.contains {
+            push_error(
+                &mut collector.errors,
+                array.validate_contains(
+                    self.min_contains.unwrap_or(1),
+                    self.max_contains,
+                    |item| contains.is_valid(item),
+                ),
+            );
+        }
+
+        for (index, item) in array.iter().enumerate() {
+            let schema = match self.prefix_items.get(index) {
+                Some(schema) => schema,
+                None => match &self.items {
+                    Some(schema) => schema,
+                    None => continue,
+                },
+            };
+            schema.collect(item, collector.item(index));
+        }
+    }
+
+    fn collect_object(&self, object: &Map<String, Value>, collector: &mut Collector) {
+        if let Some(min_properties) = self.min_properties {
+            push_error(
+                &mut collector.errors,
+                object.validate_min_properties(min_properties),
+            );
+        }
+        if let Some(max_properties) = self.max_properties {
+            push_error(
+                &mut collector.errors,
+                object.validate_max_properties(max_properties),
+            );
+        }
+
+        for name in self.required.iter() {
+            if !object.contains_key(name) {
+                collector
+                    .property(name)
+                    .errors
+                    .push(Error::Custom("The property is required.".to_owned()));
+            }
+        }
+
+        for (name, dependencies) in self.dependent_required.iter() {
+            if !object.contains_key(name) {
+                continue;
+            }
+            for dependency in dependencies.iter() {
+                if !object.contains_key(dependency) {
+                    collector
+                        .property(dependency)
+                        .errors
+                        .push(Error::Custom(format!(
+                            "The property is required when `{name}` is present."
+                        )));
+                }
+            }
+        }
+
+        for (name, property) in object.iter() {
+            if let Some(property_names) = &self.property_names {
+                property_names.collect(&Value::String(name.to_owned()), collector.property(name));
+            }
+
+            let mut is_evaluated = false;
+            if let Some(schema) = self.properties.get(name) {
+                schema.collect(property, collector.property(name));
+                is_evaluated = true;
+            }
+            for (pattern, schema) in self.pattern_properties.iter() {
+                if pattern.is_match(name) {
+                    schema.collect(property, collector.property(name));
+                    is_evaluated = true;
+                }
+            }
+            if !is_evaluated {
+                if let Some(schema) = &self.additional_properties {
+                    schema.collect(property, collector.property(name));
+                }
+            }
+        }
+    }
+}
+
+/// The errors of the value, which is converted to [`Errors`] with the shape of the value.
+#[derive(Debug, Default)]
+struct Collector {
+    errors: VecErrors,
+    properties: IndexMap<String, Collector>,
+    items: IndexMap<usize, Collector>,
+}
+
+impl Collector {
+    fn property(&mut self, name: &str) -> &mut Collector {
+        self.properties.entry(name.to_owned()).or_default()
+    }
+
+    fn item(&mut self, index: usize) -> &mut Collector {
+        self.items.entry(index).or_default()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+            && self.properties.values().all(Collector::is_empty)
+            && self.items.values().all(Collector::is_empty)
+    }
+
+    fn into_errors(self, value: Option<&Value>) -> Errors {
+        match value {
+            Some(Value::Object(object)) => Errors::Object(ObjectErrors::new(
+                self.errors,
+                self.properties
+                    .into_iter()
+                    .filter(|(_, collector)| !collector.is_empty())
+                    .map(|(name, collector)| {
+                        let errors = collector.into_errors(object.get(&name));
+                        (Cow::Owned(name), errors)
+                    })
+                    .collect(),
+            )),
+            Some(Value::Array(array)) => Errors::Array(ArrayErrors::new(
+                self.errors,
+                self.items
+                    .into_iter()
+                    .filter(|(_, collector)| !collector.is_empty())
+                    .map(|(index, collector)| (index, collector.into_errors(array.get(index))))
+                    .collect(),
+            )),
+            _ => Errors::NewType(self.errors),
+        }
+    }
+}
+
+fn push_error<Params>(errors: &mut VecErrors, result: Result<(), Params>)
+where
+    Params: ToDefaultMessage,
+    Composited<Params>: IntoError<Params>,
+{
+    if let Err(params) = result {
+        errors.push(Composited::Single(params).into_error());
+    }
+}
+
+/// Validate the number with the validation of the same number type as the limit.
+fn validate_number<Params>(
+    number: &Number,
+    limit: &Number,
+    validate_i64: fn(&i64, i64) -> Result<(), Params>,
+    validate_u64: fn(&u64, u64) -> Result<(), Params>,
+    validate_f64: fn(&f64, f64) -> Result<(), Params>,
+) -> Result<(), Params> {
+    if let (Some(number), Some(limit)) = (number.as_i64(), limit.as_i64()) {
+        return validate_i64(&number, limit);
+    }
+    if let (Some(number), Some(limit)) = (number.as_u64(), limit.as_u64()) {
+        return validate_u64(&number, limit);
+    }
+    match (number.as_f64(), limit.as_f64()) {
+        (Some(number), Some(limit)) => validate_f64(&number, limit),
+        _ => Ok(()),
+    }
+}
+
+/// The equality of JSON Schema, where the numbers are equal by the mathematical value,
+/// such as `1` and `1.0`.
+fn json_equals(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => {
+            if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+                return a == b;
+            }
+            if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+                return a == b;
+            }
+            a.as_f64() == b.as_f64()
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_equals(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| json_equals(a, b)))
+        }
+        _ => a == b,
+    }
+}
+
+fn to_literal(value: &Value) -> Literal {
+    match value {
+        Value::Null => Literal::Null,
+        Value::Bool(value) => Literal::Bool(*value),
+        Value::Number(number) => {
+            if let Some(number) = number.as_i64() {
+                Literal::from(number)
+            } else if let Some(number) = number.as_u64() {
+                Literal::from(number)
+            } else {
+                Literal::from(number.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        Value::String(value) => Literal::from(value.to_owned()),
+        _ => Literal::from(value.to_string()),
+    }
+}
+
+fn get_number(map: &Map<String, Value>, keyword: &str) -> Result<Option<Number>, SchemaError> {
+    match map.get(keyword) {
+        Some(Value::Number(number)) => Ok(Some(number.clone())),
+        Some(_) => Err(SchemaError::invalid_keyword(keyword, "a number")),
+        None => Ok(None),
+    }
+}
+
+fn get_usize(map: &Map<String, Value>, keyword: &str) -> Result<Option<usize>, SchemaError> {
+    match map.get(keyword) {
+        Some(value) => match value.as_u64() {
+            Some(value) => Ok(Some(value as usize)),
+            None => Err(SchemaError::invalid_keyword(
+                keyword,
+                "a non-negative integer",
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+fn get_schema(map: &Map<String, Value>, keyword: &str) -> Result<Option<Schema>, SchemaError> {
+    map.get(keyword).map(Schema::compile).transpose()
+}
+
+fn get_schemas(map: &Map<String, Value>, keyword: &str) -> Result<Vec<Schema>, SchemaError> {
+    match map.get(keyword) {
+        Some(Value::Array(schemas)) => schemas.iter().map(Schema::compile).collect(),
+        Some(_) => Err(SchemaError::invalid_keyword(keyword, "an array of schemas")),
+        None => Ok(vec![]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_validator_type() {
+        let validator = SchemaValidator::compile(&json!({ "type": ["integer", "null"] })).unwrap();
+
+        assert!(validator.is_valid(&json!(1)));
+        assert!(validator.is_valid(&json!(1.0)));
+        assert!(validator.is_valid(&json!(null)));
+        assert!(!validator.is_valid(&json!(1.5)));
+        assert!(!validator.is_valid(&json!("1")));
+    }
+
+    #[test]
+    fn test_schema_validator_numeric_limit_type() {
+        let validator = SchemaValidator::compile(&json!({ "maximum": 2.5 })).unwrap();
+
+        assert!(validator.is_valid(&json!(2)));
+        assert!(!validator.is_valid(&json!(3)));
+        assert!(!validator.is_valid(&json!(u64::MAX)));
+    }
+
+    #[test]
+    fn test_schema_validator_invalid_schema() {
+        assert!(SchemaValidator::compile(&json!(1)).is_err());
+        assert!(SchemaValidator::compile(&json!({ "type": "integers" })).is_err());
+        assert!(SchemaValidator::compile(&json!({ "maxLength": -1 })).is_err());
+        assert!(SchemaValidator::compile(&json!({ "pattern": "(" })).is_err());
+        assert!(SchemaValidator::compile(&json!({ "format": "unknown" })).is_err());
+    }
+
+    #[test]
+    fn test_schema_validator_multiple_of_must_be_positive() {
+        assert!(SchemaValidator::compile(&json!({ "multipleOf": 0 })).is_err());
+        assert!(SchemaValidator::compile(&json!({ "multipleOf": -1 })).is_err());
+        assert!(SchemaValidator::compile(&json!({ "multipleOf": 0.0 })).is_err());
+
+        let validator = SchemaValidator::compile(&json!({ "multipleOf": 2 })).unwrap();
+        assert!(validator.is_valid(&json!(i64::MIN)));
+        assert!(!validator.is_valid(&json!(5)));
+    }
+
+    #[test]
+    fn test_schema_validator_unsupported_keyword() {
+        assert!(matches!(
+            SchemaValidator::compile(&json!({ "$ref": "#/$defs/a" })),
+            Err(SchemaError::UnsupportedKeyword(keyword)) if keyword == "$ref"
+        ));
+        assert!(matches!(
+            SchemaValidator::compile(&json!({
+                "properties": { "a": { "unevaluatedProperties": false } }
+            })),
+            Err(SchemaError::UnsupportedKeyword(keyword)) if keyword == "unevaluatedProperties"
+        ));
+        assert!(SchemaValidator::compile(&json!({ "title": "a", "x-extension": 1 })).is_ok());
+    }
+
+    #[test]
+    fn test_schema_validator_if_then_else() {
+        let validator = SchemaValidator::compile(&json!({
+            "if": { "minimum": 10 },
+            "then": { "multipleOf": 10 },
+            "else": { "maximum": 5 }
+        }))
+        .unwrap();
+
+        assert!(validator.is_valid(&json!(20)));
+        assert!(!validator.is_valid(&json!(15)));
+        assert!(validator.is_valid(&json!(5)));
+        assert!(!validator.is_valid(&json!(7)));
+    }
+
+    #[test]
+    fn test_schema_validator_property_names() {
+        let validator = SchemaValidator::compile(&json!({
+            "propertyNames": { "pattern": "^[a-z_]+$" }
+        }))
+        .unwrap();
+
+        assert!(validator.is_valid(&json!({ "ab_c": 1 })));
+        assert!(!validator.is_valid(&json!({ "AbC": 1 })));
+    }
+
+    #[test]
+    fn test_schema_validator_pattern_properties() {
+        let validator = SchemaValidator::compile(&json!({
+            "properties": { "name": { "type": "string" } },
+            "patternProperties": { "^n_": { "type": "integer" } },
+            "additionalProperties": false
+        }))
+        .unwrap();
+
+        assert!(validator.is_valid(&json!({ "name": "a", "n_1": 1 })));
+        assert!(!validator.is_valid(&json!({ "n_1": "1" })));
+        assert!(!validator.is_valid(&json!({ "other": 1 })));
+    }
+
+    #[test]
+    fn test_schema_validator_dependent_required() {
+        let validator = SchemaValidator::compile(&json!({
+            "dependentRequired": { "credit_card": ["billing_address"] }
+        }))
+        .unwrap();
+
+        assert!(validator.is_valid(&json!({})));
+        assert!(validator.is_valid(&json!({ "credit_card": 1, "billing_address": "a" })));
+        assert!(!validator.is_valid(&json!({ "credit_card": 1 })));
+    }
+
+    #[test]
+    fn test_schema_validator_const() {
+        let validator = SchemaValidator::compile(&json!({ "const": "v1" })).unwrap();
+
+        assert!(validator.is_valid(&json!("v1")));
+        assert!(!validator.is_valid(&json!("v2")));
+        assert!(!validator.is_valid(&json!(1)));
+    }
+
+    #[test]
+    fn test_schema_validator_const_and_enum_numeric_equality() {
+        let validator = SchemaValidator::compile(&json!({ "const": 1 })).unwrap();
+
+        assert!(validator.is_valid(&json!(1)));
+        assert!(validator.is_valid(&json!(1.0)));
+        assert!(!validator.is_valid(&json!(1.5)));
+
+        let validator = SchemaValidator::compile(&json!({ "const": { "a": [1.0, -2] } })).unwrap();
+
+        assert!(validator.is_valid(&json!({ "a": [1, -2.0] })));
+        assert!(!validator.is_valid(&json!({ "a": [1, 2] })));
+
+        let validator = SchemaValidator::compile(&json!({ "enum": [1, "a"] })).unwrap();
+
+        assert!(validator.is_valid(&json!(1.0)));
+        assert!(!validator.is_valid(&json!("1")));
+    }
+
+    #[test]
+    fn test_schema_validator_contains() {
+        let validator = SchemaValidator::compile(&json!({
+            "contains": { "const": "admin" },
+            "maxContains": 1
+        }))
+        .unwrap();
+
+        assert!(validator.is_valid(&json!(["user", "admin"])));
+        assert!(!validator.is_valid(&json!(["user"])));
+        assert!(!validator.is_valid(&json!(["admin", "admin"])));
+
+        let validator = SchemaValidator::compile(&json!({
+            "contains": { "minimum": 10 },
+            "minContains": 0
+        }))
+        .unwrap();
+
+        assert!(validator.is_valid(&json!([1, 2])));
+    }
+
+    #[test]
+    fn test_schema_validator_format() {
+        let validator = SchemaValidator::compile(&json!({ "format": "date" })).unwrap();
+
+        assert!(validator.is_valid(&json!("2020-09-10")));
+        assert!(validator.is_valid(&json!(1)));
+        assert!(!validator.is_valid(&json!("2020/09/10")));
+    }
+}
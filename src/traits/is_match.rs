@@ -1,48 +1,69 @@
+use std::borrow::Cow;
+
 pub trait IsMatch {
-    fn is_match(&self, pattern: &regex::Regex) -> bool;
+    fn as_match_str(&self) -> Cow<'_, str>;
+
+    fn is_match(&self, pattern: &regex::Regex) -> bool {
+        pattern.is_match(&self.as_match_str())
+    }
 }
 
 macro_rules! impl_for_str {
     ($ty:ty) => {
         impl IsMatch for $ty {
-            fn is_match(&self, pattern: &regex::Regex) -> bool {
-                pattern.is_match(self)
+            fn as_match_str(&self) -> Cow<'_, str> {
+                Cow::Borrowed(self)
             }
         }
     };
 }
 
 impl_for_str!(str);
-impl_for_str!(&str);
 impl_for_str!(String);
 impl_for_str!(std::borrow::Cow<'_, str>);
 
 macro_rules! impl_for_os_str {
     ($ty:ty) => {
         impl IsMatch for $ty {
-            fn is_match(&self, pattern: &regex::Regex) -> bool {
-                pattern.is_match(&self.to_string_lossy())
+            fn as_match_str(&self) -> Cow<'_, str> {
+                self.to_string_lossy()
             }
         }
     };
 }
 
 impl_for_os_str!(std::ffi::OsStr);
-impl_for_os_str!(&std::ffi::OsStr);
 impl_for_os_str!(std::ffi::OsString);
 impl_for_os_str!(std::borrow::Cow<'_, std::ffi::OsStr>);
 
 macro_rules! impl_for_path {
     ($ty:ty) => {
         impl IsMatch for $ty {
-            fn is_match(&self, pattern: &regex::Regex) -> bool {
-                self.as_os_str().is_match(pattern)
+            fn as_match_str(&self) -> Cow<'_, str> {
+                self.as_os_str().to_string_lossy()
             }
         }
     };
 }
 
 impl_for_path!(std::path::Path);
-impl_for_path!(&std::path::Path);
 impl_for_path!(std::path::PathBuf);
 impl_for_path!(std::borrow::Cow<'_, std::path::Path>);
+
+impl<T> IsMatch for Box<T>
+where
+    T: IsMatch + ?Sized,
+{
+    fn as_match_str(&self) -> Cow<'_, str> {
+        (**self).as_match_str()
+    }
+}
+
+impl<T> IsMatch for &T
+where
+    T: IsMatch + ?Sized,
+{
+    fn as_match_str(&self) -> Cow<'_, str> {
+        (**self).as_match_str()
+    }
+}
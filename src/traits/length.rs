@@ -15,7 +15,6 @@ macro_rules! impl_for_str {
 }
 
 impl_for_str!(str);
-impl_for_str!(&str);
 impl_for_str!(String);
 impl_for_str!(std::borrow::Cow<'_, str>);
 
@@ -30,7 +29,6 @@ macro_rules! impl_for_os_str {
 }
 
 impl_for_os_str!(std::ffi::OsStr);
-impl_for_os_str!(&std::ffi::OsStr);
 impl_for_os_str!(std::ffi::OsString);
 impl_for_os_str!(std::borrow::Cow<'_, std::ffi::OsStr>);
 
@@ -45,6 +43,23 @@ macro_rules! impl_for_path {
 }
 
 impl_for_path!(std::path::Path);
-impl_for_path!(&std::path::Path);
 impl_for_path!(std::path::PathBuf);
 impl_for_os_str!(std::borrow::Cow<'_, std::path::Path>);
+
+impl<T> Length for Box<T>
+where
+    T: Length + ?Sized,
+{
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+impl<T> Length for &T
+where
+    T: Length + ?Sized,
+{
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
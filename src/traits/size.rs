@@ -22,3 +22,21 @@ impl Size for serde_json::Map<String, serde_json::Value> {
         self.len()
     }
 }
+
+impl<T> Size for Box<T>
+where
+    T: Size + ?Sized,
+{
+    fn size(&self) -> usize {
+        (**self).size()
+    }
+}
+
+impl<T> Size for &T
+where
+    T: Size + ?Sized,
+{
+    fn size(&self) -> usize {
+        (**self).size()
+    }
+}
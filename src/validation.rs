@@ -6,114 +6,251 @@ mod object;
 mod string;
 
 use crate::{
-    EnumerateErrorParams, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
-    MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams, MinLengthErrorParams,
-    MinPropertiesErrorParams, MinimumErrorParams, MultipleOfErrorParams, PatternErrorParams,
+    ConstErrorParams, EnumerateErrorParams, ExclusiveMaximumErrorParams,
+    ExclusiveMinimumErrorParams, FormatErrorParams, MaxLengthErrorParams, MaxPropertiesErrorParams,
+    MaximumErrorParams, MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams,
+    MultipleOfErrorParams, PatternErrorParams,
 };
-pub use array::{ValidateMaxItems, ValidateMinItems, ValidateUniqueItems};
+pub use array::{ValidateContains, ValidateMaxItems, ValidateMinItems, ValidateUniqueItems};
 pub use error::{
-    ArrayErrors, Composited, Error, Errors, IntoError, ItemErrorsMap, ItemVecErrorsMap,
-    ObjectErrors, PropertyErrorsMap, PropertyVecErrorsMap, VecErrors,
+    ArrayErrors, Composited, Error, Errors, FlatError, IntoError, IntoVecErrors, ItemErrorsMap,
+    ItemVecErrorsMap, JsonPointer, LocalizedErrors, ObjectErrors, PropertyErrorsMap,
+    PropertyVecErrorsMap, StructuredErrors, VecErrors,
 };
-pub use generic::ValidateEnumerate;
+pub use generic::{ValidateConstValue, ValidateEnumerate};
 pub use numeric::{
     ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaximum, ValidateMinimum,
     ValidateMultipleOf,
 };
 pub use object::{ValidateMaxProperties, ValidateMinProperties};
 pub use serde_valid_literal::{Literal, Number, Pattern};
-pub use string::{ValidateMaxLength, ValidateMinLength, ValidatePattern};
+pub use string::{ValidateFormat, ValidateMaxLength, ValidateMinLength, ValidatePattern};
 
-macro_rules! impl_composited_validation_1args {
+/// The composited validations of the containers, which validate the items element-wise.
+///
+/// The sequences and the tuples report the errors by the index,
+/// `HashSet<T>` reports them on the set without the index,
+/// and `Rc<T>` and `Arc<T>` delegate to the inner value.
+/// `Box<T>` and `&T` of the single values are covered by [`impl_validate_pointers`],
+/// so the composited ones are limited to the slices, like `Cow<'_, [T]>`.
+macro_rules! impl_composited_containers {
     (
-        pub trait $ValidateCompositedTrait:ident {
-            fn $validate_composited_method:ident(
+        [$($limit_generics:tt)*] $ValidateCompositedTrait:path,
+        fn $method:ident($limit:ident: $limit_type:ty) -> $ErrorParams:ty
+    ) => {
+        impl_composited_containers!(
+            @items [$($limit_generics)* U] $ValidateCompositedTrait, Vec<U>,
+            fn $method($limit: $limit_type) -> $ErrorParams
+        );
+        impl_composited_containers!(
+            @items [$($limit_generics)* U, const N: usize] $ValidateCompositedTrait, [U; N],
+            fn $method($limit: $limit_type) -> $ErrorParams
+        );
+        impl_composited_containers!(
+            @items [$($limit_generics)* U] $ValidateCompositedTrait, std::collections::VecDeque<U>,
+            fn $method($limit: $limit_type) -> $ErrorParams
+        );
+        impl_composited_containers!(
+            @unordered [$($limit_generics)* U, S] $ValidateCompositedTrait, std::collections::HashSet<U, S>,
+            fn $method($limit: $limit_type) -> $ErrorParams
+        );
+        impl_composited_containers!(
+            @items [$($limit_generics)* U] $ValidateCompositedTrait, std::collections::BTreeSet<U>,
+            fn $method($limit: $limit_type) -> $ErrorParams
+        );
+        impl_composited_containers!(
+            @items [$($limit_generics)* U] $ValidateCompositedTrait, &[U],
+            fn $method($limit: $limit_type) -> $ErrorParams
+        );
+        impl_composited_containers!(
+            @items [$($limit_generics)* U] $ValidateCompositedTrait, Box<[U]>,
+            fn $method($limit: $limit_type) -> $ErrorParams
+        );
+        impl_composited_containers!(
+            @items [$($limit_generics)* U: Clone] $ValidateCompositedTrait, std::borrow::Cow<'_, [U]>,
+            fn $method($limit: $limit_type) -> $ErrorParams
+        );
+        impl_composited_containers!(
+            @deref [$($limit_generics)* U: ?Sized] $ValidateCompositedTrait, std::rc::Rc<U>,
+            fn $method($limit: $limit_type) -> $ErrorParams
+        );
+        impl_composited_containers!(
+            @deref [$($limit_generics)* U: ?Sized] $ValidateCompositedTrait, std::sync::Arc<U>,
+            fn $method($limit: $limit_type) -> $ErrorParams
+        );
+
+        impl<$($limit_generics)* U> $ValidateCompositedTrait for Option<U>
+        where
+            U: $ValidateCompositedTrait,
+        {
+            fn $method(
                 &self,
-                $limit:ident: $limit_type:ty,
-            ) -> Result<(), Composited<$ErrorParams:ty>>;
+                $limit: $limit_type,
+            ) -> Result<(), crate::validation::Composited<$ErrorParams>> {
+                match self {
+                    Some(value) => value.$method($limit),
+                    None => Ok(()),
+                }
+            }
         }
+
+        impl_composited_containers!(
+            @tuple [$($limit_generics)*] $ValidateCompositedTrait,
+            fn $method($limit: $limit_type) -> $ErrorParams,
+            (U0 0)
+            (U0 0, U1 1)
+            (U0 0, U1 1, U2 2)
+            (U0 0, U1 1, U2 2, U3 3)
+            (U0 0, U1 1, U2 2, U3 3, U4 4)
+            (U0 0, U1 1, U2 2, U3 3, U4 4, U5 5)
+            (U0 0, U1 1, U2 2, U3 3, U4 4, U5 5, U6 6)
+            (U0 0, U1 1, U2 2, U3 3, U4 4, U5 5, U6 6, U7 7)
+        );
+    };
+    (
+        @items [$($generics:tt)*] $ValidateCompositedTrait:path, $Type:ty,
+        fn $method:ident($limit:ident: $limit_type:ty) -> $ErrorParams:ty
     ) => {
-        paste::paste! {
-            pub trait $ValidateCompositedTrait {
-                fn $validate_composited_method(
-                    &self,
-                    $limit: $limit_type,
-                ) -> Result<(), Composited<$ErrorParams>>;
-            }
+        impl<$($generics)*> $ValidateCompositedTrait for $Type
+        where
+            U: $ValidateCompositedTrait,
+        {
+            fn $method(
+                &self,
+                $limit: $limit_type,
+            ) -> Result<(), crate::validation::Composited<$ErrorParams>> {
+                let mut errors = indexmap::IndexMap::new();
+                self.iter().enumerate().for_each(|(index, item)| {
+                    item.$method($limit)
+                        .map_err(|error| errors.insert(index, error))
+                        .ok();
+                });
 
-            impl<T> $ValidateCompositedTrait for T
-            where
-                T: [<Validate $limit:camel>],
-            {
-                fn $validate_composited_method(
-                    &self,
-                    $limit: $limit_type,
-                ) -> Result<(), Composited<$ErrorParams>> {
-                    self.[<validate_ $limit>]($limit)
-                        .map_err(|error| Composited::Single(error))
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(crate::validation::Composited::Array(errors))
                 }
             }
+        }
+    };
+    (
+        @unordered [$($generics:tt)*] $ValidateCompositedTrait:path, $Type:ty,
+        fn $method:ident($limit:ident: $limit_type:ty) -> $ErrorParams:ty
+    ) => {
+        impl<$($generics)*> $ValidateCompositedTrait for $Type
+        where
+            U: $ValidateCompositedTrait,
+        {
+            fn $method(
+                &self,
+                $limit: $limit_type,
+            ) -> Result<(), crate::validation::Composited<$ErrorParams>> {
+                let errors = self
+                    .iter()
+                    .filter_map(|item| item.$method($limit).err())
+                    .collect::<Vec<_>>();
 
-            impl<T> $ValidateCompositedTrait for Vec<T>
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(crate::validation::Composited::Unordered(errors))
+                }
+            }
+        }
+    };
+    (
+        @deref [$($generics:tt)*] $ValidateCompositedTrait:path, $Type:ty,
+        fn $method:ident($limit:ident: $limit_type:ty) -> $ErrorParams:ty
+    ) => {
+        impl<$($generics)*> $ValidateCompositedTrait for $Type
+        where
+            U: $ValidateCompositedTrait,
+        {
+            fn $method(
+                &self,
+                $limit: $limit_type,
+            ) -> Result<(), crate::validation::Composited<$ErrorParams>> {
+                (**self).$method($limit)
+            }
+        }
+    };
+    (
+        @tuple [$($limit_generics:tt)*] $ValidateCompositedTrait:path,
+        fn $method:ident($limit:ident: $limit_type:ty) -> $ErrorParams:ty,
+    ) => {};
+    (
+        @tuple [$($limit_generics:tt)*] $ValidateCompositedTrait:path,
+        fn $method:ident($limit:ident: $limit_type:ty) -> $ErrorParams:ty,
+        ($($U:ident $index:tt),+)
+        $($rest:tt)*
+    ) => {
+        impl<$($limit_generics)* $($U),+> $ValidateCompositedTrait for ($($U,)+)
             where
-                T: $ValidateCompositedTrait,
+                $($U: $ValidateCompositedTrait,)+
             {
-                fn $validate_composited_method(
+                fn $method(
                     &self,
                     $limit: $limit_type,
-                ) -> Result<(), Composited<$ErrorParams>> {
-                    let mut errors = vec![];
-                    self.iter().for_each(|item| {
-                        item.$validate_composited_method($limit)
-                            .map_err(|error| errors.push(error))
+                ) -> Result<(), crate::validation::Composited<$ErrorParams>> {
+                    let mut errors = indexmap::IndexMap::new();
+                    $(
+                        self.$index
+                            .$method($limit)
+                            .map_err(|error| errors.insert($index, error))
                             .ok();
-                    });
+                    )+
 
                     if errors.is_empty() {
                         Ok(())
                     } else {
-                        Err(Composited::Array(errors))
+                        Err(crate::validation::Composited::Array(errors))
                     }
                 }
             }
 
-            impl<T, const N: usize> $ValidateCompositedTrait for [T; N]
-            where
-                T: $ValidateCompositedTrait,
-            {
+        impl_composited_containers!(
+            @tuple [$($limit_generics)*] $ValidateCompositedTrait,
+            fn $method($limit: $limit_type) -> $ErrorParams,
+            $($rest)*
+        );
+    };
+}
+
+macro_rules! impl_composited_validation_1args {
+    (
+        pub trait $ValidateCompositedTrait:ident {
+            fn $validate_composited_method:ident(
+                &self,
+                $limit:ident: $limit_type:ty,
+            ) -> Result<(), Composited<$ErrorParams:ty>>;
+        }
+    ) => {
+        paste::paste! {
+            pub trait $ValidateCompositedTrait {
                 fn $validate_composited_method(
                     &self,
                     $limit: $limit_type,
-                ) -> Result<(), Composited<$ErrorParams>> {
-                    let mut errors = vec![];
-                    self.iter().for_each(|item| {
-                        item.$validate_composited_method($limit)
-                            .map_err(|error| errors.push(error))
-                            .ok();
-                    });
-
-                    if errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(Composited::Array(errors))
-                    }
-                }
+                ) -> Result<(), Composited<$ErrorParams>>;
             }
 
-            impl<T> $ValidateCompositedTrait for Option<T>
+            impl<T> $ValidateCompositedTrait for T
             where
-                T: $ValidateCompositedTrait,
+                T: [<Validate $limit:camel>],
             {
                 fn $validate_composited_method(
                     &self,
                     $limit: $limit_type,
                 ) -> Result<(), Composited<$ErrorParams>> {
-                    match self {
-                        Some(value) => value.$validate_composited_method($limit),
-                        None => Ok(()),
-                    }
+                    self.[<validate_ $limit>]($limit)
+                        .map_err(|error| Composited::Single(error))
                 }
             }
+
+            impl_composited_containers!(
+                [] $ValidateCompositedTrait,
+                fn $validate_composited_method($limit: $limit_type) -> $ErrorParams
+            );
         }
     };
     (
@@ -131,69 +268,10 @@ macro_rules! impl_composited_validation_1args {
             ) -> Result<(), crate::validation::Composited<$ErrorParams>>;
         }
 
-        impl<T, U> $ValidateCompositedTrait<T> for Vec<U>
-        where
-            T: Copy,
-            U: $ValidateCompositedTrait<T>,
-        {
-            fn $validate_composited_method(
-                &self,
-                limit: T,
-            ) -> Result<(), crate::validation::Composited<$ErrorParams>> {
-                let mut errors = vec![];
-                self.iter().for_each(|item| {
-                    item.$validate_composited_method(limit)
-                        .map_err(|error| errors.push(error))
-                        .ok();
-                });
-
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(crate::validation::Composited::Array(errors))
-                }
-            }
-        }
-
-        impl<T, U, const N: usize> $ValidateCompositedTrait<T> for [U; N]
-        where
-            T: Copy,
-            U: $ValidateCompositedTrait<T>,
-        {
-            fn $validate_composited_method(
-                &self,
-                limit: T,
-            ) -> Result<(), crate::validation::Composited<$ErrorParams>> {
-                let mut errors = vec![];
-                self.iter().for_each(|item| {
-                    item.$validate_composited_method(limit)
-                        .map_err(|error| errors.push(error))
-                        .ok();
-                });
-
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(crate::validation::Composited::Array(errors))
-                }
-            }
-        }
-
-        impl<T, U> $ValidateCompositedTrait<T> for Option<U>
-        where
-            T: Copy,
-            U: $ValidateCompositedTrait<T>,
-        {
-            fn $validate_composited_method(
-                &self,
-                limit: T,
-            ) -> Result<(), crate::validation::Composited<$ErrorParams>> {
-                match self {
-                    Some(value) => value.$validate_composited_method(limit),
-                    None => Ok(()),
-                }
-            }
-        }
+        impl_composited_containers!(
+            [T: Copy,] $ValidateCompositedTrait<T>,
+            fn $validate_composited_method(limit: T) -> $ErrorParams
+        );
     };
 }
 
@@ -221,6 +299,39 @@ macro_rules! impl_generic_composited_validation_1args {
 
 pub(crate) use impl_generic_composited_validation_1args;
 
+/// The validations of `Box<U>` and `&U`, which delegate to the inner value.
+///
+/// Unlike `Rc<U>` and `Arc<U>`, these pointers are implemented by the single value validations,
+/// because the composited validations would conflict with the user implementations for `Box<MyType>`.
+macro_rules! impl_validate_pointers {
+    (
+        $ValidateTrait:ident<T> $(where [$($bounds:tt)*])?,
+        fn $method:ident($limit:ident: $limit_type:ty) -> $ErrorParams:ty
+    ) => {
+        impl<T, U> $ValidateTrait<T> for Box<U>
+        where
+            U: $ValidateTrait<T> + ?Sized,
+            $($($bounds)*)?
+        {
+            fn $method(&self, $limit: $limit_type) -> Result<(), $ErrorParams> {
+                (**self).$method($limit)
+            }
+        }
+
+        impl<T, U> $ValidateTrait<T> for &U
+        where
+            U: $ValidateTrait<T> + ?Sized,
+            $($($bounds)*)?
+        {
+            fn $method(&self, $limit: $limit_type) -> Result<(), $ErrorParams> {
+                (**self).$method($limit)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_validate_pointers;
+
 // Number
 impl_composited_validation_1args!(
     pub trait ValidateCompositedMaximum<T> {
@@ -295,6 +406,15 @@ impl_composited_validation_1args!(
     }
 );
 
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedFormat {
+        fn validate_composited_format(
+            &self,
+            format: &str,
+        ) -> Result<(), Composited<FormatErrorParams>>;
+    }
+);
+
 // Object
 impl_composited_validation_1args!(
     pub trait ValidateCompositedMaxProperties {
@@ -315,6 +435,15 @@ impl_composited_validation_1args!(
 );
 
 // Generic
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedConstValue<T> {
+        fn validate_composited_const_value(
+            &self,
+            const_value: T,
+        ) -> Result<(), Composited<ConstErrorParams>>;
+    }
+);
+
 impl_composited_validation_1args!(
     pub trait ValidateCompositedEnumerate<T> {
         fn validate_composited_enumerate(
@@ -1,7 +1,9 @@
+mod contains;
 mod max_items;
 mod min_items;
 mod unique_items;
 
+pub use contains::ValidateContains;
 pub use max_items::ValidateMaxItems;
 pub use min_items::ValidateMinItems;
 pub use unique_items::ValidateUniqueItems;
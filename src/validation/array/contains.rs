@@ -0,0 +1,187 @@
+use crate::ContainsErrorParams;
+
+/// Contains validation of the array items.
+///
+/// The count of the items which match the predicate must be `>= min_contains`,
+/// and `<= max_contains` if it is given.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/array.html#contains>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateContains};
+///
+/// struct MyType(Vec<String>);
+///
+/// impl ValidateContains for MyType {
+///     type Item = String;
+///
+///     fn validate_contains<F>(
+///         &self,
+///         min_contains: usize,
+///         max_contains: Option<usize>,
+///         predicate: F,
+///     ) -> Result<(), serde_valid::ContainsErrorParams>
+///     where
+///         F: FnMut(&Self::Item) -> bool,
+///     {
+///         self.0.validate_contains(min_contains, max_contains, predicate)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(contains(enumerate("admin")))]
+///     roles: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     roles: MyType(vec!["user".to_owned()]),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "roles": {
+///                 "errors": ["The items must contain `>= 1` matching items."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateContains {
+    type Item: ?Sized;
+
+    fn validate_contains<F>(
+        &self,
+        min_contains: usize,
+        max_contains: Option<usize>,
+        predicate: F,
+    ) -> Result<(), ContainsErrorParams>
+    where
+        F: FnMut(&Self::Item) -> bool;
+}
+
+fn validate_contains_items<'a, T, F>(
+    items: impl IntoIterator<Item = &'a T>,
+    min_contains: usize,
+    max_contains: Option<usize>,
+    mut predicate: F,
+) -> Result<(), ContainsErrorParams>
+where
+    T: 'a + ?Sized,
+    F: FnMut(&T) -> bool,
+{
+    let matches = items.into_iter().filter(|item| predicate(item)).count();
+    if matches >= min_contains && max_contains.is_none_or(|max| matches <= max) {
+        Ok(())
+    } else {
+        Err(ContainsErrorParams::new(
+            min_contains,
+            max_contains,
+            matches,
+        ))
+    }
+}
+
+macro_rules! impl_validate_contains {
+    ([$($generics:tt)*] $Type:ty) => {
+        impl<$($generics)*> ValidateContains for $Type {
+            type Item = T;
+
+            fn validate_contains<F>(
+                &self,
+                min_contains: usize,
+                max_contains: Option<usize>,
+                predicate: F,
+            ) -> Result<(), ContainsErrorParams>
+            where
+                F: FnMut(&Self::Item) -> bool,
+            {
+                validate_contains_items(self, min_contains, max_contains, predicate)
+            }
+        }
+    };
+}
+
+impl_validate_contains!([T] Vec<T>);
+impl_validate_contains!([T, const N: usize] [T; N]);
+impl_validate_contains!([T][T]);
+impl_validate_contains!([T] std::collections::VecDeque<T>);
+impl_validate_contains!([T, S] std::collections::HashSet<T, S>);
+impl_validate_contains!([T] std::collections::BTreeSet<T>);
+
+impl<T> ValidateContains for Option<T>
+where
+    T: ValidateContains,
+{
+    type Item = T::Item;
+
+    fn validate_contains<F>(
+        &self,
+        min_contains: usize,
+        max_contains: Option<usize>,
+        predicate: F,
+    ) -> Result<(), ContainsErrorParams>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        match self {
+            Some(value) => value.validate_contains(min_contains, max_contains, predicate),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_contains_vec_type() {
+        assert!(
+            ValidateContains::validate_contains(&vec![1, 10, 20], 1, None, |item| *item >= 10)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_array_contains_array_type() {
+        assert!(
+            ValidateContains::validate_contains(&[1, 2, 3], 1, None, |item| *item > 3).is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_array_contains_min_contains() {
+        let error =
+            ValidateContains::validate_contains(&vec![1, 10, 20], 3, None, |item| *item >= 10)
+                .unwrap_err();
+        assert_eq!(error.min_contains, 3);
+        assert_eq!(error.max_contains, None);
+        assert_eq!(error.matches, 2);
+    }
+
+    #[test]
+    fn test_validate_array_contains_max_contains() {
+        assert!(
+            ValidateContains::validate_contains(&vec![1, 10, 20], 0, Some(1), |item| *item >= 10)
+                .is_err()
+        );
+        assert!(
+            ValidateContains::validate_contains(&vec![1, 2, 3], 0, Some(1), |item| *item >= 10)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_array_contains_option_type() {
+        assert!(
+            ValidateContains::validate_contains(&None::<Vec<i32>>, 1, None, |item| *item >= 10)
+                .is_ok()
+        );
+    }
+}
@@ -49,7 +49,7 @@ impl<T> ValidateMaxItems for Vec<T> {
         if max_items >= self.len() {
             Ok(())
         } else {
-            Err(crate::MaxItemsErrorParams::new(max_items))
+            Err(crate::MaxItemsErrorParams::new(max_items, self.len()))
         }
     }
 }
@@ -59,7 +59,7 @@ impl<T, const N: usize> ValidateMaxItems for [T; N] {
         if max_items >= self.len() {
             Ok(())
         } else {
-            Err(crate::MaxItemsErrorParams::new(max_items))
+            Err(crate::MaxItemsErrorParams::new(max_items, self.len()))
         }
     }
 }
@@ -49,7 +49,7 @@ impl<T> ValidateMinItems for Vec<T> {
         if min_items <= self.len() {
             Ok(())
         } else {
-            Err(crate::MinItemsErrorParams::new(min_items))
+            Err(crate::MinItemsErrorParams::new(min_items, self.len()))
         }
     }
 }
@@ -59,7 +59,7 @@ impl<T, const N: usize> ValidateMinItems for [T; N] {
         if min_items <= self.len() {
             Ok(())
         } else {
-            Err(crate::MinItemsErrorParams::new(min_items))
+            Err(crate::MinItemsErrorParams::new(min_items, self.len()))
         }
     }
 }
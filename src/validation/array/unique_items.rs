@@ -1,4 +1,5 @@
 use crate::traits::IsUnique;
+use itertools::Itertools;
 
 /// Uniqueness validation of the array items.
 ///
@@ -45,26 +46,26 @@ pub trait ValidateUniqueItems {
 
 impl<T> ValidateUniqueItems for Vec<T>
 where
-    T: std::cmp::Eq + std::hash::Hash + std::fmt::Debug,
+    T: std::cmp::Eq + std::hash::Hash + serde::Serialize,
 {
     fn validate_unique_items(&self) -> Result<(), crate::UniqueItemsErrorParams> {
         if self.is_unique() {
             Ok(())
         } else {
-            Err(crate::UniqueItemsErrorParams {})
+            Err(crate::UniqueItemsErrorParams::new(self.iter().duplicates()))
         }
     }
 }
 
 impl<T, const N: usize> ValidateUniqueItems for [T; N]
 where
-    T: std::cmp::Eq + std::hash::Hash + std::fmt::Debug,
+    T: std::cmp::Eq + std::hash::Hash + serde::Serialize,
 {
     fn validate_unique_items(&self) -> Result<(), crate::UniqueItemsErrorParams> {
         if self.is_unique() {
             Ok(())
         } else {
-            Err(crate::UniqueItemsErrorParams {})
+            Err(crate::UniqueItemsErrorParams::new(self.iter().duplicates()))
         }
     }
 }
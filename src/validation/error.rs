@@ -1,25 +1,36 @@
 mod array_erros;
 mod composited;
 mod errors;
+mod flatten;
+mod into_vec_errors;
+mod localized;
 mod object_errors;
+mod rendered;
+mod structured;
 
 use crate::error::{
-    EnumerateErrorParams, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
-    MaxItemsErrorParams, MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams,
-    Message, MinItemsErrorParams, MinLengthErrorParams, MinPropertiesErrorParams,
-    MinimumErrorParams, MultipleOfErrorParams, PatternErrorParams, UniqueItemsErrorParams,
+    ConstErrorParams, ContainsErrorParams, EnumerateErrorParams, ExclusiveMaximumErrorParams,
+    ExclusiveMinimumErrorParams, FormatErrorParams, MaxItemsErrorParams, MaxLengthErrorParams,
+    MaxPropertiesErrorParams, MaximumErrorParams, Message, MinItemsErrorParams,
+    MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams, MultipleOfErrorParams,
+    PatternErrorParams, UniqueItemsErrorParams,
 };
 pub use array_erros::ArrayErrors;
 pub use composited::{Composited, IntoError};
 pub use errors::Errors;
+pub use flatten::{FlatError, JsonPointer};
 use indexmap::IndexMap;
+pub use into_vec_errors::IntoVecErrors;
+pub use localized::LocalizedErrors;
 pub use object_errors::ObjectErrors;
+use std::borrow::Cow;
+pub use structured::StructuredErrors;
 
 pub type VecErrors = Vec<Error>;
 pub type ItemErrorsMap = IndexMap<usize, Errors>;
 pub type ItemVecErrorsMap = IndexMap<usize, VecErrors>;
-pub type PropertyErrorsMap = IndexMap<&'static str, Errors>;
-pub type PropertyVecErrorsMap = IndexMap<&'static str, VecErrors>;
+pub type PropertyErrorsMap = IndexMap<Cow<'static, str>, Errors>;
+pub type PropertyVecErrorsMap = IndexMap<Cow<'static, str>, VecErrors>;
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 #[serde(untagged)]
@@ -56,6 +67,10 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Pattern(Message<PatternErrorParams>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Format(Message<FormatErrorParams>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinItems(Message<MinItemsErrorParams>),
@@ -68,6 +83,10 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     UniqueItems(Message<UniqueItemsErrorParams>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Contains(Message<ContainsErrorParams>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinProperties(Message<MinPropertiesErrorParams>),
@@ -80,6 +99,10 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Enumerate(Message<EnumerateErrorParams>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Const(Message<ConstErrorParams>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     Custom(String),
@@ -91,6 +114,35 @@ pub enum Error {
     Properties(ObjectErrors),
 }
 
+impl Error {
+    /// The stable code of the error, like `"maximum"`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Minimum(_) => "minimum",
+            Self::Maximum(_) => "maximum",
+            Self::ExclusiveMinimum(_) => "exclusive_minimum",
+            Self::ExclusiveMaximum(_) => "exclusive_maximum",
+            Self::MultipleOf(_) => "multiple_of",
+            Self::MinLength(_) => "min_length",
+            Self::MaxLength(_) => "max_length",
+            Self::Pattern(_) => "pattern",
+            Self::Format(_) => "format",
+            Self::MinItems(_) => "min_items",
+            Self::MaxItems(_) => "max_items",
+            Self::UniqueItems(_) => "unique_items",
+            Self::Contains(message) if message.params().exceeds_max_contains() => "max_contains",
+            Self::Contains(_) => "contains",
+            Self::MinProperties(_) => "min_properties",
+            Self::MaxProperties(_) => "max_properties",
+            Self::Enumerate(_) => "enumerate",
+            Self::Const(_) => "const_value",
+            Self::Custom(_) => "custom",
+            Self::Items(_) => "items",
+            Self::Properties(_) => "properties",
+        }
+    }
+}
+
 fn serialize_error_message<T, S>(message: &T, serializer: S) -> Result<S::Ok, S::Error>
 where
     T: std::fmt::Display,
@@ -2,48 +2,74 @@ use crate::error::ToDefaultMessage;
 
 use super::Error;
 use crate::error::{
-    EnumerateErrorParams, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
-    MaxItemsErrorParams, MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams,
-    MinItemsErrorParams, MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams,
-    MultipleOfErrorParams, PatternErrorParams, UniqueItemsErrorParams,
+    ConstErrorParams, ContainsErrorParams, EnumerateErrorParams, ExclusiveMaximumErrorParams,
+    ExclusiveMinimumErrorParams, FormatErrorParams, MaxItemsErrorParams, MaxLengthErrorParams,
+    MaxPropertiesErrorParams, MaximumErrorParams, MinItemsErrorParams, MinLengthErrorParams,
+    MinPropertiesErrorParams, MinimumErrorParams, MultipleOfErrorParams, PatternErrorParams,
+    UniqueItemsErrorParams,
 };
 use indexmap::IndexMap;
 
 #[derive(Debug)]
 pub enum Composited<ErrorParams> {
     Single(ErrorParams),
-    Array(Vec<Composited<ErrorParams>>),
+    Array(IndexMap<usize, Composited<ErrorParams>>),
+    /// The errors of the unordered items like `HashSet<T>`, which have no stable index.
+    Unordered(Vec<Composited<ErrorParams>>),
 }
 
 pub trait IntoError<Params>: Sized
 where
     Params: ToDefaultMessage,
 {
-    fn into_error(self) -> crate::validation::Error {
-        self.into_error_by(Params::to_default_message)
-    }
+    fn into_error(self) -> crate::validation::Error;
 
     fn into_error_by(self, format_fn: fn(&Params) -> String) -> crate::validation::Error;
 }
 
+impl<Params> Composited<Params> {
+    fn into_error_with(self, to_error: &dyn Fn(Params) -> Error) -> Error {
+        match self {
+            Composited::Single(single) => to_error(single),
+            Composited::Array(array) => Error::Items(crate::validation::ArrayErrors::new(
+                Vec::with_capacity(0),
+                array
+                    .into_iter()
+                    .map(|(index, params)| {
+                        (
+                            index,
+                            crate::validation::Errors::NewType(vec![
+                                params.into_error_with(to_error)
+                            ]),
+                        )
+                    })
+                    .collect::<IndexMap<_, _>>(),
+            )),
+            Composited::Unordered(items) => Error::Items(crate::validation::ArrayErrors::new(
+                items
+                    .into_iter()
+                    .map(|params| params.into_error_with(to_error))
+                    .collect(),
+                IndexMap::new(),
+            )),
+        }
+    }
+}
+
 macro_rules! impl_into_error {
     ($ErrorType:ident) => {
         paste::paste! {
             impl IntoError<[<$ErrorType ErrorParams>]> for Composited<[<$ErrorType ErrorParams>]> {
+                fn into_error(self) -> Error {
+                    self.into_error_with(&|params| {
+                        Error::$ErrorType(crate::error::Message::new_default(params))
+                    })
+                }
+
                 fn into_error_by(self, format_fn: fn(&[<$ErrorType ErrorParams>]) -> String) -> Error {
-                    match self {
-                        Composited::Single(single) => Error::$ErrorType(crate::error::Message::new(single, format_fn)),
-                        Composited::Array(array) => Error::Items(crate::validation::ArrayErrors::new(
-                            Vec::with_capacity(0),
-                            array
-                                .into_iter()
-                                .enumerate()
-                                .map(|(index, params)| {
-                                    (index, crate::validation::Errors::NewType(vec![params.into_error_by(format_fn)]))
-                                })
-                                .collect::<IndexMap<_, _>>(),
-                        )),
-                    }
+                    self.into_error_with(&|params| {
+                        Error::$ErrorType(crate::error::Message::new(params, format_fn))
+                    })
                 }
             }
         }
@@ -52,6 +78,7 @@ macro_rules! impl_into_error {
 
 // Global
 impl_into_error!(Enumerate);
+impl_into_error!(Const);
 
 // Numeric
 impl_into_error!(Maximum);
@@ -64,11 +91,13 @@ impl_into_error!(MultipleOf);
 impl_into_error!(MaxLength);
 impl_into_error!(MinLength);
 impl_into_error!(Pattern);
+impl_into_error!(Format);
 
 // Array
 impl_into_error!(MaxItems);
 impl_into_error!(MinItems);
 impl_into_error!(UniqueItems);
+impl_into_error!(Contains);
 
 // Object
 impl_into_error!(MaxProperties);
@@ -1,4 +1,9 @@
-use super::{ArrayErrors, ObjectErrors, VecErrors};
+use super::flatten::flatten_errors;
+use super::{
+    ArrayErrors, Error, FlatError, JsonPointer, LocalizedErrors, ObjectErrors, StructuredErrors,
+    VecErrors,
+};
+use crate::error::MessageCatalog;
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 #[serde(untagged)]
@@ -10,44 +15,91 @@ pub enum Errors {
 }
 
 impl Errors {
+    /// Flatten the errors tree into the list of errors with the JSON Pointer of the value.
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Validate)]
+    /// struct Item {
+    ///     #[validate(min_length = 1)]
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(Validate)]
+    /// struct SampleStruct {
+    ///     #[validate]
+    ///     items: Vec<Item>,
+    /// }
+    ///
+    /// let s = SampleStruct {
+    ///     items: vec![Item { name: "a".to_owned() }, Item { name: "".to_owned() }],
+    /// };
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(s.validate().unwrap_err().flatten()).unwrap(),
+    ///     json!([
+    ///         {
+    ///             "path": "/items/1/name",
+    ///             "message": "The length of the value must be `>= 1`."
+    ///         }
+    ///     ])
+    /// );
+    /// ```
+    pub fn flatten(&self) -> Vec<FlatError> {
+        let mut flat_errors = vec![];
+        flatten_errors(self, &JsonPointer::root(), &mut flat_errors);
+        flat_errors
+    }
+
+    /// Serialize the errors with the error code and params. See [`StructuredErrors`].
+    pub fn structured(&self) -> StructuredErrors<'_> {
+        StructuredErrors::new(self)
+    }
+
+    /// Render the default messages by the catalog. See [`MessageCatalog`].
+    pub fn localize<'a>(&'a self, catalog: &'a dyn MessageCatalog) -> LocalizedErrors<'a> {
+        LocalizedErrors::new(self, catalog)
+    }
+
+    /// Merge the other errors into the errors.
+    /// The [`Errors::Object`] merged into the [`Errors::Array`] (and vice versa) is kept
+    /// in the `errors` as [`Error::Properties`] (or [`Error::Items`]) at the same path.
     pub fn merge(&mut self, other: Errors) {
-        match self {
-            Errors::Array(a) => match other {
-                Errors::Array(b) => {
-                    a.errors.extend(b.errors);
-
-                    for (index, item) in b.items {
-                        match a.items.get_mut(&index) {
-                            Some(errors) => errors.merge(item),
-                            None => {
-                                a.items.insert(index, item);
-                            }
-                        };
-                    }
-                }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
-                }
-                Errors::NewType(errors) => {
-                    a.errors.extend(errors.into_iter());
-                }
-            },
-            Errors::NewType(a) => match other {
-                Errors::Array(b) => {
-                    a.extend(b.errors);
-                    *self = Errors::Array(ArrayErrors::new(a.to_vec(), b.items));
-                }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
-                }
-                Errors::NewType(b) => {
-                    a.extend(b);
-                }
-            },
-            Errors::Object(_) => {
-                unimplemented!("Object not support yet.")
+        let errors = std::mem::replace(self, Errors::NewType(vec![]));
+        *self = match (errors, other) {
+            (Errors::Array(a), Errors::Array(b)) => Errors::Array(a.merge(b)),
+            (Errors::Array(mut a), Errors::Object(b)) => {
+                a.errors.push(Error::Properties(b));
+                Errors::Array(a)
             }
-        }
+            (Errors::Array(mut a), Errors::NewType(b)) => {
+                a.errors.extend(b);
+                Errors::Array(a)
+            }
+            (Errors::NewType(mut a), Errors::Array(b)) => {
+                a.extend(b.errors);
+                Errors::Array(ArrayErrors::new(a, b.items))
+            }
+            (Errors::NewType(mut a), Errors::Object(b)) => {
+                a.extend(b.errors);
+                Errors::Object(ObjectErrors::new(a, b.properties))
+            }
+            (Errors::NewType(mut a), Errors::NewType(b)) => {
+                a.extend(b);
+                Errors::NewType(a)
+            }
+            (Errors::Object(a), Errors::Object(b)) => Errors::Object(a.merge(b)),
+            (Errors::Object(mut a), Errors::Array(b)) => {
+                a.errors.push(Error::Items(b));
+                Errors::Object(a)
+            }
+            (Errors::Object(mut a), Errors::NewType(b)) => {
+                a.errors.extend(b);
+                Errors::Object(a)
+            }
+        };
     }
 }
 
@@ -74,3 +126,96 @@ impl std::fmt::Display for Errors {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::indexmap;
+    use serde_json::json;
+
+    #[test]
+    fn test_errors_merge_object() {
+        let mut errors = Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("a".to_owned())],
+            indexmap! {
+                "val1".into() => Errors::NewType(vec![Error::Custom("b".to_owned())]),
+            },
+        ));
+
+        errors.merge(Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("c".to_owned())],
+            indexmap! {
+                "val1".into() => Errors::NewType(vec![Error::Custom("d".to_owned())]),
+                "val2".into() => Errors::NewType(vec![Error::Custom("e".to_owned())]),
+            },
+        )));
+
+        assert_eq!(
+            serde_json::to_value(errors).unwrap(),
+            json!({
+                "errors": ["a", "c"],
+                "properties": {
+                    "val1": { "errors": ["b", "d"] },
+                    "val2": { "errors": ["e"] }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors_merge_new_type_and_object() {
+        let mut errors = Errors::NewType(vec![Error::Custom("a".to_owned())]);
+
+        errors.merge(Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("b".to_owned())],
+            indexmap! {
+                "val".into() => Errors::NewType(vec![Error::Custom("c".to_owned())]),
+            },
+        )));
+
+        assert_eq!(
+            serde_json::to_value(errors).unwrap(),
+            json!({
+                "errors": ["a", "b"],
+                "properties": {
+                    "val": { "errors": ["c"] }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors_merge_array_and_object() {
+        let mut errors = Errors::Array(ArrayErrors::new(
+            vec![Error::Custom("a".to_owned())],
+            indexmap! {
+                0 => Errors::NewType(vec![Error::Custom("b".to_owned())]),
+            },
+        ));
+
+        errors.merge(Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("c".to_owned())],
+            indexmap! {
+                "val".into() => Errors::NewType(vec![Error::Custom("d".to_owned())]),
+            },
+        )));
+
+        assert_eq!(
+            serde_json::to_value(errors).unwrap(),
+            json!({
+                "errors": [
+                    "a",
+                    {
+                        "errors": ["c"],
+                        "properties": {
+                            "val": { "errors": ["d"] }
+                        }
+                    }
+                ],
+                "items": {
+                    "0": { "errors": ["b"] }
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use super::{ArrayErrors, Error, Errors, ObjectErrors, VecErrors};
+
+/// JSON Pointer to the invalid value.
+///
+/// See <https://www.rfc-editor.org/rfc/rfc6901>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(transparent)]
+pub struct JsonPointer(String);
+
+impl JsonPointer {
+    /// The pointer to the whole document.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// The pointer to the child of the value.
+    pub fn join(&self, token: impl std::fmt::Display) -> Self {
+        let token = token.to_string().replace('~', "~0").replace('/', "~1");
+        Self(format!("{}/{}", self.0, token))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for JsonPointer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The error entry of [`Errors::flatten`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FlatError {
+    pub path: JsonPointer,
+    #[serde(rename = "message")]
+    pub error: Error,
+}
+
+impl FlatError {
+    pub fn new(path: JsonPointer, error: Error) -> Self {
+        Self { path, error }
+    }
+}
+
+impl std::fmt::Display for FlatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.error)
+    }
+}
+
+pub(super) fn flatten_errors(
+    errors: &Errors,
+    path: &JsonPointer,
+    flat_errors: &mut Vec<FlatError>,
+) {
+    match errors {
+        Errors::Object(object_errors) => flatten_object_errors(object_errors, path, flat_errors),
+        Errors::Array(array_errors) => flatten_array_errors(array_errors, path, flat_errors),
+        Errors::NewType(vec_errors) => flatten_vec_errors(vec_errors, path, flat_errors),
+    }
+}
+
+fn flatten_object_errors(
+    object_errors: &ObjectErrors,
+    path: &JsonPointer,
+    flat_errors: &mut Vec<FlatError>,
+) {
+    flatten_vec_errors(&object_errors.errors, path, flat_errors);
+    for (property, errors) in object_errors.properties.iter() {
+        flatten_errors(errors, &path.join(property), flat_errors);
+    }
+}
+
+fn flatten_array_errors(
+    array_errors: &ArrayErrors,
+    path: &JsonPointer,
+    flat_errors: &mut Vec<FlatError>,
+) {
+    flatten_vec_errors(&array_errors.errors, path, flat_errors);
+    for (index, errors) in array_errors.items.iter() {
+        flatten_errors(errors, &path.join(index), flat_errors);
+    }
+}
+
+fn flatten_vec_errors(
+    vec_errors: &VecErrors,
+    path: &JsonPointer,
+    flat_errors: &mut Vec<FlatError>,
+) {
+    for error in vec_errors {
+        match error {
+            Error::Properties(object_errors) => {
+                flatten_object_errors(object_errors, path, flat_errors)
+            }
+            Error::Items(array_errors) => flatten_array_errors(array_errors, path, flat_errors),
+            _ => flat_errors.push(FlatError::new(path.clone(), error.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_pointer_join() {
+        assert_eq!(JsonPointer::root().as_str(), "");
+        assert_eq!(
+            JsonPointer::root().join("items").join(3).as_str(),
+            "/items/3"
+        );
+        assert_eq!(JsonPointer::root().join("a/b~c").as_str(), "/a~1b~0c");
+    }
+}
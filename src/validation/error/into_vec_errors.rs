@@ -0,0 +1,68 @@
+use super::{Error, Errors, VecErrors};
+
+/// The error types which `custom` validations and `#[rule]` can return.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::Validate;
+///
+/// fn all_positive(vals: &[i32]) -> Result<(), Vec<serde_valid::validation::Error>> {
+///     let errors = vals
+///         .iter()
+///         .filter(|val| **val <= 0)
+///         .map(|val| serde_valid::validation::Error::Custom(format!("`{val}` is not positive.")))
+///         .collect::<Vec<_>>();
+///
+///     if errors.is_empty() {
+///         Ok(())
+///     } else {
+///         Err(errors)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct SampleStruct {
+///     #[validate(custom(all_positive))]
+///     vals: Vec<i32>,
+/// }
+///
+/// let s = SampleStruct { vals: vec![-1, 1, 0] };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "vals": {
+///                 "errors": ["`-1` is not positive.", "`0` is not positive."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait IntoVecErrors {
+    fn into_vec_errors(self) -> VecErrors;
+}
+
+impl IntoVecErrors for Error {
+    fn into_vec_errors(self) -> VecErrors {
+        vec![self]
+    }
+}
+
+impl IntoVecErrors for VecErrors {
+    fn into_vec_errors(self) -> VecErrors {
+        self
+    }
+}
+
+impl IntoVecErrors for Errors {
+    fn into_vec_errors(self) -> VecErrors {
+        match self {
+            Errors::Array(array_errors) => vec![Error::Items(array_errors)],
+            Errors::Object(object_errors) => vec![Error::Properties(object_errors)],
+            Errors::NewType(vec_errors) => vec_errors,
+        }
+    }
+}
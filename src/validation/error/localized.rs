@@ -0,0 +1,87 @@
+use super::rendered::{RenderMessage, RenderedErrors};
+use super::Errors;
+use crate::error::{render_template, Message, MessageCatalog, ToDefaultMessage};
+
+/// The errors whose default messages are rendered by the [`MessageCatalog`].
+///
+/// See [`MessageCatalog`] for the example.
+#[derive(Clone, Copy)]
+pub struct LocalizedErrors<'a> {
+    errors: &'a Errors,
+    catalog: &'a dyn MessageCatalog,
+}
+
+impl<'a> LocalizedErrors<'a> {
+    pub fn new(errors: &'a Errors, catalog: &'a dyn MessageCatalog) -> Self {
+        Self { errors, catalog }
+    }
+}
+
+impl std::fmt::Debug for LocalizedErrors<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalizedErrors")
+            .field("errors", self.errors)
+            .finish_non_exhaustive()
+    }
+}
+
+impl serde::Serialize for LocalizedErrors<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        RenderedErrors(self.errors, LocalizedMessage(self.catalog)).serialize(serializer)
+    }
+}
+
+impl std::fmt::Display for LocalizedErrors<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match serde_json::to_string(&self) {
+            Ok(json_string) => {
+                write!(f, "{}", json_string)
+            }
+            Err(_) => Err(std::fmt::Error),
+        }
+    }
+}
+
+/// The default message is rendered by the template of the catalog, if any.
+#[derive(Clone, Copy)]
+struct LocalizedMessage<'a>(&'a dyn MessageCatalog);
+
+impl RenderMessage for LocalizedMessage<'_> {
+    fn serialize_message<S, Params>(
+        self,
+        serializer: S,
+        code: &str,
+        message: &Message<Params>,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+        Params: ToDefaultMessage + serde::Serialize,
+    {
+        let template = if message.is_default() {
+            self.0.template(code)
+        } else {
+            None
+        };
+        match template {
+            Some(template) => {
+                serializer.serialize_str(&render_template(template, message.params()))
+            }
+            None => serializer.serialize_str(&message.to_string()),
+        }
+    }
+
+    fn serialize_custom<S>(
+        self,
+        serializer: S,
+        _code: &str,
+        message: &str,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(message)
+    }
+}
@@ -10,6 +10,20 @@ impl ObjectErrors {
     pub fn new(errors: VecErrors, properties: PropertyErrorsMap) -> Self {
         Self { errors, properties }
     }
+
+    pub fn merge(mut self, other: ObjectErrors) -> Self {
+        self.errors.extend(other.errors);
+
+        for (property, errors) in other.properties {
+            match self.properties.get_mut(&property) {
+                Some(self_errors) => self_errors.merge(errors),
+                None => {
+                    self.properties.insert(property, errors);
+                }
+            };
+        }
+        self
+    }
 }
 
 impl std::fmt::Display for ObjectErrors {
@@ -0,0 +1,162 @@
+use super::{ArrayErrors, Error, Errors, ObjectErrors, VecErrors};
+use crate::error::{Message, ToDefaultMessage};
+use serde::ser::{SerializeMap, SerializeSeq};
+
+/// How the leaf error is serialized in the views of the errors,
+/// such as [`StructuredErrors`](super::StructuredErrors) and [`LocalizedErrors`](super::LocalizedErrors).
+pub(crate) trait RenderMessage: Copy {
+    fn serialize_message<S, Params>(
+        self,
+        serializer: S,
+        code: &str,
+        message: &Message<Params>,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+        Params: ToDefaultMessage + serde::Serialize;
+
+    fn serialize_custom<S>(
+        self,
+        serializer: S,
+        code: &str,
+        message: &str,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer;
+}
+
+/// The errors whose leaf errors are serialized by the [`RenderMessage`],
+/// in the same shape as the [`Errors`].
+pub(crate) struct RenderedErrors<'a, R>(pub &'a Errors, pub R);
+
+impl<R> serde::Serialize for RenderedErrors<'_, R>
+where
+    R: RenderMessage,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.0 {
+            Errors::Object(object_errors) => {
+                RenderedObjectErrors(object_errors, self.1).serialize(serializer)
+            }
+            Errors::Array(array_errors) => {
+                RenderedArrayErrors(array_errors, self.1).serialize(serializer)
+            }
+            Errors::NewType(vec_errors) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("errors", &RenderedVecErrors(vec_errors, self.1))?;
+                map.end()
+            }
+        }
+    }
+}
+
+struct RenderedObjectErrors<'a, R>(&'a ObjectErrors, R);
+
+impl<R> serde::Serialize for RenderedObjectErrors<'_, R>
+where
+    R: RenderMessage,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("errors", &RenderedVecErrors(&self.0.errors, self.1))?;
+        map.serialize_entry(
+            "properties",
+            &self
+                .0
+                .properties
+                .iter()
+                .map(|(property, errors)| (property, RenderedErrors(errors, self.1)))
+                .collect::<indexmap::IndexMap<_, _>>(),
+        )?;
+        map.end()
+    }
+}
+
+struct RenderedArrayErrors<'a, R>(&'a ArrayErrors, R);
+
+impl<R> serde::Serialize for RenderedArrayErrors<'_, R>
+where
+    R: RenderMessage,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("errors", &RenderedVecErrors(&self.0.errors, self.1))?;
+        map.serialize_entry(
+            "items",
+            &self
+                .0
+                .items
+                .iter()
+                .map(|(index, errors)| (index, RenderedErrors(errors, self.1)))
+                .collect::<indexmap::IndexMap<_, _>>(),
+        )?;
+        map.end()
+    }
+}
+
+struct RenderedVecErrors<'a, R>(&'a VecErrors, R);
+
+impl<R> serde::Serialize for RenderedVecErrors<'_, R>
+where
+    R: RenderMessage,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for error in self.0 {
+            seq.serialize_element(&RenderedError(error, self.1))?;
+        }
+        seq.end()
+    }
+}
+
+struct RenderedError<'a, R>(&'a Error, R);
+
+impl<R> serde::Serialize for RenderedError<'_, R>
+where
+    R: RenderMessage,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let code = self.0.code();
+        match self.0 {
+            Error::Minimum(message) => self.1.serialize_message(serializer, code, message),
+            Error::Maximum(message) => self.1.serialize_message(serializer, code, message),
+            Error::ExclusiveMinimum(message) => self.1.serialize_message(serializer, code, message),
+            Error::ExclusiveMaximum(message) => self.1.serialize_message(serializer, code, message),
+            Error::MultipleOf(message) => self.1.serialize_message(serializer, code, message),
+            Error::MinLength(message) => self.1.serialize_message(serializer, code, message),
+            Error::MaxLength(message) => self.1.serialize_message(serializer, code, message),
+            Error::Pattern(message) => self.1.serialize_message(serializer, code, message),
+            Error::Format(message) => self.1.serialize_message(serializer, code, message),
+            Error::MinItems(message) => self.1.serialize_message(serializer, code, message),
+            Error::MaxItems(message) => self.1.serialize_message(serializer, code, message),
+            Error::UniqueItems(message) => self.1.serialize_message(serializer, code, message),
+            Error::Contains(message) => self.1.serialize_message(serializer, code, message),
+            Error::MinProperties(message) => self.1.serialize_message(serializer, code, message),
+            Error::MaxProperties(message) => self.1.serialize_message(serializer, code, message),
+            Error::Enumerate(message) => self.1.serialize_message(serializer, code, message),
+            Error::Const(message) => self.1.serialize_message(serializer, code, message),
+            Error::Custom(message) => self.1.serialize_custom(serializer, code, message),
+            Error::Items(array_errors) => {
+                RenderedArrayErrors(array_errors, self.1).serialize(serializer)
+            }
+            Error::Properties(object_errors) => {
+                RenderedObjectErrors(object_errors, self.1).serialize(serializer)
+            }
+        }
+    }
+}
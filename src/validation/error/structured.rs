@@ -0,0 +1,107 @@
+use super::rendered::{RenderMessage, RenderedErrors};
+use super::Errors;
+use crate::error::{Message, ToDefaultMessage};
+use serde::ser::SerializeMap;
+
+/// The errors serialized with the error code and params.
+///
+/// Each error becomes the object of `code`, `params` and `message`,
+/// instead of the message string.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::Validate;
+///
+/// #[derive(Validate)]
+/// struct SampleStruct {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// let s = SampleStruct { val: 101 };
+///
+/// assert_eq!(
+///     serde_json::to_value(s.validate().unwrap_err().structured()).unwrap(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": [
+///                     {
+///                         "code": "maximum",
+///                         "params": { "maximum": 100, "value": 101 },
+///                         "message": "The number must be `<= 100`."
+///                     }
+///                 ]
+///             }
+///         }
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StructuredErrors<'a>(&'a Errors);
+
+impl<'a> StructuredErrors<'a> {
+    pub fn new(errors: &'a Errors) -> Self {
+        Self(errors)
+    }
+}
+
+impl serde::Serialize for StructuredErrors<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        RenderedErrors(self.0, StructuredMessage).serialize(serializer)
+    }
+}
+
+impl std::fmt::Display for StructuredErrors<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match serde_json::to_string(&self) {
+            Ok(json_string) => {
+                write!(f, "{}", json_string)
+            }
+            Err(_) => Err(std::fmt::Error),
+        }
+    }
+}
+
+/// The error is the object of `code`, `params` and `message`.
+#[derive(Clone, Copy)]
+struct StructuredMessage;
+
+impl RenderMessage for StructuredMessage {
+    fn serialize_message<S, Params>(
+        self,
+        serializer: S,
+        code: &str,
+        message: &Message<Params>,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+        Params: ToDefaultMessage + serde::Serialize,
+    {
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("code", code)?;
+        map.serialize_entry("params", message.params())?;
+        map.serialize_entry("message", &message.to_string())?;
+        map.end()
+    }
+
+    fn serialize_custom<S>(
+        self,
+        serializer: S,
+        code: &str,
+        message: &str,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("code", code)?;
+        map.serialize_entry("params", &serde_json::Map::new())?;
+        map.serialize_entry("message", message)?;
+        map.end()
+    }
+}
@@ -1,2 +1,4 @@
+mod const_value;
 mod enumerate;
+pub use const_value::ValidateConstValue;
 pub use enumerate::ValidateEnumerate;
@@ -0,0 +1,222 @@
+use crate::validation::{impl_validate_pointers, ValidateCompositedConstValue};
+use crate::ConstErrorParams;
+
+/// Const validation.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/generic.html#constant-values>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateConstValue};
+///
+/// struct MyType(String);
+///
+/// impl ValidateConstValue<&'static str> for MyType {
+///     fn validate_const_value(
+///         &self,
+///         const_value: &'static str,
+///     ) -> Result<(), serde_valid::ConstErrorParams> {
+///         self.0.validate_const_value(const_value)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(const_value = "v1")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType("v2".to_string()),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must be `v1`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateConstValue<T> {
+    fn validate_const_value(&self, const_value: T) -> Result<(), ConstErrorParams>;
+}
+
+macro_rules! impl_validate_generic_const_value_literal {
+    ($type:ty) => {
+        impl ValidateConstValue<$type> for $type {
+            fn validate_const_value(&self, const_value: $type) -> Result<(), ConstErrorParams> {
+                if *self == const_value {
+                    Ok(())
+                } else {
+                    Err(ConstErrorParams::new(const_value, *self))
+                }
+            }
+        }
+
+        impl<T> ValidateCompositedConstValue<$type> for T
+        where
+            T: ValidateConstValue<$type>,
+        {
+            fn validate_composited_const_value(
+                &self,
+                limit: $type,
+            ) -> Result<(), crate::validation::Composited<ConstErrorParams>> {
+                self.validate_const_value(limit)
+                    .map_err(crate::validation::Composited::Single)
+            }
+        }
+    };
+}
+
+impl_validate_generic_const_value_literal!(i8);
+impl_validate_generic_const_value_literal!(i16);
+impl_validate_generic_const_value_literal!(i32);
+impl_validate_generic_const_value_literal!(i64);
+impl_validate_generic_const_value_literal!(i128);
+impl_validate_generic_const_value_literal!(isize);
+impl_validate_generic_const_value_literal!(u8);
+impl_validate_generic_const_value_literal!(u16);
+impl_validate_generic_const_value_literal!(u32);
+impl_validate_generic_const_value_literal!(u64);
+impl_validate_generic_const_value_literal!(u128);
+impl_validate_generic_const_value_literal!(usize);
+impl_validate_generic_const_value_literal!(f32);
+impl_validate_generic_const_value_literal!(f64);
+impl_validate_generic_const_value_literal!(char);
+impl_validate_generic_const_value_literal!(bool);
+
+macro_rules! impl_validate_generic_const_value_str {
+    ($type:ty) => {
+        impl_validate_generic_const_value_str!($type, to_string);
+    };
+
+    ($type:ty, $($to_string:ident).+) => {
+        impl ValidateConstValue<&'static str> for $type {
+            fn validate_const_value(
+                &self,
+                const_value: &'static str,
+            ) -> Result<(), ConstErrorParams> {
+                if self == &const_value {
+                    Ok(())
+                } else {
+                    Err(ConstErrorParams::new(const_value, self$(.$to_string())+))
+                }
+            }
+        }
+    };
+}
+
+impl_validate_generic_const_value_str!(&str);
+impl_validate_generic_const_value_str!(String);
+impl_validate_generic_const_value_str!(std::borrow::Cow<'_, str>);
+impl_validate_generic_const_value_str!(&std::ffi::OsStr, to_string_lossy.into_owned);
+impl_validate_generic_const_value_str!(std::ffi::OsString, to_string_lossy.into_owned);
+
+macro_rules! impl_validate_generic_const_value_path {
+    ($type:ty) => {
+        impl ValidateConstValue<&'static str> for $type {
+            fn validate_const_value(
+                &self,
+                const_value: &'static str,
+            ) -> Result<(), ConstErrorParams> {
+                if &std::path::Path::new(const_value) == self {
+                    Ok(())
+                } else {
+                    Err(ConstErrorParams::new(
+                        const_value,
+                        self.to_string_lossy().into_owned(),
+                    ))
+                }
+            }
+        }
+    };
+}
+
+impl_validate_generic_const_value_path!(&std::path::Path);
+impl_validate_generic_const_value_path!(std::path::PathBuf);
+
+impl<T> ValidateCompositedConstValue<&'static str> for T
+where
+    T: ValidateConstValue<&'static str>,
+{
+    fn validate_composited_const_value(
+        &self,
+        limit: &'static str,
+    ) -> Result<(), crate::validation::Composited<ConstErrorParams>> {
+        self.validate_const_value(limit)
+            .map_err(crate::validation::Composited::Single)
+    }
+}
+
+impl_validate_pointers!(
+    ValidateConstValue<T>,
+    fn validate_const_value(const_value: T) -> ConstErrorParams
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_integer_type() {
+        assert!(ValidateConstValue::validate_const_value(&1, 1).is_ok());
+        assert!(ValidateConstValue::validate_const_value(&1, 2).is_err());
+    }
+
+    #[test]
+    fn test_validate_float_type() {
+        assert!(ValidateConstValue::validate_const_value(&0.5, 0.5).is_ok());
+        assert!(ValidateConstValue::validate_const_value(&0.5, 0.6).is_err());
+    }
+
+    #[test]
+    fn test_validate_bool_type() {
+        assert!(ValidateConstValue::validate_const_value(&true, true).is_ok());
+        assert!(ValidateConstValue::validate_const_value(&false, true).is_err());
+    }
+
+    #[test]
+    fn test_validate_char_type() {
+        assert!(ValidateConstValue::validate_const_value(&'a', 'a').is_ok());
+    }
+
+    #[test]
+    fn test_validate_str_type() {
+        assert!(ValidateConstValue::validate_const_value(&"v1", "v1").is_ok());
+        assert!(ValidateConstValue::validate_const_value(&"v2", "v1").is_err());
+    }
+
+    #[test]
+    fn test_validate_string_type() {
+        assert!(ValidateConstValue::validate_const_value(&String::from("v1"), "v1").is_ok());
+    }
+
+    #[test]
+    fn test_validate_os_string_type() {
+        assert!(
+            ValidateConstValue::validate_const_value(&std::ffi::OsString::from("v1"), "v1").is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_path_buf_type() {
+        assert!(ValidateConstValue::validate_const_value(
+            &std::path::PathBuf::from("./a/b"),
+            "./a/b"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_error_params() {
+        let error = ValidateConstValue::validate_const_value(&"v2", "v1").unwrap_err();
+        assert_eq!(error.const_value, "v1".into());
+        assert_eq!(error.value, "v2".into());
+    }
+}
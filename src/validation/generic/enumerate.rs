@@ -1,4 +1,4 @@
-use crate::validation::ValidateCompositedEnumerate;
+use crate::validation::{impl_validate_pointers, ValidateCompositedEnumerate};
 use crate::EnumerateErrorParams;
 
 /// Enumerate validation.
@@ -54,7 +54,7 @@ macro_rules! impl_validate_generic_enumerate_literal {
                 if enumerate.iter().any(|candidate| candidate == self) {
                     Ok(())
                 } else {
-                    Err(EnumerateErrorParams::new(enumerate))
+                    Err(EnumerateErrorParams::new(enumerate, *self))
                 }
             }
         }
@@ -104,6 +104,10 @@ impl_validate_generic_enumerate_literal!(char);
 
 macro_rules! impl_validate_generic_enumerate_str {
     ($type:ty) => {
+        impl_validate_generic_enumerate_str!($type, to_string);
+    };
+
+    ($type:ty, $($to_string:ident).+) => {
         impl ValidateEnumerate<&'static str> for $type {
             fn validate_enumerate(
                 &self,
@@ -112,7 +116,7 @@ macro_rules! impl_validate_generic_enumerate_str {
                 if enumerate.iter().any(|candidate| candidate == self) {
                     Ok(())
                 } else {
-                    Err(EnumerateErrorParams::new(enumerate))
+                    Err(EnumerateErrorParams::new(enumerate, self$(.$to_string())+))
                 }
             }
         }
@@ -122,8 +126,8 @@ macro_rules! impl_validate_generic_enumerate_str {
 impl_validate_generic_enumerate_str!(&str);
 impl_validate_generic_enumerate_str!(String);
 impl_validate_generic_enumerate_str!(std::borrow::Cow<'_, str>);
-impl_validate_generic_enumerate_str!(&std::ffi::OsStr);
-impl_validate_generic_enumerate_str!(std::ffi::OsString);
+impl_validate_generic_enumerate_str!(&std::ffi::OsStr, to_string_lossy.into_owned);
+impl_validate_generic_enumerate_str!(std::ffi::OsString, to_string_lossy.into_owned);
 
 macro_rules! impl_validate_generic_enumerate_path {
     ($type:ty) => {
@@ -138,7 +142,10 @@ macro_rules! impl_validate_generic_enumerate_path {
                 {
                     Ok(())
                 } else {
-                    Err(EnumerateErrorParams::new(enumerate))
+                    Err(EnumerateErrorParams::new(
+                        enumerate,
+                        self.to_string_lossy().into_owned(),
+                    ))
                 }
             }
         }
@@ -161,6 +168,11 @@ where
     }
 }
 
+impl_validate_pointers!(
+    ValidateEnumerate<T>,
+    fn validate_enumerate(enumerate: &[T]) -> EnumerateErrorParams
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,6 @@
 use crate::validation::{
-    impl_generic_composited_validation_1args, ValidateCompositedExclusiveMaximum,
+    impl_generic_composited_validation_1args, impl_validate_pointers,
+    ValidateCompositedExclusiveMaximum,
 };
 use crate::ExclusiveMaximumErrorParams;
 
@@ -59,7 +60,10 @@ macro_rules! impl_validate_numeric_exclusive_maximum {
                 if *self < exclusive_maximum {
                     Ok(())
                 } else {
-                    Err(crate::ExclusiveMaximumErrorParams::new(exclusive_maximum))
+                    Err(crate::ExclusiveMaximumErrorParams::new(
+                        exclusive_maximum,
+                        *self,
+                    ))
                 }
             }
         }
@@ -95,6 +99,11 @@ impl_validate_numeric_exclusive_maximum!(std::num::NonZeroUsize);
 impl_validate_numeric_exclusive_maximum!(f32);
 impl_validate_numeric_exclusive_maximum!(f64);
 
+impl_validate_pointers!(
+    ValidateExclusiveMaximum<T> where [T: PartialOrd + PartialEq],
+    fn validate_exclusive_maximum(exclusive_maximum: T) -> ExclusiveMaximumErrorParams
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
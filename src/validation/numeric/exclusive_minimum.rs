@@ -1,5 +1,6 @@
 use crate::validation::{
-    impl_generic_composited_validation_1args, ValidateCompositedExclusiveMinimum,
+    impl_generic_composited_validation_1args, impl_validate_pointers,
+    ValidateCompositedExclusiveMinimum,
 };
 use crate::ExclusiveMinimumErrorParams;
 
@@ -59,7 +60,7 @@ macro_rules! impl_validate_numeric_exclusive_minimum {
                 if *self > exclusive_minimum {
                     Ok(())
                 } else {
-                    Err(ExclusiveMinimumErrorParams::new(exclusive_minimum))
+                    Err(ExclusiveMinimumErrorParams::new(exclusive_minimum, *self))
                 }
             }
         }
@@ -95,6 +96,11 @@ impl_validate_numeric_exclusive_minimum!(std::num::NonZeroUsize);
 impl_validate_numeric_exclusive_minimum!(f32);
 impl_validate_numeric_exclusive_minimum!(f64);
 
+impl_validate_pointers!(
+    ValidateExclusiveMinimum<T> where [T: PartialOrd + PartialEq],
+    fn validate_exclusive_minimum(exclusive_minimum: T) -> ExclusiveMinimumErrorParams
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
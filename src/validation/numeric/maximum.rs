@@ -1,4 +1,6 @@
-use crate::validation::{impl_generic_composited_validation_1args, ValidateCompositedMaximum};
+use crate::validation::{
+    impl_generic_composited_validation_1args, impl_validate_pointers, ValidateCompositedMaximum,
+};
 use crate::MaximumErrorParams;
 
 /// Maximum validation of the number.
@@ -51,7 +53,7 @@ macro_rules! impl_validate_numeric_maximum {
                 if *self <= maximum {
                     Ok(())
                 } else {
-                    Err(MaximumErrorParams::new(maximum))
+                    Err(MaximumErrorParams::new(maximum, *self))
                 }
             }
         }
@@ -87,6 +89,11 @@ impl_validate_numeric_maximum!(std::num::NonZeroUsize);
 impl_validate_numeric_maximum!(f32);
 impl_validate_numeric_maximum!(f64);
 
+impl_validate_pointers!(
+    ValidateMaximum<T> where [T: PartialOrd + PartialEq],
+    fn validate_maximum(maximum: T) -> MaximumErrorParams
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
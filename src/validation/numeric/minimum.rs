@@ -1,4 +1,6 @@
-use crate::validation::{impl_generic_composited_validation_1args, ValidateCompositedMinimum};
+use crate::validation::{
+    impl_generic_composited_validation_1args, impl_validate_pointers, ValidateCompositedMinimum,
+};
 use crate::MinimumErrorParams;
 
 /// Minimum validation of the number.
@@ -51,7 +53,7 @@ macro_rules! impl_validate_numeric_minimum {
                 if *self >= minimum {
                     Ok(())
                 } else {
-                    Err(MinimumErrorParams::new(minimum))
+                    Err(MinimumErrorParams::new(minimum, *self))
                 }
             }
         }
@@ -87,6 +89,11 @@ impl_validate_numeric_minimum!(std::num::NonZeroUsize);
 impl_validate_numeric_minimum!(f32);
 impl_validate_numeric_minimum!(f64);
 
+impl_validate_pointers!(
+    ValidateMinimum<T> where [T: PartialOrd + PartialEq],
+    fn validate_minimum(minimum: T) -> MinimumErrorParams
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
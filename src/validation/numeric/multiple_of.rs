@@ -1,4 +1,6 @@
-use crate::validation::{impl_generic_composited_validation_1args, ValidateCompositedMultipleOf};
+use crate::validation::{
+    impl_generic_composited_validation_1args, impl_validate_pointers, ValidateCompositedMultipleOf,
+};
 use crate::MultipleOfErrorParams;
 
 /// Multipl validation of the number.
@@ -61,7 +63,7 @@ macro_rules! impl_validate_numeric_multiple_of {
                 ) {
                     Ok(())
                 } else {
-                    Err(crate::MultipleOfErrorParams::new(multiple_of))
+                    Err(crate::MultipleOfErrorParams::new(multiple_of, *self))
                 }
             }
         }
@@ -85,6 +87,11 @@ impl_validate_numeric_multiple_of!(usize);
 impl_validate_numeric_multiple_of!(f32);
 impl_validate_numeric_multiple_of!(f64);
 
+impl_validate_pointers!(
+    ValidateMultipleOf<T> where [T: std::cmp::PartialEq + std::ops::Rem<Output = T> + num_traits::Zero],
+    fn validate_multiple_of(multiple_of: T) -> crate::MultipleOfErrorParams
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
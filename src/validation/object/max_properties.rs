@@ -65,7 +65,7 @@ where
         if max_properties >= self.size() {
             Ok(())
         } else {
-            Err(MaxPropertiesErrorParams::new(max_properties))
+            Err(MaxPropertiesErrorParams::new(max_properties, self.size()))
         }
     }
 }
@@ -63,7 +63,7 @@ where
         if min_properties <= self.size() {
             Ok(())
         } else {
-            Err(MinPropertiesErrorParams::new(min_properties))
+            Err(MinPropertiesErrorParams::new(min_properties, self.size()))
         }
     }
 }
@@ -0,0 +1,108 @@
+use crate::{format, traits::IsMatch, FormatErrorParams};
+
+/// Format validation of the string.
+///
+/// The built-in formats are
+/// `email`, `uri`, `uri-reference`, `hostname`, `ipv4`, `ipv6`, `uuid`,
+/// `date`, `time`, `date-time`, `duration`, `json-pointer` and `regex`,
+/// and the other formats can be registered by [`format::register`].
+///
+/// # Panics
+///
+/// Panics if the format is neither built-in nor registered, which is the mistake of the program.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/string.html#built-in-formats>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateFormat};
+///
+/// struct MyType(String);
+///
+/// impl ValidateFormat for MyType {
+///     fn validate_format(&self, format: &str) -> Result<(), serde_valid::FormatErrorParams> {
+///         self.0.validate_format(format)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(format = "date")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("2020/09/10")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": [r#"The value must match the format of "date"."#]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateFormat {
+    fn validate_format(&self, format: &str) -> Result<(), FormatErrorParams>;
+}
+
+impl<T> ValidateFormat for T
+where
+    T: IsMatch + ?Sized,
+{
+    fn validate_format(&self, format: &str) -> Result<(), FormatErrorParams> {
+        let value = self.as_match_str();
+        match format::check(format, &value) {
+            Some(true) => Ok(()),
+            Some(false) => Err(FormatErrorParams::new(format)),
+            None => panic!(
+                "The format `{format}` is unknown. Register it by `serde_valid::format::register`."
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::path::Path;
+
+    #[test]
+    fn test_validate_string_format_str_type() {
+        assert!(ValidateFormat::validate_format("2020-09-10", "date").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_string_type() {
+        assert!(ValidateFormat::validate_format(&String::from("::1"), "ipv6").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_cow_str_type() {
+        assert!(ValidateFormat::validate_format(&Cow::from("a@example.com"), "email").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_path_type() {
+        assert!(ValidateFormat::validate_format(Path::new("/foo/bar"), "json-pointer").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_is_false() {
+        let error = ValidateFormat::validate_format("2020/09/10", "date").unwrap_err();
+        assert_eq!(error.format, "date");
+    }
+
+    #[test]
+    #[should_panic(expected = "The format `unknown` is unknown.")]
+    fn test_validate_string_format_unknown_format() {
+        let _ = ValidateFormat::validate_format("abc", "unknown");
+    }
+}
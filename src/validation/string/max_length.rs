@@ -54,7 +54,7 @@ where
         if max_length >= self.length() {
             Ok(())
         } else {
-            Err(MaxLengthErrorParams::new(max_length))
+            Err(MaxLengthErrorParams::new(max_length, self.length()))
         }
     }
 }
@@ -54,7 +54,7 @@ where
         if min_length <= self.length() {
             Ok(())
         } else {
-            Err(MinLengthErrorParams::new(min_length))
+            Err(MinLengthErrorParams::new(min_length, self.length()))
         }
     }
 }
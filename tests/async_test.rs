@@ -0,0 +1,264 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::{FromJsonStrAsync, FromJsonValueAsync};
+use serde_valid::{AsyncValidate, Validate};
+
+async fn username_not_taken(username: &str) -> Result<(), serde_valid::validation::Error> {
+    if username == "taken" {
+        Err(serde_valid::validation::Error::Custom(
+            "The username is already taken.".to_owned(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+async fn email_matches_username(
+    username: &str,
+    email: &str,
+) -> Result<(), serde_valid::validation::Error> {
+    if email.starts_with(username) {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(
+            "The email must start with the username.".to_owned(),
+        ))
+    }
+}
+
+#[test]
+fn async_custom_is_ok() {
+    #[derive(Validate, AsyncValidate)]
+    struct TestStruct {
+        #[validate(custom_async(username_not_taken))]
+        username: String,
+    }
+
+    let s = TestStruct {
+        username: "alice".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+    assert!(pollster::block_on(s.validate_async()).is_ok());
+}
+
+#[test]
+fn async_custom_is_err() {
+    #[derive(Validate, AsyncValidate)]
+    struct TestStruct {
+        #[validate(custom_async(username_not_taken))]
+        username: String,
+    }
+
+    let s = TestStruct {
+        username: "taken".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        pollster::block_on(s.validate_async())
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "username": {
+                    "errors": ["The username is already taken."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn async_errors_merge_into_sync_errors() {
+    #[derive(Validate, AsyncValidate)]
+    #[rule_async(email_matches_username(username, email))]
+    struct TestStruct {
+        #[validate(max_length = 3)]
+        #[validate(custom_async(username_not_taken))]
+        username: String,
+        #[validate(min_length = 3)]
+        email: String,
+    }
+
+    let s = TestStruct {
+        username: "taken".to_owned(),
+        email: "a".to_owned(),
+    };
+    assert_eq!(
+        serde_json::to_value(pollster::block_on(s.validate_async()).unwrap_err()).unwrap(),
+        json!({
+            "errors": ["The email must start with the username."],
+            "properties": {
+                "username": {
+                    "errors": [
+                        "The length of the value must be `<= 3`.",
+                        "The username is already taken."
+                    ]
+                },
+                "email": {
+                    "errors": ["The length of the value must be `>= 3`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn async_unnamed_struct_is_err() {
+    #[derive(Validate, AsyncValidate)]
+    #[rule_async(email_matches_username(0, 1))]
+    struct TestStruct(#[validate(custom_async(username_not_taken))] String, String);
+
+    let s = TestStruct("taken".to_owned(), "alice@example.com".to_owned());
+    assert_eq!(
+        serde_json::to_value(pollster::block_on(s.validate_async()).unwrap_err()).unwrap(),
+        json!({
+            "errors": ["The email must start with the username."],
+            "items": {
+                "0": {
+                    "errors": ["The username is already taken."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn async_enum_is_err() {
+    #[derive(Validate, AsyncValidate)]
+    enum TestEnum {
+        Named {
+            #[validate(custom_async(username_not_taken))]
+            username: String,
+        },
+        Unnamed(#[validate(max_length = 3)] String),
+    }
+
+    let s = TestEnum::Named {
+        username: "taken".to_owned(),
+    };
+    assert_eq!(
+        pollster::block_on(s.validate_async())
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "username": {
+                    "errors": ["The username is already taken."]
+                }
+            }
+        })
+        .to_string()
+    );
+
+    let s = TestEnum::Unnamed("abcd".to_owned());
+    assert_eq!(
+        pollster::block_on(s.validate_async())
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["The length of the value must be `<= 3`."]
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn from_json_async_is_err() {
+    #[derive(Debug, Deserialize, Validate, AsyncValidate)]
+    struct TestStruct {
+        #[validate(custom_async(username_not_taken))]
+        username: String,
+    }
+
+    assert!(pollster::block_on(TestStruct::from_json_value_async(
+        json!({ "username": "alice" })
+    ))
+    .is_ok());
+
+    let err = pollster::block_on(TestStruct::from_json_str_async(
+        r#"{ "username": "taken" }"#,
+    ))
+    .unwrap_err();
+    assert_eq!(
+        err.as_validation_errors().unwrap().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "username": {
+                    "errors": ["The username is already taken."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn async_nested_is_err() {
+    #[derive(Validate, AsyncValidate)]
+    struct TestInnerStruct {
+        #[validate(max_length = 3)]
+        #[validate(custom_async(username_not_taken))]
+        username: String,
+    }
+
+    #[derive(Validate, AsyncValidate)]
+    struct TestStruct {
+        #[validate]
+        owner: TestInnerStruct,
+        #[validate]
+        members: Vec<TestInnerStruct>,
+        #[validate]
+        deputy: Option<TestInnerStruct>,
+    }
+
+    let s = TestStruct {
+        owner: TestInnerStruct {
+            username: "taken".to_owned(),
+        },
+        members: vec![
+            TestInnerStruct {
+                username: "bob".to_owned(),
+            },
+            TestInnerStruct {
+                username: "alice".to_owned(),
+            },
+        ],
+        deputy: None,
+    };
+    assert_eq!(
+        serde_json::to_value(pollster::block_on(s.validate_async()).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "owner": {
+                    "errors": [],
+                    "properties": {
+                        "username": {
+                            "errors": [
+                                "The length of the value must be `<= 3`.",
+                                "The username is already taken."
+                            ]
+                        }
+                    }
+                },
+                "members": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "username": {
+                                    "errors": ["The length of the value must be `<= 3`."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
@@ -0,0 +1,133 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn custom_closure_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom = |val: &i32| if *val >= 0 { Ok(()) } else { Err(serde_valid::validation::Error::Custom("negative".to_owned())) })]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 1 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn custom_closure_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom = |val: &str| {
+            if val.chars().all(char::is_alphanumeric) {
+                Ok(())
+            } else {
+                Err(serde_valid::validation::Error::Custom(
+                    "The value must be alphanumeric.".to_owned(),
+                ))
+            }
+        })]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "a-b".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value must be alphanumeric."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn custom_closure_unnamed_struct_is_err() {
+    #[derive(Validate)]
+    struct TestStruct(
+        #[validate(custom = |val: &i32| if *val % 2 == 0 { Ok(()) } else { Err(serde_valid::validation::Error::Custom("odd".to_owned())) })]
+         i32,
+    );
+
+    let s = TestStruct(3);
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["odd"]
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn expr_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        start: u32,
+        #[validate(
+            expr = "self.start <= self.end",
+            message = "The end must be after the start."
+        )]
+        end: u32,
+    }
+
+    let s = TestStruct { start: 1, end: 2 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn expr_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        start: u32,
+        #[validate(
+            expr = "self.start <= self.end",
+            message = "The end must be after the start."
+        )]
+        end: u32,
+    }
+
+    let s = TestStruct { start: 2, end: 1 };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "end": {
+                    "errors": ["The end must be after the start."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn expr_with_field_variable_default_message_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(expr = "!val.trim().is_empty()")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "  ".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The condition is not satisfied."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
@@ -0,0 +1,237 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateConstValue};
+
+#[test]
+fn const_value_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(const_value = "v1")]
+        val: &'a str,
+    }
+
+    let s = TestStruct { val: "v1" };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_string_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = "v1")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("v1"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_integer_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 2)]
+        val: u16,
+    }
+
+    let s = TestStruct { val: 2 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_float_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 0.5)]
+        val: f32,
+    }
+
+    let s = TestStruct { val: 0.5 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_bool_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = true)]
+        val: bool,
+    }
+
+    let s = TestStruct { val: false };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn const_value_char_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 'a')]
+        val: char,
+    }
+
+    let s = TestStruct { val: 'a' };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = "v1")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("v2"),
+    };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn const_value_vec_type_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 1)]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct { val: vec![1, 2] };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["The value must be `1`."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn const_value_option_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = "v1")]
+        val: Option<String>,
+    }
+
+    let s = TestStruct { val: None };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = "v1")]
+        api_version: String,
+    }
+
+    let s = TestStruct {
+        api_version: String::from("v2"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "api_version": {
+                    "errors": ["The value must be `v1`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn const_value_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = "v1", message = "{value} is not supported.")]
+        api_version: String,
+    }
+
+    let s = TestStruct {
+        api_version: String::from("v2"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "api_version": {
+                    "errors": ["v2 is not supported."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn const_value_structured_error() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 1)]
+        version: u8,
+    }
+
+    let s = TestStruct { version: 2 };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().structured()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "version": {
+                    "errors": [
+                        {
+                            "code": "const_value",
+                            "params": { "const_value": 1, "value": 2 },
+                            "message": "The value must be `1`."
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn const_value_trait() {
+    struct MyType(String);
+
+    impl ValidateConstValue<&'static str> for MyType {
+        fn validate_const_value(
+            &self,
+            const_value: &'static str,
+        ) -> Result<(), serde_valid::ConstErrorParams> {
+            self.0.validate_const_value(const_value)
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = "v1")]
+        val: MyType,
+    }
+
+    let s = TestStruct {
+        val: MyType(String::from("v1")),
+    };
+    assert!(s.validate().is_ok());
+}
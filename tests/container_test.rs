@@ -0,0 +1,271 @@
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn box_recursive_validate_is_err() {
+    #[derive(Validate)]
+    enum Expr {
+        Number(#[validate(maximum = 100)] i32),
+        Add(#[validate] Box<Expr>, #[validate] Box<Expr>),
+    }
+
+    let s = Expr::Add(
+        Box::new(Expr::Number(1)),
+        Box::new(Expr::Add(
+            Box::new(Expr::Number(2)),
+            Box::new(Expr::Number(101)),
+        )),
+    );
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "items": {
+                "1": {
+                    "errors": [],
+                    "items": {
+                        "1": { "errors": ["The number must be `<= 100`."] }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn smart_pointer_validate_is_err() {
+    #[derive(Clone, Validate)]
+    struct Inner {
+        #[validate(min_length = 1)]
+        name: String,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        rc: Rc<Inner>,
+        #[validate]
+        arc: Arc<Inner>,
+        #[validate]
+        cow: Cow<'static, Inner>,
+    }
+
+    let s = TestStruct {
+        rc: Rc::new(Inner {
+            name: "".to_owned(),
+        }),
+        arc: Arc::new(Inner {
+            name: "arc".to_owned(),
+        }),
+        cow: Cow::Owned(Inner {
+            name: "cow".to_owned(),
+        }),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "rc": {
+                    "errors": [],
+                    "properties": {
+                        "name": {
+                            "errors": ["The length of the value must be `>= 1`."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn tuple_validate_is_err() {
+    #[derive(Validate)]
+    struct Point {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let s = (Point { val: 1 }, Point { val: 11 });
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "items": {
+                "1": {
+                    "errors": [],
+                    "properties": {
+                        "val": { "errors": ["The number must be `<= 10`."] }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn collections_element_wise_validate_is_err() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(max_length = 3)]
+        deque: VecDeque<String>,
+        #[validate(max_length = 3)]
+        hash_set: HashSet<String>,
+        #[validate(max_length = 3)]
+        btree_set: BTreeSet<String>,
+        #[validate(maximum = 10)]
+        slice: &'a [i32],
+        #[validate(maximum = 10)]
+        boxed_slice: Box<[i32]>,
+        #[validate(maximum = 10)]
+        cow_slice: Cow<'a, [i32]>,
+        #[validate(max_length = 3)]
+        tuple: (String, &'a str),
+    }
+
+    let s = TestStruct {
+        deque: VecDeque::from(["abc".to_owned(), "abcd".to_owned()]),
+        hash_set: HashSet::from(["abcd".to_owned()]),
+        btree_set: BTreeSet::from(["abc".to_owned(), "abcd".to_owned()]),
+        slice: &[10, 11],
+        boxed_slice: Box::new([11]),
+        cow_slice: Cow::Owned(vec![1, 2, 11]),
+        tuple: ("abc".to_owned(), "abcd"),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "deque": {
+                    "errors": [],
+                    "items": { "1": { "errors": ["The length of the value must be `<= 3`."] } }
+                },
+                "hash_set": {
+                    "errors": ["The length of the value must be `<= 3`."],
+                    "items": {}
+                },
+                "btree_set": {
+                    "errors": [],
+                    "items": { "1": { "errors": ["The length of the value must be `<= 3`."] } }
+                },
+                "slice": {
+                    "errors": [],
+                    "items": { "1": { "errors": ["The number must be `<= 10`."] } }
+                },
+                "boxed_slice": {
+                    "errors": [],
+                    "items": { "0": { "errors": ["The number must be `<= 10`."] } }
+                },
+                "cow_slice": {
+                    "errors": [],
+                    "items": { "2": { "errors": ["The number must be `<= 10`."] } }
+                },
+                "tuple": {
+                    "errors": [],
+                    "items": { "1": { "errors": ["The length of the value must be `<= 3`."] } }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn hash_set_validate_errors_have_no_index() {
+    #[derive(PartialEq, Eq, Hash, Validate)]
+    struct Tag(#[validate(max_length = 3)] String);
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        tags: HashSet<Tag>,
+    }
+
+    let s = TestStruct {
+        tags: HashSet::from([
+            Tag("abcd".to_owned()),
+            Tag("abcde".to_owned()),
+            Tag("ok".to_owned()),
+        ]),
+    };
+    let errors = s.validate().unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "tags": {
+                    "errors": [
+                        "The length of the value must be `<= 3`.",
+                        "The length of the value must be `<= 3`."
+                    ],
+                    "items": {}
+                }
+            }
+        })
+    );
+    assert_eq!(
+        errors
+            .flatten()
+            .iter()
+            .map(|flat_error| flat_error.path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["/tags", "/tags"]
+    );
+}
+
+#[test]
+fn box_and_reference_single_value_validate_is_err() {
+    #[allow(clippy::box_collection)]
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(maximum = 3)]
+        boxed_number: Box<i32>,
+        #[validate(max_length = 3)]
+        boxed_string: Box<String>,
+        #[validate(pattern = r"^\d+$")]
+        boxed_str: Box<str>,
+        #[validate(enumerate(1, 2))]
+        number_ref: &'a i32,
+        #[validate(min_length = 2)]
+        string_ref: &'a String,
+        #[validate(maximum = 3)]
+        optional_box: Option<Box<i32>>,
+    }
+
+    let s = TestStruct {
+        boxed_number: Box::new(4),
+        boxed_string: Box::new("abcd".to_owned()),
+        boxed_str: "abc".into(),
+        number_ref: &3,
+        string_ref: &"a".to_owned(),
+        optional_box: Some(Box::new(4)),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "boxed_number": { "errors": ["The number must be `<= 3`."] },
+                "boxed_string": { "errors": ["The length of the value must be `<= 3`."] },
+                "boxed_str": { "errors": [r#"The value must match the pattern of "^\d+$"."#] },
+                "number_ref": { "errors": ["The value must be in [1, 2]."] },
+                "string_ref": { "errors": ["The length of the value must be `>= 2`."] },
+                "optional_box": { "errors": ["The number must be `<= 3`."] }
+            }
+        })
+    );
+}
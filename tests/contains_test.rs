@@ -0,0 +1,330 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateContains};
+
+#[test]
+fn contains_enumerate_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(enumerate("admin")))]
+        roles: Vec<String>,
+    }
+
+    let s = TestStruct {
+        roles: vec!["user".to_owned(), "admin".to_owned()],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn contains_enumerate_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(enumerate("admin")))]
+        roles: Vec<String>,
+    }
+
+    let s = TestStruct {
+        roles: vec!["user".to_owned(), "guest".to_owned()],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "roles": {
+                    "errors": ["The items must contain `>= 1` matching items."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn contains_minimum_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(minimum = 10))]
+        vals: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        vals: vec![1, 2, 10],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn contains_minimum_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(minimum = 10))]
+        vals: [i32; 3],
+    }
+
+    let s = TestStruct { vals: [1, 2, 3] };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn contains_custom_is_ok() {
+    fn is_even(val: &i32) -> Result<(), serde_valid::validation::Error> {
+        if val % 2 == 0 {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "The value must be even.".to_owned(),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_even)))]
+        vals: Vec<i32>,
+    }
+
+    assert!(TestStruct { vals: vec![1, 2] }.validate().is_ok());
+    assert!(TestStruct { vals: vec![1, 3] }.validate().is_err());
+}
+
+#[test]
+fn contains_min_contains_and_max_contains() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(maximum = 0, min_contains = 2, max_contains = 3))]
+        vals: Vec<i32>,
+    }
+
+    assert!(TestStruct {
+        vals: vec![0, -1, 5]
+    }
+    .validate()
+    .is_ok());
+
+    let s = TestStruct {
+        vals: vec![0, -1, -2, -3],
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vals": {
+                    "errors": ["The items must contain `<= 3` matching items."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn contains_max_contains_only() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(enumerate("admin"), max_contains = 1))]
+        roles: Vec<&'static str>,
+    }
+
+    assert!(TestStruct {
+        roles: vec!["admin"]
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        roles: vec!["admin", "admin"]
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn contains_option_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(enumerate("admin")))]
+        roles: Option<Vec<String>>,
+    }
+
+    assert!(TestStruct { roles: None }.validate().is_ok());
+    assert!(TestStruct {
+        roles: Some(vec!["user".to_owned()])
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn contains_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            contains(enumerate("admin"), min_contains = 2),
+            message = "{matches} of {min_contains} admins."
+        )]
+        roles: Vec<String>,
+    }
+
+    let s = TestStruct {
+        roles: vec!["admin".to_owned()],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "roles": {
+                    "errors": ["1 of 2 admins."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn contains_err_message_max_contains() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            contains(enumerate("admin"), max_contains = 2),
+            message = "need {min_contains}..{max_contains}, got {matches}"
+        )]
+        roles: Vec<&'static str>,
+    }
+
+    let s = TestStruct {
+        roles: vec!["admin", "admin", "admin"],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "roles": {
+                    "errors": ["need 1..2, got 3"]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn contains_localize() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(enumerate("admin"), max_contains = 1))]
+        roles: Vec<&'static str>,
+    }
+
+    let catalog = serde_valid::error::JsonMessageCatalog::from_value(json!({
+        "contains": "{min_contains}件以上の管理者が必要です。",
+        "max_contains": "管理者は{max_contains}件以下にしてください。"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        TestStruct { roles: vec![] }
+            .validate()
+            .unwrap_err()
+            .localize(&catalog)
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "roles": {
+                    "errors": ["1件以上の管理者が必要です。"]
+                }
+            }
+        })
+        .to_string()
+    );
+    assert_eq!(
+        TestStruct {
+            roles: vec!["admin", "admin"]
+        }
+        .validate()
+        .unwrap_err()
+        .localize(&catalog)
+        .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "roles": {
+                    "errors": ["管理者は1件以下にしてください。"]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn contains_structured_error_params() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(minimum = 10, max_contains = 1))]
+        vals: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        vals: vec![10, 20, 30],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().structured()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vals": {
+                    "errors": [
+                        {
+                            "code": "max_contains",
+                            "params": { "min_contains": 1, "max_contains": 1, "matches": 3 },
+                            "message": "The items must contain `<= 1` matching items."
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn contains_trait() {
+    struct MyType(Vec<i32>);
+
+    impl ValidateContains for MyType {
+        type Item = i32;
+
+        fn validate_contains<F>(
+            &self,
+            min_contains: usize,
+            max_contains: Option<usize>,
+            predicate: F,
+        ) -> Result<(), serde_valid::ContainsErrorParams>
+        where
+            F: FnMut(&Self::Item) -> bool,
+        {
+            self.0
+                .validate_contains(min_contains, max_contains, predicate)
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(exclusive_minimum = 0))]
+        val: MyType,
+    }
+
+    assert!(TestStruct {
+        val: MyType(vec![-1, 1])
+    }
+    .validate()
+    .is_ok());
+}
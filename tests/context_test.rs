@@ -0,0 +1,325 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateWithContext};
+
+struct Context {
+    max_len: usize,
+    reserved: Vec<&'static str>,
+}
+
+impl Context {
+    fn new() -> Self {
+        Self {
+            max_len: 5,
+            reserved: vec!["admin", "root"],
+        }
+    }
+}
+
+fn not_reserved(val: &str, context: &Context) -> Result<(), serde_valid::validation::Error> {
+    if context.reserved.contains(&val) {
+        Err(serde_valid::validation::Error::Custom(format!(
+            "`{val}` is reserved."
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn within_max_len(
+    context: &Context,
+    first: &str,
+    last: &str,
+) -> Result<(), serde_valid::validation::Error> {
+    if first.len() + last.len() <= context.max_len {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(
+            "The name is too long.".to_owned(),
+        ))
+    }
+}
+
+#[test]
+fn context_custom_is_ok() {
+    #[derive(Validate)]
+    #[validate(context = "Context")]
+    struct TestStruct {
+        #[validate(custom(not_reserved), context)]
+        name: String,
+    }
+
+    let s = TestStruct {
+        name: "alice".to_owned(),
+    };
+    assert!(s.validate_with_context(&Context::new()).is_ok());
+}
+
+#[test]
+fn context_custom_is_err() {
+    #[derive(Validate)]
+    #[validate(context = "Context")]
+    struct TestStruct {
+        #[validate(custom(not_reserved), context)]
+        #[validate(max_length = 10)]
+        name: String,
+    }
+
+    let s = TestStruct {
+        name: "admin".to_owned(),
+    };
+    assert_eq!(
+        s.validate_with_context(&Context::new())
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["`admin` is reserved."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_rule_is_err() {
+    #[derive(Validate)]
+    #[validate(context = "Context")]
+    #[rule(within_max_len(ctx, first, last))]
+    struct TestStruct {
+        first: String,
+        last: String,
+    }
+
+    let s = TestStruct {
+        first: "abc".to_owned(),
+        last: "de".to_owned(),
+    };
+    assert!(s.validate_with_context(&Context::new()).is_ok());
+
+    let s = TestStruct {
+        first: "abc".to_owned(),
+        last: "def".to_owned(),
+    };
+    assert_eq!(
+        s.validate_with_context(&Context::new())
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["The name is too long."],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_unnamed_rule_is_err() {
+    #[derive(Validate)]
+    #[validate(context = "Context")]
+    #[rule(within_max_len(ctx, 0, 1))]
+    struct TestStruct(String, String);
+
+    let s = TestStruct("abc".to_owned(), "def".to_owned());
+    assert_eq!(
+        s.validate_with_context(&Context::new())
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["The name is too long."],
+            "items": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_nested_struct_is_err() {
+    #[derive(Validate)]
+    #[validate(context = "Context")]
+    struct TestInnerStruct {
+        #[validate(custom(not_reserved), context)]
+        name: String,
+    }
+
+    #[derive(Validate)]
+    struct TestPlainStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    #[derive(Validate)]
+    #[validate(context = "Context")]
+    enum TestEnum {
+        Named {
+            #[validate]
+            inner: TestInnerStruct,
+            #[validate]
+            plain: TestPlainStruct,
+        },
+    }
+
+    let s = TestEnum::Named {
+        inner: TestInnerStruct {
+            name: "root".to_owned(),
+        },
+        plain: TestPlainStruct { val: 11 },
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate_with_context(&Context::new()).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "inner": {
+                    "errors": [],
+                    "properties": {
+                        "name": {
+                            "errors": ["`root` is reserved."]
+                        }
+                    }
+                },
+                "plain": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["The number must be `<= 10`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn context_free_type_validate_with_context_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 5 };
+    assert!(s.validate_with_context(&Context::new()).is_ok());
+    assert!(vec![s].validate_with_context(&()).is_ok());
+}
+
+#[test]
+fn context_nested_containers_is_err() {
+    #[derive(Validate)]
+    #[validate(context = "Context")]
+    struct TestChild {
+        #[validate(custom(not_reserved), context)]
+        name: String,
+    }
+
+    #[derive(Validate)]
+    #[validate(context = "Context")]
+    struct TestStruct {
+        #[validate]
+        children: Vec<TestChild>,
+        #[validate]
+        owner: Option<TestChild>,
+        #[validate]
+        aliases: std::collections::BTreeMap<String, TestChild>,
+    }
+
+    let child = |name: &str| TestChild {
+        name: name.to_owned(),
+    };
+
+    let s = TestStruct {
+        children: vec![child("alice"), child("root")],
+        owner: Some(child("admin")),
+        aliases: [("bob".to_owned(), child("bob"))].into_iter().collect(),
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate_with_context(&Context::new()).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "children": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "name": {
+                                    "errors": ["`root` is reserved."]
+                                }
+                            }
+                        }
+                    }
+                },
+                "owner": {
+                    "errors": [],
+                    "properties": {
+                        "name": {
+                            "errors": ["`admin` is reserved."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let s = TestStruct {
+        children: vec![child("alice")],
+        owner: None,
+        aliases: [("bob".to_owned(), child("bob"))].into_iter().collect(),
+    };
+    assert!(s.validate_with_context(&Context::new()).is_ok());
+}
+
+#[test]
+fn context_manual_validate_nested_is_err() {
+    #[derive(serde::Deserialize)]
+    struct Code(String);
+
+    impl Validate for Code {
+        fn validate(&self) -> Result<(), serde_valid::validation::Errors> {
+            if self.0.chars().all(|c| c.is_ascii_digit()) {
+                Ok(())
+            } else {
+                Err(serde_valid::validation::Errors::NewType(vec![
+                    serde_valid::validation::Error::Custom("The code must be digits.".to_owned()),
+                ]))
+            }
+        }
+    }
+
+    #[derive(serde::Deserialize, Validate)]
+    #[validate(context = "Context")]
+    struct TestStruct {
+        #[validate(custom(not_reserved), context)]
+        name: String,
+        #[validate]
+        code: serde_valid::WithoutContext<Code>,
+        #[validate]
+        codes: Vec<serde_valid::WithoutContext<Code>>,
+    }
+
+    let s: TestStruct = serde_json::from_value(json!({
+        "name": "admin",
+        "code": "12a",
+        "codes": ["1", "x"]
+    }))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(s.validate_with_context(&Context::new()).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": { "errors": ["`admin` is reserved."] },
+                "code": { "errors": ["The code must be digits."] },
+                "codes": {
+                    "errors": [],
+                    "items": { "1": { "errors": ["The code must be digits."] } }
+                }
+            }
+        })
+    );
+}
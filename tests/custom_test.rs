@@ -71,3 +71,223 @@ fn custom_validation_error() {
         .to_string()
     );
 }
+
+fn in_range(val: &i32, min: i32, max: i32) -> Result<(), serde_valid::validation::Error> {
+    if (min..=max).contains(val) {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(format!(
+            "The value must be in {min}..={max}."
+        )))
+    }
+}
+
+#[test]
+fn custom_validation_with_arguments_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(in_range(1, 10)))]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 10 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn custom_validation_with_arguments_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(in_range(1, 10)))]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 11 };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value must be in 1..=10."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn custom_validation_with_path_arguments_is_err() {
+    const MIN: i32 = 0;
+    const MAX: i32 = 5;
+
+    fn has_prefix(val: &str, prefix: &str) -> Result<(), serde_valid::validation::Error> {
+        if val.starts_with(prefix) {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(format!(
+                "The value must start with `{prefix}`."
+            )))
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(in_range(MIN, MAX)))]
+        val: i32,
+        #[validate(custom(has_prefix("id-")))]
+        id: String,
+    }
+
+    let s = TestStruct {
+        val: 6,
+        id: "xx-1".to_owned(),
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value must be in 0..=5."]
+                },
+                "id": {
+                    "errors": ["The value must start with `id-`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn custom_validation_with_expression_arguments_is_err() {
+    const MAX: i32 = 6;
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(in_range(1 + 1, MAX - 1)))]
+        val: i32,
+        #[validate(custom(in_range(i32::MIN, -(1 << 2))))]
+        negative: i32,
+    }
+
+    assert!(TestStruct {
+        val: 2,
+        negative: -4
+    }
+    .validate()
+    .is_ok());
+
+    let s = TestStruct {
+        val: 6,
+        negative: 0,
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value must be in 2..=5."]
+                },
+                "negative": {
+                    "errors": [format!("The value must be in {}..=-4.", i32::MIN)]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn custom_validation_vec_errors_is_err() {
+    fn all_positive(vals: &[i32]) -> Result<(), Vec<serde_valid::validation::Error>> {
+        let errors = vals
+            .iter()
+            .filter(|val| **val <= 0)
+            .map(|val| serde_valid::validation::Error::Custom(format!("`{val}` is not positive.")))
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(all_positive))]
+        vals: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        vals: vec![-1, 1, 0],
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vals": {
+                    "errors": ["`-1` is not positive.", "`0` is not positive."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn custom_validation_errors_is_err() {
+    use serde_valid::validation::{ArrayErrors, Error, Errors, ItemErrorsMap};
+
+    fn all_positive(vals: &[i32]) -> Result<(), Errors> {
+        let items = vals
+            .iter()
+            .enumerate()
+            .filter(|(_, val)| **val <= 0)
+            .map(|(index, _)| {
+                (
+                    index,
+                    Errors::NewType(vec![Error::Custom("The value is not positive.".to_owned())]),
+                )
+            })
+            .collect::<ItemErrorsMap>();
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(Errors::Array(ArrayErrors::new(vec![], items)))
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(all_positive))]
+        #[validate(max_items = 2)]
+        vals: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        vals: vec![-1, 1, 0],
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vals": {
+                    "errors": ["The length of the items must be `<= 2`."],
+                    "items": {
+                        "0": {
+                            "errors": ["The value is not positive."]
+                        },
+                        "2": {
+                            "errors": ["The value is not positive."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
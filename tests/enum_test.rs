@@ -200,3 +200,96 @@ fn enum_newtype_variant_validation_is_err() {
         .to_string()
     );
 }
+
+#[test]
+fn enum_unit_variant_without_rule_is_ok() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Unit,
+        NewType(#[validate(minimum = 5)] u32),
+    }
+
+    assert!(TestEnum::Unit.validate().is_ok());
+    assert!(TestEnum::NewType(5).validate().is_ok());
+}
+
+#[test]
+fn enum_unit_variant_rule_is_err() {
+    fn not_allowed() -> Result<(), serde_valid::validation::Error> {
+        Err(serde_valid::validation::Error::Custom(
+            "`Legacy` is not allowed after 2025.".to_owned(),
+        ))
+    }
+
+    #[derive(Validate)]
+    enum Plan {
+        Free,
+        #[rule(not_allowed())]
+        Legacy,
+    }
+
+    assert!(Plan::Free.validate().is_ok());
+    assert_eq!(
+        Plan::Legacy.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["`Legacy` is not allowed after 2025."]
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn enum_rule_is_err() {
+    #[derive(Validate)]
+    #[rule(max_total_rule(self))]
+    enum Range {
+        Single(#[validate(maximum = 100)] u32),
+        Pair { first: u32, second: u32 },
+        Empty,
+    }
+
+    fn max_total_rule(range: &Range) -> Result<(), serde_valid::validation::Error> {
+        let total = match range {
+            Range::Single(value) => *value,
+            Range::Pair { first, second } => first + second,
+            Range::Empty => 0,
+        };
+        if total > 50 {
+            Err(serde_valid::validation::Error::Custom(
+                "The total must be `<= 50`.".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    assert!(Range::Empty.validate().is_ok());
+    assert!(Range::Pair {
+        first: 20,
+        second: 30
+    }
+    .validate()
+    .is_ok());
+
+    assert_eq!(
+        Range::Pair {
+            first: 30,
+            second: 30
+        }
+        .validate()
+        .unwrap_err()
+        .to_string(),
+        json!({
+            "errors": ["The total must be `<= 50`."]
+        })
+        .to_string()
+    );
+
+    assert_eq!(
+        Range::Single(101).validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["The number must be `<= 100`.", "The total must be `<= 50`."]
+        })
+        .to_string()
+    );
+}
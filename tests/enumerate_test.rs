@@ -251,3 +251,38 @@ fn enumerate_numeric_trait() {
         .to_string()
     );
 }
+
+#[test]
+fn enumerate_custom_err_message_fn_with_value() {
+    fn error_message(params: &serde_valid::error::EnumerateErrorParams) -> String {
+        format!("got {}.", params.value)
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(enumerate(1, 2, 3), message_fn(error_message))]
+        num: i32,
+        #[validate(enumerate("a", "b"), message_fn(error_message))]
+        name: String,
+    }
+
+    let s = TestStruct {
+        num: 4,
+        name: String::from("c"),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "num": {
+                    "errors": ["got 4."]
+                },
+                "name": {
+                    "errors": ["got c."]
+                }
+            }
+        })
+    );
+}
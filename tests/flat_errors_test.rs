@@ -0,0 +1,127 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn flat_errors_nested_struct() {
+    #[derive(Validate)]
+    struct TestItem {
+        #[validate(min_length = 1)]
+        name: String,
+    }
+
+    #[derive(Validate)]
+    #[rule(sample_rule(count))]
+    struct TestStruct {
+        count: i32,
+        #[validate]
+        #[validate(max_items = 2)]
+        items: Vec<TestItem>,
+    }
+
+    fn sample_rule(count: &i32) -> Result<(), serde_valid::validation::Error> {
+        if *count >= 0 {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "count must be positive.".to_owned(),
+            ))
+        }
+    }
+
+    let s = TestStruct {
+        count: -1,
+        items: vec![
+            TestItem {
+                name: "a".to_owned(),
+            },
+            TestItem {
+                name: "".to_owned(),
+            },
+            TestItem {
+                name: "".to_owned(),
+            },
+        ],
+    };
+
+    let flat_errors = s.validate().unwrap_err().flatten();
+
+    assert_eq!(
+        flat_errors
+            .iter()
+            .map(|flat_error| flat_error.path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["", "/items", "/items/1/name", "/items/2/name"]
+    );
+    assert_eq!(
+        serde_json::to_value(flat_errors).unwrap(),
+        json!([
+            {
+                "path": "",
+                "message": "count must be positive."
+            },
+            {
+                "path": "/items",
+                "message": "The length of the items must be `<= 2`."
+            },
+            {
+                "path": "/items/1/name",
+                "message": "The length of the value must be `>= 1`."
+            },
+            {
+                "path": "/items/2/name",
+                "message": "The length of the value must be `>= 1`."
+            }
+        ])
+    );
+}
+
+#[test]
+fn flat_errors_composited_array() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 5)]
+        vals: Vec<Vec<i32>>,
+    }
+
+    let s = TestStruct {
+        vals: vec![vec![1, 6], vec![7]],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().flatten()).unwrap(),
+        json!([
+            {
+                "path": "/vals/0/1",
+                "message": "The number must be `<= 5`."
+            },
+            {
+                "path": "/vals/1/0",
+                "message": "The number must be `<= 5`."
+            }
+        ])
+    );
+}
+
+#[test]
+fn flat_errors_unnamed_struct() {
+    #[derive(Validate)]
+    struct TestStruct(
+        #[validate(maximum = 5)] i32,
+        #[validate(pattern = "^a")] String,
+    );
+
+    let s = TestStruct(6, "b/c".to_owned());
+
+    assert_eq!(
+        s.validate()
+            .unwrap_err()
+            .flatten()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![
+            "/0: The number must be `<= 5`.",
+            "/1: The value must match the pattern of \"^a\".",
+        ]
+    );
+}
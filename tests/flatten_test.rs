@@ -0,0 +1,129 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::FromJsonValue;
+use serde_valid::Validate;
+
+#[test]
+fn flatten_is_ok() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestInnerStruct {
+        #[validate(maximum = 100)]
+        val: i32,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        name: String,
+        #[validate]
+        #[serde(flatten)]
+        inner: TestInnerStruct,
+    }
+
+    let s = TestStruct::from_json_value(json!({ "name": "serde_valid", "val": 50 }));
+
+    assert!(s.is_ok())
+}
+
+#[test]
+fn flatten_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TestInnerStruct {
+        #[validate(maximum = 100)]
+        inner_val: i32,
+        #[validate(max_items = 1)]
+        inner_items: Vec<i32>,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        name: String,
+        #[validate]
+        #[serde(flatten)]
+        inner: TestInnerStruct,
+    }
+
+    let err = TestStruct::from_json_value(json!({
+        "name": "",
+        "innerVal": 123,
+        "innerItems": [1, 2]
+    }))
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["The length of the value must be `>= 1`."]
+                },
+                "innerVal": {
+                    "errors": ["The number must be `<= 100`."]
+                },
+                "innerItems": {
+                    "errors": ["The length of the items must be `<= 1`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn flatten_nested_struct_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestNestedStruct {
+        #[validate(maximum = 100)]
+        val: i32,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    #[rule(sample_rule(val1))]
+    struct TestInnerStruct {
+        val1: i32,
+        #[validate]
+        nested: TestNestedStruct,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate]
+        #[serde(flatten)]
+        inner: TestInnerStruct,
+    }
+
+    fn sample_rule(val1: &i32) -> Result<(), serde_valid::validation::Error> {
+        if *val1 > 0 {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "val1 must be positive.".to_owned(),
+            ))
+        }
+    }
+
+    let err = TestStruct::from_json_value(json!({
+        "val1": 0,
+        "nested": { "val": 123 }
+    }))
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": ["val1 must be positive."],
+            "properties": {
+                "nested": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["The number must be `<= 100`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
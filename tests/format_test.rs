@@ -0,0 +1,375 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateFormat};
+use std::borrow::Cow;
+
+#[test]
+fn format_string_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("joe.bloggs@example.com"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(format = "date-time")]
+        val: &'a str,
+    }
+
+    let s = TestStruct {
+        val: "2020-09-10T08:30:06Z",
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_cow_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(format = "uuid")]
+        val: Cow<'a, str>,
+    }
+
+    let s = TestStruct {
+        val: Cow::from("2eb8aa08-aa98-11ea-b4aa-73b441d16380"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_builtin_formats_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        email: String,
+        #[validate(format = "uri")]
+        uri: String,
+        #[validate(format = "uri-reference")]
+        uri_reference: String,
+        #[validate(format = "hostname")]
+        hostname: String,
+        #[validate(format = "ipv4")]
+        ipv4: String,
+        #[validate(format = "ipv6")]
+        ipv6: String,
+        #[validate(format = "uuid")]
+        uuid: String,
+        #[validate(format = "date")]
+        date: String,
+        #[validate(format = "time")]
+        time: String,
+        #[validate(format = "date-time")]
+        date_time: String,
+        #[validate(format = "duration")]
+        duration: String,
+        #[validate(format = "json-pointer")]
+        json_pointer: String,
+        #[validate(format = "regex")]
+        regex: String,
+    }
+
+    let s = TestStruct {
+        email: String::from("joe@example.com"),
+        uri: String::from("https://example.com/path?query=1"),
+        uri_reference: String::from("../path"),
+        hostname: String::from("www.example.com"),
+        ipv4: String::from("192.168.0.1"),
+        ipv6: String::from("::1"),
+        uuid: String::from("2eb8aa08-aa98-11ea-b4aa-73b441d16380"),
+        date: String::from("2020-02-29"),
+        time: String::from("08:30:06+09:00"),
+        date_time: String::from("2020-09-10T08:30:06.123Z"),
+        duration: String::from("P1DT12H"),
+        json_pointer: String::from("/foo/0"),
+        regex: String::from(r"^\d+$"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "date")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("2021-02-29"),
+    };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn format_vec_type_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "ipv4")]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec![String::from("192.168.0.1"), String::from("192.168.0.256")],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["The value must match the format of \"ipv4\"."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_option_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "hostname")]
+        val: Option<String>,
+    }
+
+    let s = TestStruct { val: None };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("joe.example.com"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value must match the format of \"email\"."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_custom_err_message_fn() {
+    fn error_message(params: &serde_valid::FormatErrorParams) -> String {
+        format!("The value is not {}.", params.format)
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email", message_fn(error_message))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("joe.example.com"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value is not email."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "uuid", message = "The value is not {format}.")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("abc"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value is not uuid."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_structured_error() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "date")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("2020/09/10"),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().structured()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        {
+                            "code": "format",
+                            "params": { "format": "date" },
+                            "message": "The value must match the format of \"date\"."
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn format_trait() {
+    struct MyType(String);
+
+    impl ValidateFormat for MyType {
+        fn validate_format(&self, format: &str) -> Result<(), serde_valid::FormatErrorParams> {
+            self.0.validate_format(format)
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "ipv6")]
+        val: MyType,
+    }
+
+    let s = TestStruct {
+        val: MyType(String::from("2001:db8::1")),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_registered_format() {
+    serde_valid::format::register("e164-phone", |value| {
+        value.len() <= 16
+            && value
+                .strip_prefix('+')
+                .map(|digits| !digits.is_empty() && digits.chars().all(|ch| ch.is_ascii_digit()))
+                .unwrap_or(false)
+    });
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "e164-phone")]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec![String::from("+819012345678"), String::from("090-1234-5678")],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["The value must match the format of \"e164-phone\"."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_registered_format_trait() {
+    struct Iban;
+
+    impl serde_valid::format::Format for Iban {
+        const NAME: &'static str = "iban";
+
+        fn is_valid(value: &str) -> bool {
+            value.len() >= 15
+                && value[..2].chars().all(|ch| ch.is_ascii_uppercase())
+                && value[2..].chars().all(|ch| ch.is_ascii_alphanumeric())
+        }
+    }
+
+    serde_valid::format::register_format::<Iban>();
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "iban")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("DE89370400440532013000"),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        val: String::from("de89"),
+    };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+#[should_panic(expected = "The format `unregistered` is unknown.")]
+fn format_unregistered_format_panics() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "unregistered")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("abc"),
+    };
+    let _ = s.validate();
+}
@@ -0,0 +1,199 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidationGroup};
+
+struct Create;
+impl ValidationGroup for Create {}
+
+struct Update;
+impl ValidationGroup for Update {}
+
+struct Delete;
+impl ValidationGroup for Delete {}
+
+#[derive(Validate)]
+struct UserDto {
+    #[validate(
+        expr = "id.is_none()",
+        message = "The id is forbidden on create.",
+        groups(Create)
+    )]
+    #[validate(
+        expr = "id.is_some()",
+        message = "The id is required on update.",
+        groups(Update)
+    )]
+    id: Option<u64>,
+    #[validate(min_length = 1)]
+    #[validate(max_length = 4, groups(Create, Update))]
+    name: String,
+}
+
+#[test]
+fn validate_without_group_runs_ungrouped_validations_only() {
+    let s = UserDto {
+        id: Some(1),
+        name: "serde_valid".to_owned(),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn validate_group_is_ok() {
+    let s = UserDto {
+        id: None,
+        name: "abc".to_owned(),
+    };
+    assert!(s.validate_group(Create).is_ok());
+
+    let s = UserDto {
+        id: Some(1),
+        name: "abc".to_owned(),
+    };
+    assert!(s.validate_group(Update).is_ok());
+}
+
+#[test]
+fn validate_group_is_err() {
+    let s = UserDto {
+        id: Some(1),
+        name: "".to_owned(),
+    };
+
+    assert_eq!(
+        s.validate_group(Create).unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "id": {
+                    "errors": ["The id is forbidden on create."]
+                },
+                "name": {
+                    "errors": ["The length of the value must be `>= 1`."]
+                }
+            }
+        })
+        .to_string()
+    );
+
+    let s = UserDto {
+        id: None,
+        name: "serde_valid".to_owned(),
+    };
+
+    assert_eq!(
+        s.validate_group(Update).unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "id": {
+                    "errors": ["The id is required on update."]
+                },
+                "name": {
+                    "errors": ["The length of the value must be `<= 4`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn validate_unknown_group_runs_ungrouped_validations_only() {
+    let s = UserDto {
+        id: Some(1),
+        name: "serde_valid".to_owned(),
+    };
+
+    assert!(s.validate_group(Delete).is_ok());
+}
+
+#[test]
+fn nested_validate_group_is_err() {
+    struct Admin;
+    impl ValidationGroup for Admin {}
+
+    #[derive(Validate)]
+    struct Member {
+        #[validate(maximum = 10, groups(Admin))]
+        level: u32,
+    }
+
+    #[derive(Validate)]
+    struct Team {
+        #[validate]
+        leader: Member,
+        #[validate]
+        members: Vec<Member>,
+        #[validate]
+        deputy: Option<Member>,
+    }
+
+    let s = Team {
+        leader: Member { level: 11 },
+        members: vec![Member { level: 1 }, Member { level: 12 }],
+        deputy: Some(Member { level: 13 }),
+    };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(s.validate_group(Admin).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "leader": {
+                    "errors": [],
+                    "properties": {
+                        "level": { "errors": ["The number must be `<= 10`."] }
+                    }
+                },
+                "members": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "level": { "errors": ["The number must be `<= 10`."] }
+                            }
+                        }
+                    }
+                },
+                "deputy": {
+                    "errors": [],
+                    "properties": {
+                        "level": { "errors": ["The number must be `<= 10`."] }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn enum_validate_group_is_err() {
+    struct Strict;
+    impl ValidationGroup for Strict {}
+
+    #[derive(Validate)]
+    enum Command {
+        Rename {
+            #[validate(min_length = 1, groups(Strict))]
+            name: String,
+        },
+        Remove(#[validate(minimum = 1, groups(Strict))] u32),
+    }
+
+    assert!(Command::Remove(0).validate().is_ok());
+    assert_eq!(
+        Command::Remove(0)
+            .validate_group(Strict)
+            .unwrap_err()
+            .to_string(),
+        json!({ "errors": ["The number must be `>= 1`."] }).to_string()
+    );
+    assert!(Command::Rename {
+        name: "".to_owned()
+    }
+    .validate_group(Strict)
+    .is_err());
+}
@@ -0,0 +1,721 @@
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_valid::schema::SchemaValidator;
+use serde_valid::JsonSchema;
+
+#[test]
+fn json_schema_named_struct() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate(minimum = 0)]
+        #[validate(maximum = 10)]
+        val: i32,
+        #[validate(max_length = 5, message = "custom message.")]
+        #[validate(pattern = r"^\d+$")]
+        code: String,
+        #[validate(multiple_of = 0.5)]
+        ratio: f64,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "val": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 10
+                },
+                "code": {
+                    "type": "string",
+                    "maxLength": 5,
+                    "pattern": r"^\d+$"
+                },
+                "ratio": {
+                    "type": "number",
+                    "multipleOf": 0.5
+                }
+            },
+            "required": ["val", "code", "ratio"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_document() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        val: bool,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema_document(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "val": { "type": "boolean" }
+            },
+            "required": ["val"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_optional_fields() {
+    #[derive(Deserialize, JsonSchema)]
+    struct TestStruct {
+        #[validate(enumerate(1, 2, 3))]
+        val1: Option<i32>,
+        #[serde(default)]
+        val2: i32,
+        val3: i32,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "val1": {
+                    "type": ["integer", "null"],
+                    "enum": [1, 2, 3, null]
+                },
+                "val2": { "type": "integer" },
+                "val3": { "type": "integer" }
+            },
+            "required": ["val3"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_serde_rename() {
+    #[derive(Deserialize, JsonSchema)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        #[serde(rename = "value")]
+        val: i32,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "value": {
+                    "type": "integer",
+                    "maximum": 100
+                }
+            },
+            "required": ["value"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_array_items() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate(max_items = 3)]
+        #[validate(unique_items)]
+        #[validate(max_length = 2)]
+        val: Vec<String>,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "val": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "maxLength": 2
+                    },
+                    "maxItems": 3,
+                    "uniqueItems": true
+                }
+            },
+            "required": ["val"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_nested_struct() {
+    #[derive(JsonSchema)]
+    struct TestInnerStruct {
+        #[validate(min_length = 1)]
+        val: String,
+    }
+
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate]
+        inner: TestInnerStruct,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "inner": {
+                    "type": "object",
+                    "properties": {
+                        "val": {
+                            "type": "string",
+                            "minLength": 1
+                        }
+                    },
+                    "required": ["val"]
+                }
+            },
+            "required": ["inner"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_unnamed_struct() {
+    #[derive(JsonSchema)]
+    struct TestStruct(#[validate(maximum = 4)] u32, String);
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "array",
+            "prefixItems": [
+                { "type": "integer", "maximum": 4 },
+                { "type": "string" }
+            ],
+            "items": false,
+            "minItems": 2,
+            "maxItems": 2
+        })
+    );
+}
+
+#[test]
+fn json_schema_new_type() {
+    #[derive(JsonSchema)]
+    struct TestNewType(#[validate(max_properties = 2)] std::collections::HashMap<String, i32>);
+
+    assert_eq!(
+        TestNewType::json_schema(),
+        json!({
+            "type": "object",
+            "additionalProperties": { "type": "integer" },
+            "maxProperties": 2
+        })
+    );
+}
+
+#[test]
+fn json_schema_enum() {
+    #[derive(Deserialize, JsonSchema)]
+    enum TestEnum {
+        Named {
+            #[validate(exclusive_maximum = 5)]
+            a: i32,
+        },
+        Unnamed(#[validate(exclusive_minimum = 5)] i32, bool),
+        NewType(#[validate(min_items = 1)] Vec<i32>),
+        #[serde(rename = "unit")]
+        Unit,
+    }
+
+    assert_eq!(
+        TestEnum::json_schema(),
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "Named": {
+                            "type": "object",
+                            "properties": {
+                                "a": {
+                                    "type": "integer",
+                                    "exclusiveMaximum": 5
+                                }
+                            },
+                            "required": ["a"]
+                        }
+                    },
+                    "required": ["Named"],
+                    "additionalProperties": false
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Unnamed": {
+                            "type": "array",
+                            "prefixItems": [
+                                { "type": "integer", "exclusiveMinimum": 5 },
+                                { "type": "boolean" }
+                            ],
+                            "items": false,
+                            "minItems": 2,
+                            "maxItems": 2
+                        }
+                    },
+                    "required": ["Unnamed"],
+                    "additionalProperties": false
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "NewType": {
+                            "type": "array",
+                            "items": { "type": "integer" },
+                            "minItems": 1
+                        }
+                    },
+                    "required": ["NewType"],
+                    "additionalProperties": false
+                },
+                { "const": "unit" }
+            ]
+        })
+    );
+}
+
+#[test]
+fn json_schema_internally_tagged_enum() {
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    #[serde(tag = "type")]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 5)]
+            a: i32,
+        },
+        Unit,
+    }
+
+    let schema = TestEnum::json_schema();
+    assert_eq!(
+        schema,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "a": { "type": "integer", "maximum": 5 },
+                        "type": { "const": "Named" }
+                    },
+                    "required": ["a", "type"]
+                },
+                {
+                    "type": "object",
+                    "properties": { "type": { "const": "Unit" } },
+                    "required": ["type"]
+                }
+            ]
+        })
+    );
+
+    let validator = SchemaValidator::compile(&schema).unwrap();
+    for value in [TestEnum::Named { a: 1 }, TestEnum::Unit] {
+        assert!(validator.is_valid(&serde_json::to_value(value).unwrap()));
+    }
+    assert!(!validator.is_valid(&json!({ "Named": { "a": 1 } })));
+}
+
+#[test]
+fn json_schema_adjacently_tagged_enum() {
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    #[serde(tag = "t", content = "c")]
+    enum TestEnum {
+        NewType(#[validate(min_length = 1)] String),
+        Unit,
+    }
+
+    let schema = TestEnum::json_schema();
+    assert_eq!(
+        schema,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "t": { "const": "NewType" },
+                        "c": { "type": "string", "minLength": 1 }
+                    },
+                    "required": ["t", "c"]
+                },
+                {
+                    "type": "object",
+                    "properties": { "t": { "const": "Unit" } },
+                    "required": ["t"]
+                }
+            ]
+        })
+    );
+
+    let validator = SchemaValidator::compile(&schema).unwrap();
+    for value in [TestEnum::NewType("a".to_owned()), TestEnum::Unit] {
+        assert!(validator.is_valid(&serde_json::to_value(value).unwrap()));
+    }
+    assert!(!validator.is_valid(&json!({ "t": "NewType", "c": "" })));
+}
+
+#[test]
+fn json_schema_untagged_enum() {
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    #[serde(untagged)]
+    enum TestEnum {
+        Short { a: i32 },
+        Long { a: i32, b: i32 },
+        Unit,
+    }
+
+    let schema = TestEnum::json_schema();
+    assert_eq!(
+        schema,
+        json!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": { "a": { "type": "integer" } },
+                    "required": ["a"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "a": { "type": "integer" },
+                        "b": { "type": "integer" }
+                    },
+                    "required": ["a", "b"]
+                },
+                { "type": "null" }
+            ]
+        })
+    );
+
+    let validator = SchemaValidator::compile(&schema).unwrap();
+    for value in [
+        TestEnum::Short { a: 1 },
+        TestEnum::Long { a: 1, b: 2 },
+        TestEnum::Unit,
+    ] {
+        assert!(validator.is_valid(&serde_json::to_value(value).unwrap()));
+    }
+    assert!(!validator.is_valid(&json!("Unit")));
+}
+
+#[test]
+fn json_schema_serde_skip_and_container_default() {
+    #[derive(Deserialize, JsonSchema)]
+    struct TestStruct {
+        val: i32,
+        #[serde(skip)]
+        skipped: i32,
+        #[serde(skip_deserializing)]
+        skipped_deserializing: i32,
+    }
+
+    #[derive(Default, Deserialize, JsonSchema)]
+    #[serde(default)]
+    struct TestDefaultStruct {
+        val: i32,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": { "val": { "type": "integer" } },
+            "required": ["val"]
+        })
+    );
+    assert_eq!(
+        TestDefaultStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": { "val": { "type": "integer" } }
+        })
+    );
+}
+
+#[test]
+fn json_schema_user_option_type_is_required() {
+    mod user {
+        #[derive(serde::Deserialize)]
+        pub struct Option(pub i32);
+
+        impl serde_valid::JsonSchema for Option {
+            fn json_schema() -> serde_json::Value {
+                serde_json::json!({ "type": "integer" })
+            }
+        }
+    }
+
+    #[derive(Deserialize, JsonSchema)]
+    struct TestStruct {
+        val: user::Option,
+        std_val: std::option::Option<i32>,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "val": { "type": "integer" },
+                "std_val": { "type": ["integer", "null"] }
+            },
+            "required": ["val"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_serde_rename_all() {
+    #[derive(Deserialize, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    enum TestEnum {
+        #[serde(rename_all = "camelCase")]
+        NamedVariant {
+            first_value: i32,
+        },
+        UnitVariant,
+    }
+
+    assert_eq!(
+        TestEnum::json_schema(),
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "named_variant": {
+                            "type": "object",
+                            "properties": {
+                                "firstValue": { "type": "integer" }
+                            },
+                            "required": ["firstValue"]
+                        }
+                    },
+                    "required": ["named_variant"],
+                    "additionalProperties": false
+                },
+                { "const": "unit_variant" }
+            ]
+        })
+    );
+}
+
+#[test]
+fn json_schema_serde_flatten() {
+    #[derive(Deserialize, JsonSchema)]
+    struct TestInnerStruct {
+        #[validate(maximum = 100)]
+        val: i32,
+        opt: Option<bool>,
+    }
+
+    #[derive(Deserialize, JsonSchema)]
+    struct TestStruct {
+        name: String,
+        #[serde(flatten)]
+        inner: TestInnerStruct,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "val": { "type": "integer", "maximum": 100 },
+                "opt": { "type": ["boolean", "null"] }
+            },
+            "required": ["name", "val"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_when() {
+    fn is_shipping(order: &Order) -> bool {
+        order.shipping
+    }
+
+    #[derive(JsonSchema)]
+    struct Order {
+        shipping: bool,
+        country: String,
+        #[validate(pattern = r"^DE\d{9}$", when = "self.country == \"DE\"")]
+        vat_number: String,
+        #[validate(min_length = 1, when = is_shipping)]
+        address: String,
+    }
+
+    assert_eq!(
+        Order::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "shipping": { "type": "boolean" },
+                "country": { "type": "string" },
+                "vat_number": { "type": "string" },
+                "address": { "type": "string" }
+            },
+            "required": ["shipping", "country", "vat_number", "address"],
+            "allOf": [
+                {
+                    "if": {
+                        "properties": { "country": { "const": "DE" } },
+                        "required": ["country"]
+                    },
+                    "then": {
+                        "properties": {
+                            "vat_number": { "type": "string", "pattern": r"^DE\d{9}$" }
+                        }
+                    }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn json_schema_map_keys_and_values() {
+    #[derive(JsonSchema)]
+    struct Config {
+        #[validate(keys(pattern = r"^[a-z_]+$"))]
+        #[validate(values(max_length = 64))]
+        envs: std::collections::HashMap<String, String>,
+    }
+
+    assert_eq!(
+        Config::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "envs": {
+                    "type": "object",
+                    "propertyNames": { "pattern": r"^[a-z_]+$" },
+                    "additionalProperties": { "type": "string", "maxLength": 64 }
+                }
+            },
+            "required": ["envs"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_format() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        email: String,
+        #[validate(format = "date")]
+        birthdays: Vec<String>,
+        #[validate(format = "iban")]
+        iban: String,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "email": { "type": "string", "format": "email" },
+                "birthdays": {
+                    "type": "array",
+                    "items": { "type": "string", "format": "date" }
+                },
+                "iban": { "type": "string", "format": "iban" }
+            },
+            "required": ["email", "birthdays", "iban"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_contains() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate(contains(enumerate("admin")))]
+        roles: Vec<String>,
+        #[validate(contains(minimum = 10, min_contains = 2, max_contains = 3))]
+        vals: Vec<i32>,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "roles": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "contains": { "enum": ["admin"] }
+                },
+                "vals": {
+                    "type": "array",
+                    "items": { "type": "integer" },
+                    "contains": { "minimum": 10 },
+                    "minContains": 2,
+                    "maxContains": 3
+                }
+            },
+            "required": ["roles", "vals"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_const_value() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate(const_value = "v1")]
+        api_version: String,
+        #[validate(const_value = 1)]
+        revision: u8,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "api_version": { "type": "string", "const": "v1" },
+                "revision": { "type": "integer", "const": 1 }
+            },
+            "required": ["api_version", "revision"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_optional_const_value() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate(const_value = "v1")]
+        api_version: Option<String>,
+    }
+
+    let schema = TestStruct::json_schema();
+    assert_eq!(
+        schema,
+        json!({
+            "type": "object",
+            "properties": {
+                "api_version": { "type": ["string", "null"], "enum": ["v1", null] }
+            }
+        })
+    );
+
+    let validator = SchemaValidator::compile(&schema).unwrap();
+    assert!(validator.is_valid(&json!({ "api_version": null })));
+    assert!(validator.is_valid(&json!({ "api_version": "v1" })));
+    assert!(!validator.is_valid(&json!({ "api_version": "v2" })));
+}
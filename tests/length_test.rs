@@ -371,3 +371,36 @@ fn length_vec_err_message() {
         .to_string()
     );
 }
+
+#[test]
+fn length_custom_err_message_fn_with_length() {
+    fn custom_max_error_message(params: &serde_valid::MaxLengthErrorParams) -> String {
+        format!(
+            "got length {}, expected <= {}.",
+            params.length, params.max_length
+        )
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 3, message_fn(custom_max_error_message))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("a̐éö̲x"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["got length 4, expected <= 3."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
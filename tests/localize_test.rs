@@ -0,0 +1,114 @@
+use serde_json::json;
+use serde_valid::error::{EnglishMessageCatalog, JsonMessageCatalog};
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestInnerStruct {
+    #[validate(min_length = 2)]
+    name: String,
+}
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(maximum = 10)]
+    #[validate(multiple_of = 3, message = "custom message.")]
+    val: i32,
+    #[validate(minimum = 0)]
+    #[validate(unique_items)]
+    vals: Vec<i32>,
+    #[validate(enumerate("a", "b"))]
+    kind: String,
+    #[validate]
+    inner: TestInnerStruct,
+}
+
+fn invalid_struct() -> TestStruct {
+    TestStruct {
+        val: 11,
+        vals: vec![-1, 2, 2],
+        kind: "c".to_owned(),
+        inner: TestInnerStruct {
+            name: "a".to_owned(),
+        },
+    }
+}
+
+#[test]
+fn localize_json_catalog() {
+    let catalog: JsonMessageCatalog = r#"{
+        "maximum": "Le nombre doit être `<= {maximum}`, mais {value}.",
+        "minimum": "Le nombre doit être `>= {minimum}`.",
+        "multiple_of": "Le nombre doit être multiple de `{multiple_of}`.",
+        "unique_items": "Les éléments doivent être uniques : {duplicates}.",
+        "enumerate": "La valeur doit être dans [{enumerate}]."
+    }"#
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(invalid_struct().validate().unwrap_err().localize(&catalog)).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "Le nombre doit être `<= 10`, mais 11.",
+                        "custom message."
+                    ]
+                },
+                "vals": {
+                    "errors": ["Les éléments doivent être uniques : 2."],
+                    "items": {
+                        "0": {
+                            "errors": ["Le nombre doit être `>= 0`."]
+                        }
+                    }
+                },
+                "kind": {
+                    "errors": ["La valeur doit être dans [a, b]."]
+                },
+                "inner": {
+                    "errors": [],
+                    "properties": {
+                        "name": {
+                            "errors": ["The length of the value must be `>= 2`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn localize_english_catalog_is_same_as_default_messages() {
+    let errors = invalid_struct().validate().unwrap_err();
+
+    assert_eq!(
+        errors.localize(&EnglishMessageCatalog).to_string(),
+        errors.to_string()
+    );
+}
+
+#[test]
+fn localize_json_catalog_from_value() {
+    #[derive(Validate)]
+    struct TestStruct(#[validate(max_length = 3)] String);
+
+    let catalog = JsonMessageCatalog::from_value(json!({
+        "max_length": "{length}文字です。{max_length}文字以下にしてください。"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        TestStruct("abcd".to_owned())
+            .validate()
+            .unwrap_err()
+            .localize(&catalog)
+            .to_string(),
+        json!({
+            "errors": ["4文字です。3文字以下にしてください。"]
+        })
+        .to_string()
+    );
+}
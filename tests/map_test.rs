@@ -0,0 +1,115 @@
+use std::collections::{BTreeMap, HashMap};
+
+use indexmap::IndexMap;
+use serde_json::json;
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct ServiceConfig {
+    #[validate(minimum = 1)]
+    #[validate(maximum = 65535)]
+    port: u32,
+}
+
+#[test]
+fn hash_map_validate_is_ok() {
+    let configs = HashMap::from([
+        ("api".to_owned(), ServiceConfig { port: 8080 }),
+        ("web".to_owned(), ServiceConfig { port: 80 }),
+    ]);
+
+    assert!(configs.validate().is_ok());
+}
+
+#[test]
+fn hash_map_validate_is_err() {
+    let configs = HashMap::from([
+        ("api".to_owned(), ServiceConfig { port: 8080 }),
+        ("web".to_owned(), ServiceConfig { port: 0 }),
+    ]);
+
+    assert_eq!(
+        configs.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "web": {
+                    "errors": [],
+                    "properties": {
+                        "port": {
+                            "errors": ["The number must be `>= 1`."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn btree_map_validate_is_err() {
+    let configs = BTreeMap::from([
+        (1, ServiceConfig { port: 0 }),
+        (2, ServiceConfig { port: 8080 }),
+        (3, ServiceConfig { port: 65536 }),
+    ]);
+
+    assert_eq!(
+        serde_json::to_value(configs.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "1": {
+                    "errors": [],
+                    "properties": {
+                        "port": { "errors": ["The number must be `>= 1`."] }
+                    }
+                },
+                "3": {
+                    "errors": [],
+                    "properties": {
+                        "port": { "errors": ["The number must be `<= 65535`."] }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn index_map_field_validate_is_err() {
+    #[derive(Validate)]
+    struct Config {
+        #[validate]
+        services: IndexMap<std::borrow::Cow<'static, str>, ServiceConfig>,
+    }
+
+    let s = Config {
+        services: IndexMap::from([
+            ("api".into(), ServiceConfig { port: 8080 }),
+            ("worker".into(), ServiceConfig { port: 0 }),
+        ]),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "services": {
+                    "errors": [],
+                    "properties": {
+                        "worker": {
+                            "errors": [],
+                            "properties": {
+                                "port": { "errors": ["The number must be `>= 1`."] }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
@@ -0,0 +1,142 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn message_template_numeric() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100, message = "got {value}, expected <= {maximum}.")]
+        #[validate(multiple_of = 5, message = "{value} is not multiple of {multiple_of}.")]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 123 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "got 123, expected <= 100.",
+                        "123 is not multiple of 5."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn message_template_length() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            max_length = 3,
+            message = "must be at most {max_length} characters, but {length}."
+        )]
+        name: String,
+        #[validate(min_items = 2, message = "need {min_items} items or more.")]
+        tags: Vec<String>,
+    }
+
+    let s = TestStruct {
+        name: "abcd".to_owned(),
+        tags: vec!["a".to_owned()],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["must be at most 3 characters, but 4."]
+                },
+                "tags": {
+                    "errors": ["need 2 items or more."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_template_list_params() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(enumerate("a", "b"), message = "{value} is not one of {enumerate}.")]
+        kind: String,
+        #[validate(unique_items, message = "duplicated: {duplicates}.")]
+        vals: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        kind: "c".to_owned(),
+        vals: vec![1, 2, 1, 2, 3],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "kind": {
+                    "errors": ["c is not one of a, b."]
+                },
+                "vals": {
+                    "errors": ["duplicated: 1, 2."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_template_escaped_braces() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern = r"^\d+$", message = "{{{pattern}}} must match.")]
+        code: String,
+        #[validate(minimum = 0.0, message = "{{no placeholder}}")]
+        val: f64,
+    }
+
+    let s = TestStruct {
+        code: "abc".to_owned(),
+        val: -0.5,
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "code": {
+                    "errors": [r"{^\d+$} must match."]
+                },
+                "val": {
+                    "errors": ["{no placeholder}"]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_template_format_spec() {
+    #[derive(Validate)]
+    struct TestStruct(#[validate(maximum = 1.0, message = "got {value:.2}.")] f64);
+
+    let s = TestStruct(1.23456);
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["got 1.23."]
+        })
+        .to_string()
+    );
+}
@@ -245,3 +245,131 @@ fn properties_custom_err_message() {
         .to_string()
     );
 }
+
+#[test]
+fn properties_custom_err_message_fn_with_size() {
+    fn max_custom_error_message(params: &serde_valid::MaxPropertiesErrorParams) -> String {
+        format!(
+            "got {} properties, expected <= {}.",
+            params.size, params.max_properties
+        )
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_properties = 1, message_fn(max_custom_error_message))]
+        val: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let s = TestStruct {
+        val: serde_json::from_value(json!({"key1": "value1", "key2": "value2"})).unwrap(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["got 2 properties, expected <= 1."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn properties_keys_and_values_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(keys(pattern = r"^[a-z_]+$"))]
+        #[validate(values(max_length = 8))]
+        val: BTreeMap<String, String>,
+    }
+
+    let s = TestStruct {
+        val: BTreeMap::from([
+            ("api_key".to_owned(), "secret".to_owned()),
+            ("host".to_owned(), "local".to_owned()),
+        ]),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn properties_keys_and_values_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(keys(pattern = r"^[a-z_]+$", message = "The key is invalid."))]
+        #[validate(values(max_length = 8))]
+        val: BTreeMap<String, String>,
+    }
+
+    let s = TestStruct {
+        val: BTreeMap::from([
+            ("Host".to_owned(), "local".to_owned()),
+            ("password".to_owned(), "too long secret".to_owned()),
+            ("PORT".to_owned(), "123456789".to_owned()),
+        ]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "properties": {
+                        "Host": {
+                            "errors": ["The key is invalid."]
+                        },
+                        "PORT": {
+                            "errors": [
+                                "The key is invalid.",
+                                "The length of the value must be `<= 8`."
+                            ]
+                        },
+                        "password": {
+                            "errors": ["The length of the value must be `<= 8`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn properties_values_composited_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(values(maximum = 10))]
+        val: HashMap<String, Vec<i32>>,
+    }
+
+    let s = TestStruct {
+        val: HashMap::from([("scores".to_owned(), vec![1, 11])]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "properties": {
+                        "scores": {
+                            "errors": [],
+                            "items": {
+                                "1": { "errors": ["The number must be `<= 10`."] }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
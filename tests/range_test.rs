@@ -346,3 +346,31 @@ fn range_custom_err_message() {
         .to_string()
     );
 }
+
+#[test]
+fn range_custom_err_message_fn_with_value() {
+    fn custom_max_error_message(params: &serde_valid::MaximumErrorParams) -> String {
+        format!("got {}, expected <= {}.", params.value, params.maximum)
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100, message_fn(custom_max_error_message))]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 123 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["got 123, expected <= 100."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
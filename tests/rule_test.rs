@@ -112,3 +112,196 @@ fn rule_enum_is_ok() {
     let s4 = TestEnum::NoField;
     assert!(s4.validate().is_ok());
 }
+
+fn password_confirmed(
+    password: &str,
+    confirm_password: &str,
+) -> Result<(), serde_valid::validation::Error> {
+    if password == confirm_password {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(
+            "The passwords do not match.".to_owned(),
+        ))
+    }
+}
+
+#[test]
+fn rule_target_named_field_is_err() {
+    #[derive(Validate)]
+    #[rule(password_confirmed(password, confirm_password), target = confirm_password)]
+    struct TestStruct {
+        password: String,
+        #[validate(min_length = 8)]
+        confirm_password: String,
+    }
+
+    let s = TestStruct {
+        password: "password".to_owned(),
+        confirm_password: "passw0rd".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "confirm_password": {
+                    "errors": ["The passwords do not match."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_target_serde_renamed_field_is_err() {
+    #[derive(Validate, serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[rule(
+        password_confirmed(password, confirm_password),
+        target = "confirm_password"
+    )]
+    struct TestStruct {
+        password: String,
+        confirm_password: String,
+    }
+
+    let s = TestStruct {
+        password: "password".to_owned(),
+        confirm_password: "passw0rd".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "confirmPassword": {
+                    "errors": ["The passwords do not match."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_target_unnamed_field_is_err() {
+    #[derive(Validate)]
+    #[rule(password_confirmed(0, 1), target = 1)]
+    struct TestStruct(String, String);
+
+    let s = TestStruct("password".to_owned(), "passw0rd".to_owned());
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "items": {
+                "1": {
+                    "errors": ["The passwords do not match."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_vec_errors_is_err() {
+    fn prices_match(
+        skus: &[String],
+        prices: &[u32],
+    ) -> Result<(), Vec<serde_valid::validation::Error>> {
+        let errors = skus
+            .iter()
+            .zip(prices)
+            .filter(|(_, price)| **price == 0)
+            .map(|(sku, _)| {
+                serde_valid::validation::Error::Custom(format!("The price of `{sku}` is invalid."))
+            })
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    #[derive(Validate)]
+    #[rule(prices_match(skus, prices))]
+    struct TestStruct {
+        skus: Vec<String>,
+        prices: Vec<u32>,
+    }
+
+    let s = TestStruct {
+        skus: vec!["a".to_owned(), "b".to_owned(), "c".to_owned()],
+        prices: vec![0, 1, 0],
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [
+                "The price of `a` is invalid.",
+                "The price of `c` is invalid."
+            ],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_errors_merge_into_properties() {
+    use serde_valid::validation::{Error, Errors, ObjectErrors, PropertyErrorsMap};
+
+    fn lines_valid(lines: &[u32]) -> Result<(), Errors> {
+        let properties = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| **line == 0)
+            .map(|(index, _)| {
+                (
+                    format!("line_{index}").into(),
+                    Errors::NewType(vec![Error::Custom("The line is empty.".to_owned())]),
+                )
+            })
+            .collect::<PropertyErrorsMap>();
+        if properties.is_empty() {
+            Ok(())
+        } else {
+            Err(Errors::Object(ObjectErrors::new(
+                vec![Error::Custom("The lines are invalid.".to_owned())],
+                properties,
+            )))
+        }
+    }
+
+    #[derive(Validate)]
+    #[rule(lines_valid(lines))]
+    struct TestStruct {
+        lines: Vec<u32>,
+        #[validate(maximum = 10)]
+        line_1: u32,
+    }
+
+    let s = TestStruct {
+        lines: vec![1, 0],
+        line_1: 11,
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["The lines are invalid."],
+            "properties": {
+                "line_1": {
+                    "errors": [
+                        "The number must be `<= 10`.",
+                        "The line is empty."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
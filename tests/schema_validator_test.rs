@@ -0,0 +1,246 @@
+use serde_json::json;
+use serde_valid::schema::SchemaValidator;
+use serde_valid::{JsonSchema, Validate};
+
+#[test]
+fn schema_validator_is_ok() {
+    let validator = SchemaValidator::compile(&json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string", "minLength": 1 },
+            "tags": {
+                "type": "array",
+                "items": { "type": "string" },
+                "uniqueItems": true
+            }
+        },
+        "required": ["name"]
+    }))
+    .unwrap();
+
+    assert!(validator
+        .validate(&json!({ "name": "serde_valid", "tags": ["a", "b"] }))
+        .is_ok());
+}
+
+#[test]
+fn schema_validator_nested_errors() {
+    let validator = SchemaValidator::compile(&json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string", "pattern": r"^\w+$" },
+            "tags": {
+                "type": "array",
+                "items": { "type": "string", "maxLength": 3 },
+                "maxItems": 2
+            },
+            "inner": {
+                "type": "object",
+                "properties": {
+                    "val": { "enum": [1, 2, "3"] }
+                }
+            }
+        },
+        "required": ["name", "count"],
+        "additionalProperties": false
+    }))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(
+            validator
+                .validate(&json!({
+                    "name": "serde valid",
+                    "tags": ["abcd", "abc", "a"],
+                    "inner": { "val": 4 },
+                    "extra": true
+                }))
+                .unwrap_err()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "count": {
+                    "errors": ["The property is required."]
+                },
+                "name": {
+                    "errors": ["The value must match the pattern of \"^\\w+$\"."]
+                },
+                "tags": {
+                    "errors": ["The length of the items must be `<= 2`."],
+                    "items": {
+                        "0": {
+                            "errors": ["The length of the value must be `<= 3`."]
+                        }
+                    }
+                },
+                "inner": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["The value must be in [1, 2, 3]."]
+                        }
+                    }
+                },
+                "extra": {
+                    "errors": ["The value is not allowed."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn schema_validator_type_error() {
+    let validator = SchemaValidator::compile(&json!({
+        "type": "array",
+        "prefixItems": [{ "type": "integer" }, { "type": ["string", "null"] }],
+        "items": false
+    }))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(validator.validate(&json!([1.5, 2, 3])).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "items": {
+                "0": {
+                    "errors": ["The value must be of type `integer`."]
+                },
+                "1": {
+                    "errors": ["The value must be of type `string` or `null`."]
+                },
+                "2": {
+                    "errors": ["The value is not allowed."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn schema_validator_composition() {
+    let validator = SchemaValidator::compile(&json!({
+        "oneOf": [
+            { "type": "integer", "minimum": 0 },
+            { "type": "string" }
+        ],
+        "not": { "enum": [2] }
+    }))
+    .unwrap();
+
+    assert!(validator.is_valid(&json!(1)));
+    assert!(validator.is_valid(&json!("1")));
+    assert!(!validator.is_valid(&json!(-1)));
+    assert!(!validator.is_valid(&json!(2)));
+    assert_eq!(
+        validator.validate(&json!(true)).unwrap_err().to_string(),
+        json!({
+            "errors": ["The value must be valid against exactly one of the `oneOf` schemas."]
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn schema_validator_same_errors_as_derive() {
+    #[derive(Validate, JsonSchema)]
+    struct TestInnerStruct {
+        #[validate(min_length = 2)]
+        val: String,
+    }
+
+    #[derive(Validate, JsonSchema)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        #[validate(multiple_of = 3)]
+        num: i32,
+        #[validate(max_items = 1)]
+        #[validate(minimum = 1.5)]
+        items: Vec<f64>,
+        #[validate]
+        inner: TestInnerStruct,
+    }
+
+    let s = TestStruct {
+        num: 11,
+        items: vec![1.0, 2.0],
+        inner: TestInnerStruct {
+            val: "a".to_owned(),
+        },
+    };
+    let value = json!({
+        "num": 11,
+        "items": [1.0, 2.0],
+        "inner": { "val": "a" }
+    });
+
+    let validator = SchemaValidator::compile(&TestStruct::json_schema()).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(validator.validate(&value).unwrap_err()).unwrap(),
+        serde_json::to_value(s.validate().unwrap_err()).unwrap()
+    );
+}
+
+#[test]
+fn schema_validator_registered_format() {
+    assert_eq!(
+        SchemaValidator::compile(&json!({ "format": "test-semver" }))
+            .unwrap_err()
+            .to_string(),
+        "The format `test-semver` is unknown. Register it by `serde_valid::format::register`."
+    );
+
+    serde_valid::format::register("test-semver", |value| {
+        let parts = value.split('.').collect::<Vec<_>>();
+        parts.len() == 3 && parts.iter().all(|part| part.parse::<u64>().is_ok())
+    });
+
+    let validator = SchemaValidator::compile(&json!({ "format": "test-semver" })).unwrap();
+    assert!(validator.is_valid(&json!("1.2.3")));
+    assert!(!validator.is_valid(&json!("1.2")));
+}
+
+#[test]
+fn schema_validator_derived_when_schema() {
+    #[derive(Validate, JsonSchema)]
+    struct Order {
+        country: String,
+        #[validate(pattern = r"^DE\d{9}$", when = "self.country == \"DE\"")]
+        vat_number: String,
+    }
+
+    let validator = SchemaValidator::compile(&Order::json_schema()).unwrap();
+
+    assert!(validator
+        .validate(&json!({ "country": "DE", "vat_number": "DE123456789" }))
+        .is_ok());
+    assert!(validator
+        .validate(&json!({ "country": "FR", "vat_number": "FR123" }))
+        .is_ok());
+    let order = Order {
+        country: "DE".to_owned(),
+        vat_number: "FR123".to_owned(),
+    };
+    assert_eq!(
+        validator
+            .validate(&json!({ "country": "DE", "vat_number": "FR123" }))
+            .unwrap_err()
+            .to_string(),
+        order.validate().unwrap_err().to_string()
+    );
+    assert_eq!(
+        order.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vat_number": {
+                    "errors": [r#"The value must match the pattern of "^DE\d{9}$"."#]
+                }
+            }
+        })
+        .to_string()
+    );
+}
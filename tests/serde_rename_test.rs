@@ -122,3 +122,102 @@ fn serde_rename_enume_is_err() {
         })
     );
 }
+
+#[test]
+fn serde_rename_all_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        first_value: i32,
+        #[validate(maximum = 100)]
+        #[serde(rename = "second")]
+        second_value: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "firstValue": 123, "second": 123 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "firstValue": {
+                    "errors": ["The number must be `<= 100`."]
+                },
+                "second": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_deserialize_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all(serialize = "camelCase", deserialize = "SCREAMING-KEBAB-CASE"))]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        first_value: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "FIRST-VALUE": 123 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "FIRST-VALUE": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_enum_variant_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all_fields = "PascalCase")]
+    enum TestEnum {
+        #[serde(rename_all = "kebab-case")]
+        Kebab {
+            #[validate(maximum = 100)]
+            first_value: i32,
+        },
+        Pascal {
+            #[validate(maximum = 100)]
+            first_value: i32,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "Kebab": { "first-value": 123 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "first-value": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+
+    let err = TestEnum::from_json_value(json!({ "Pascal": { "FirstValue": 123 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "FirstValue": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
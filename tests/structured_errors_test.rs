@@ -0,0 +1,114 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn structured_errors_named_struct() {
+    fn sample_custom(_val: &str) -> Result<(), serde_valid::validation::Error> {
+        Err(serde_valid::validation::Error::Custom(
+            "custom error.".to_owned(),
+        ))
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern = r"^\d+$")]
+        #[validate(custom(sample_custom))]
+        code: String,
+        #[validate(enumerate("a", "b"))]
+        kind: &'static str,
+        #[validate(unique_items)]
+        #[validate(minimum = 2)]
+        vals: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        code: "a".to_owned(),
+        kind: "c",
+        vals: vec![1, 2, 1],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().structured()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "code": {
+                    "errors": [
+                        {
+                            "code": "pattern",
+                            "params": { "pattern": r"^\d+$" },
+                            "message": "The value must match the pattern of \"^\\d+$\"."
+                        },
+                        {
+                            "code": "custom",
+                            "params": {},
+                            "message": "custom error."
+                        }
+                    ]
+                },
+                "kind": {
+                    "errors": [
+                        {
+                            "code": "enumerate",
+                            "params": { "enumerate": ["a", "b"], "value": "c" },
+                            "message": "The value must be in [a, b]."
+                        }
+                    ]
+                },
+                "vals": {
+                    "errors": [
+                        {
+                            "code": "unique_items",
+                            "params": { "duplicates": [1] },
+                            "message": "The items must be unique."
+                        }
+                    ],
+                    "items": {
+                        "0": {
+                            "errors": [
+                                {
+                                    "code": "minimum",
+                                    "params": { "minimum": 2, "value": 1 },
+                                    "message": "The number must be `>= 2`."
+                                }
+                            ]
+                        },
+                        "2": {
+                            "errors": [
+                                {
+                                    "code": "minimum",
+                                    "params": { "minimum": 2, "value": 1 },
+                                    "message": "The number must be `>= 2`."
+                                }
+                            ]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn structured_errors_new_type() {
+    #[derive(Validate)]
+    struct TestStruct(#[validate(max_length = 2, message = "too long.")] String);
+
+    let s = TestStruct("abc".to_owned());
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(
+            &s.validate().unwrap_err().structured().to_string()
+        )
+        .unwrap(),
+        json!({
+            "errors": [
+                {
+                    "code": "max_length",
+                    "params": { "max_length": 2, "length": 3 },
+                    "message": "too long."
+                }
+            ]
+        })
+    );
+}
@@ -126,3 +126,36 @@ fn unique_items_custom_err_message() {
         .to_string()
     );
 }
+
+#[test]
+fn unique_items_custom_err_message_fn_with_duplicates() {
+    fn error_message(params: &serde_valid::UniqueItemsErrorParams) -> String {
+        format!(
+            "duplicated items: {}.",
+            serde_valid::error::render_param_value(&params.duplicates)
+        )
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(unique_items, message_fn(error_message))]
+        val: Vec<&'static str>,
+    }
+
+    let s = TestStruct {
+        val: vec!["a", "b", "a", "c", "b", "a"],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["duplicated items: a, b."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
@@ -0,0 +1,142 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn when_expr_is_ok() {
+    #[derive(Validate)]
+    struct Company {
+        country: String,
+        #[validate(pattern = r"^DE\d{9}$", when = "self.country == \"DE\"")]
+        vat_number: String,
+    }
+
+    let s = Company {
+        country: "DE".to_owned(),
+        vat_number: "DE123456789".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = Company {
+        country: "FR".to_owned(),
+        vat_number: "FR12345678901".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn when_expr_is_err() {
+    #[derive(Validate)]
+    struct Company {
+        country: String,
+        #[validate(pattern = r"^DE\d{9}$", when = "self.country == \"DE\"")]
+        vat_number: String,
+    }
+
+    let s = Company {
+        country: "DE".to_owned(),
+        vat_number: "FR12345678901".to_owned(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vat_number": {
+                    "errors": ["The value must match the pattern of \"^DE\\d{9}$\"."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn when_fn_is_err() {
+    fn is_shipping(order: &Order) -> bool {
+        order.shipping
+    }
+
+    #[derive(Validate)]
+    struct Order {
+        shipping: bool,
+        #[validate(min_length = 1, message = "The address is required.", when = is_shipping)]
+        address: String,
+    }
+
+    let s = Order {
+        shipping: false,
+        address: "".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = Order {
+        shipping: true,
+        address: "".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "address": {
+                    "errors": ["The address is required."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn when_custom_with_groups_is_err() {
+    fn not_empty(value: &str) -> Result<(), serde_valid::validation::Error> {
+        if value.is_empty() {
+            Err(serde_valid::validation::Error::Custom(
+                "The value must not be empty.".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    struct Checkout;
+    impl serde_valid::ValidationGroup for Checkout {}
+
+    #[derive(Validate)]
+    struct Order {
+        shipping: bool,
+        #[validate(custom(not_empty), when = "self.shipping", groups(Checkout))]
+        address: String,
+    }
+
+    let s = Order {
+        shipping: true,
+        address: "".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+    assert!(s.validate_group(Checkout).is_err());
+
+    let s = Order {
+        shipping: false,
+        address: "".to_owned(),
+    };
+    assert!(s.validate_group(Checkout).is_ok());
+}
+
+#[test]
+fn when_enum_variant_is_err() {
+    #[derive(Validate)]
+    enum Payment {
+        Card {
+            #[validate(max_length = 16, when = "matches!(self, Payment::Card { .. })")]
+            number: String,
+        },
+    }
+
+    assert!(Payment::Card {
+        number: "12345678901234567".to_owned()
+    }
+    .validate()
+    .is_err());
+}